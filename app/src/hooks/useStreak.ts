@@ -6,7 +6,7 @@ import { useConnection, useWallet } from '@solana/wallet-adapter-react';
 import { PublicKey, SystemProgram, LAMPORTS_PER_SOL } from '@solana/web3.js';
 import { Program, AnchorProvider, BN, Idl } from '@coral-xyz/anchor';
 import { IDL, type PlayerAccount, type GameStateAccount } from '@/lib/idl';
-import { PROGRAM_ID, GAME_STATE_SEED, PLAYER_SEED, MIN_STAKE_LAMPORTS } from '@/lib/constants';
+import { PROGRAM_ID, GAME_STATE_SEED, PLAYER_SEED, WITHDRAW_QUEUE_SEED, MIN_STAKE_LAMPORTS } from '@/lib/constants';
 
 export function useStreak() {
   const { connection } = useConnection();
@@ -47,6 +47,14 @@ export function useStreak() {
     return pda;
   }, []);
 
+  const getWithdrawQueuePDA = useCallback(() => {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [WITHDRAW_QUEUE_SEED],
+      PROGRAM_ID
+    );
+    return pda;
+  }, []);
+
   // Fetch game state
   const fetchGameState = useCallback(async () => {
     if (!program) return null;
@@ -106,7 +114,12 @@ export function useStreak() {
   }, [program, wallet.publicKey, getPlayerPDA]);
 
   // Stake SOL
-  const stake = useCallback(async (amount: number, referrer?: string) => {
+  const stake = useCallback(async (
+    amount: number,
+    referrer?: string,
+    deadlineOffset: number = 0,
+    commitmentDays: number = 0
+  ) => {
     if (!wallet.publicKey) {
       throw new Error('Wallet not connected');
     }
@@ -154,10 +167,26 @@ export function useStreak() {
         referrerBuffer = new Uint8Array([0]);
       }
 
-      const data = new Uint8Array(discriminator.length + amountBuffer.length + referrerBuffer.length);
-      data.set(discriminator, 0);
-      data.set(amountBuffer, discriminator.length);
-      data.set(referrerBuffer, discriminator.length + amountBuffer.length);
+      // Encode deadline offset (i64 LE, seconds after the UTC period boundary)
+      const offsetBigInt = BigInt.asUintN(64, BigInt(deadlineOffset));
+      const offsetBuffer = new Uint8Array(8);
+      for (let i = 0; i < 8; i++) {
+        offsetBuffer[i] = Number((offsetBigInt >> BigInt(i * 8)) & BigInt(0xff));
+      }
+
+      // Encode commitment target (u32 LE, 0 = no commitment)
+      const commitmentBuffer = new Uint8Array(4);
+      new DataView(commitmentBuffer.buffer).setUint32(0, commitmentDays, true);
+
+      const data = new Uint8Array(
+        discriminator.length + amountBuffer.length + referrerBuffer.length +
+        offsetBuffer.length + commitmentBuffer.length
+      );
+      let offset = 0;
+      for (const part of [discriminator, amountBuffer, referrerBuffer, offsetBuffer, commitmentBuffer]) {
+        data.set(part, offset);
+        offset += part.length;
+      }
 
       const keys = [
         { pubkey: gameStatePDA, isSigner: false, isWritable: true },
@@ -265,12 +294,21 @@ export function useStreak() {
       const gameStatePDA = getGameStatePDA();
       const playerPDA = getPlayerPDA(wallet.publicKey);
 
+      // Exit fees are split with the treasury recorded on the game state
+      const game = gameState ?? await fetchGameState();
+      if (!game) {
+        throw new Error('Game state not loaded');
+      }
+
       const tx = await program.methods
         .withdraw()
         .accounts({
           gameState: gameStatePDA,
+          treasury: new PublicKey(game.treasury),
+          withdrawQueue: getWithdrawQueuePDA(),
           player: playerPDA,
           user: wallet.publicKey,
+          sponsor: null,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -286,7 +324,7 @@ export function useStreak() {
     } finally {
       setLoading(false);
     }
-  }, [program, wallet.publicKey, connection, getGameStatePDA, getPlayerPDA, fetchPlayer, fetchGameState]);
+  }, [program, wallet.publicKey, connection, gameState, getGameStatePDA, getPlayerPDA, getWithdrawQueuePDA, fetchPlayer, fetchGameState]);
 
   // Claim rewards
   const claimRewards = useCallback(async () => {
@@ -303,6 +341,7 @@ export function useStreak() {
         .claimRewards()
         .accounts({
           gameState: gameStatePDA,
+          withdrawQueue: getWithdrawQueuePDA(),
           player: playerPDA,
           user: wallet.publicKey,
           systemProgram: SystemProgram.programId,
@@ -319,7 +358,7 @@ export function useStreak() {
     } finally {
       setLoading(false);
     }
-  }, [program, wallet.publicKey, connection, getGameStatePDA, getPlayerPDA, fetchPlayer]);
+  }, [program, wallet.publicKey, connection, getGameStatePDA, getPlayerPDA, getWithdrawQueuePDA, fetchPlayer]);
 
   // Process own death (finalize death on-chain)
   const processDeath = useCallback(async () => {
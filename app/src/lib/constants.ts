@@ -4,6 +4,7 @@ export const PROGRAM_ID = new PublicKey('Eyz3yhxzGKemxF7JYT3Q9LCVCKLkim6unnzH4cM
 
 export const GAME_STATE_SEED = Buffer.from('game_state');
 export const PLAYER_SEED = Buffer.from('player');
+export const WITHDRAW_QUEUE_SEED = Buffer.from('withdraw_queue');
 
 export const MIN_STAKE = 0.05; // SOL
 export const MAX_STAKE = 2; // SOL
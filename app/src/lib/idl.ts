@@ -81,7 +81,9 @@ export const IDL: Streak = {
       ],
       args: [
         { name: "amount", type: "u64" },
-        { name: "referrer", type: { option: "pubkey" } }
+        { name: "referrer", type: { option: "pubkey" } },
+        { name: "deadline_offset", type: "i64" },
+        { name: "commitment_days", type: "u32" }
       ]
     },
     {
@@ -114,6 +116,7 @@ export const IDL: Streak = {
         { name: "referrer_1", writable: true, optional: true },
         { name: "referrer_2", writable: true, optional: true },
         { name: "referrer_3", writable: true, optional: true },
+        { name: "sponsor", writable: true, optional: true },
         { name: "system_program", address: "11111111111111111111111111111111" }
       ],
       args: []
@@ -123,8 +126,11 @@ export const IDL: Streak = {
       discriminator: [183, 18, 70, 156, 148, 109, 161, 34],
       accounts: [
         { name: "game_state", writable: true, pda: { seeds: [{ kind: "const", value: [103, 97, 109, 101, 95, 115, 116, 97, 116, 101] }] } },
+        { name: "treasury", writable: true },
+        { name: "withdraw_queue", writable: true, pda: { seeds: [{ kind: "const", value: [119, 105, 116, 104, 100, 114, 97, 119, 95, 113, 117, 101, 117, 101] }] } },
         { name: "player", writable: true, pda: { seeds: [{ kind: "const", value: [112, 108, 97, 121, 101, 114] }, { kind: "account", path: "user" }] } },
         { name: "user", writable: true, signer: true },
+        { name: "sponsor", writable: true, optional: true },
         { name: "system_program", address: "11111111111111111111111111111111" }
       ],
       args: []
@@ -134,6 +140,7 @@ export const IDL: Streak = {
       discriminator: [4, 144, 132, 71, 116, 23, 151, 80],
       accounts: [
         { name: "game_state", writable: true, pda: { seeds: [{ kind: "const", value: [103, 97, 109, 101, 95, 115, 116, 97, 116, 101] }] } },
+        { name: "withdraw_queue", writable: true, pda: { seeds: [{ kind: "const", value: [119, 105, 116, 104, 100, 114, 97, 119, 95, 113, 117, 101, 117, 101] }] } },
         { name: "player", writable: true, pda: { seeds: [{ kind: "const", value: [112, 108, 97, 121, 101, 114] }, { kind: "account", path: "user" }] } },
         { name: "user", writable: true, signer: true },
         { name: "system_program", address: "11111111111111111111111111111111" }
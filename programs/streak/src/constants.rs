@@ -13,6 +13,33 @@ pub const BPS_DENOMINATOR: u64 = 10_000;
 pub const PROTOCOL_FEE_BPS: u64 = 300; // 3%
 pub const REFERRAL_CUT_BPS: u64 = 500; // 5% per level
 
+// Growth tiers: (min streak days, extra daily growth bps, milestone reward bps)
+pub const MAX_GROWTH_TIERS: usize = 4;
+pub const DEFAULT_GROWTH_TIERS: [(u32, u64, u64); MAX_GROWTH_TIERS] = [
+    (7, 1, 50), // 1 week: 0.11%/day, 0.5% reward
+    (30, 2, 100), // 1 month: 0.12%/day, 1% reward
+    (100, 4, 250), // 100 days: 0.14%/day, 2.5% reward
+    (365, 6, 500), // 1 year: 0.16%/day, 5% reward
+];
+
+// Commitments: (min target days, extra daily growth bps, completion bonus bps)
+pub const COMMITMENT_TIERS: [(u32, u64, u64); 3] = [
+    (7, 2, 100), // 1 week: 0.12%/day, 1% bonus
+    (30, 5, 500), // 1 month: 0.15%/day, 5% bonus
+    (90, 10, 2_000), // 3 months: 0.2%/day, 20% bonus
+];
+pub const MAX_COMMITMENT_DAYS: u32 = 365;
+pub const EARLY_EXIT_PENALTY_BPS: u64 = 2_000; // 20% of withdrawn stake
+
+// Points
+pub const POINTS_TIER_BONUS_BPS: u64 = 2_500; // +0.25x points per milestone reached this stake
+
+// Freezes (vacation mode)
+pub const FREEZE_FEE_BPS: u64 = 20; // 0.2% of stake per frozen period
+pub const MAX_FREEZE_PERIODS: u32 = 7;
+pub const MAX_FREEZES_PER_STAKE: u8 = 2;
+pub const MAX_FREEZE_LEAD_PERIODS: i64 = 30; // freezes start at most this many periods ahead
+
 // Referral
 pub const MAX_REFERRAL_DEPTH: u8 = 3;
 pub const REFS_PER_LIFELINE: u32 = 3;
@@ -21,11 +48,65 @@ pub const REFS_PER_LIFELINE: u32 = 3;
 pub const SECONDS_PER_DAY: i64 = 86_400;
 pub const GRACE_PERIOD_SECONDS: i64 = 300; // 5 minutes before midnight
 pub const BONUS_DURATION_SECONDS: i64 = 900; // 15 minutes
+pub const MAX_DEADLINE_OFFSET_SECONDS: i64 = SECONDS_PER_DAY; // exclusive upper bound
+
+// Withdrawal queue
+pub const MAX_QUEUE_ENTRIES: usize = 64;
+
+// Cohorts
+pub const MAX_MERKLE_PROOF_LEN: usize = 16; // up to 65,536 members
+
+// Leagues
+pub const MAX_LEAGUES: u8 = 8;
+pub const LEADERBOARD_SIZE: usize = 10;
+
+// Tournaments
+pub const MAX_TOURNAMENT_WINNERS: u32 = 20; // finish_tournament checks every survivor in one transaction
+
+// Duels
+pub const MAX_OPEN_DUELS: u8 = 3; // process_death settles them all in one transaction
+
+// Squads
+pub const MIN_SQUAD_SIZE: usize = 2; // the squad bonus needs at least this many members
+pub const MAX_SQUAD_SIZE: usize = 10;
+
+// Jackpot
+pub const MAX_JACKPOT_ENTRIES: usize = 64; // per period; check-ins past this roll into the next period's draw
+pub const MAX_JACKPOT_PERIODS: usize = 3; // undrawn periods the jackpot holds entries for
+pub const MAX_JACKPOT_PRIZES: usize = 16;
+pub const JACKPOT_REVEAL_SLOTS: u64 = 8; // draws use the hash of the slot this far past the round closing
+
+// Mystery boxes
+pub const MAX_BOX_REWARDS: usize = 8;
+pub const BOX_REVEAL_SLOTS: u64 = 8; // boxes open with the hash of the slot this far past the grant
+pub const BOX_REWARD_GROWTH: u8 = 1; // amount = bps of stake
+pub const BOX_REWARD_LIFELINE: u8 = 2; // amount = lifelines
+pub const BOX_REWARD_POINTS: u8 = 3; // amount = points before multipliers
+pub const BOX_REWARD_JACKPOT_TICKET: u8 = 4; // amount = extra entries in this period's draw
+
+// Arenas
+// The original singleton game. Its PDAs keep their pre-arena addresses
+// because its game seed is empty (see GameState::seed_for).
+pub const MAIN_GAME_ID: u64 = 0;
 
 // Seeds
 pub const GAME_STATE_SEED: &[u8] = b"game_state";
 pub const PLAYER_SEED: &[u8] = b"player";
 pub const VAULT_SEED: &[u8] = b"vault";
+pub const WITHDRAW_QUEUE_SEED: &[u8] = b"withdraw_queue";
+pub const COHORT_SEED: &[u8] = b"cohort";
+pub const COHORT_MEMBER_SEED: &[u8] = b"cohort_member";
+pub const STAKE_VAULT_SEED: &[u8] = b"stake_vault";
+pub const COHORT_VAULT_SEED: &[u8] = b"cohort_vault";
+pub const LEAGUE_SEED: &[u8] = b"league";
+pub const SEASON_SEED: &[u8] = b"season";
+pub const TOURNAMENT_SEED: &[u8] = b"tournament";
+pub const TOURNAMENT_ENTRY_SEED: &[u8] = b"tournament_entry";
+pub const DUEL_SEED: &[u8] = b"duel";
+pub const SQUAD_SEED: &[u8] = b"squad";
+pub const JACKPOT_SEED: &[u8] = b"jackpot";
+pub const SPONSOR_POOL_SEED: &[u8] = b"sponsor_pool";
+pub const SPONSOR_CLAIM_SEED: &[u8] = b"sponsor_claim";
 
 // Account sizes
 pub const GAME_STATE_SIZE: usize = 8 + // discriminator
@@ -55,4 +55,250 @@ pub enum StreakError {
 
     #[msg("Invalid check-in interval (must be > 0)")]
     InvalidInterval,
+
+    #[msg("Invalid deadline offset (must be 0 to 86399 seconds)")]
+    InvalidDeadlineOffset,
+
+    #[msg("Invalid late window (must be >= 0 and shorter than the check-in interval)")]
+    InvalidLateWindow,
+
+    #[msg("Basis points must not exceed 10000")]
+    InvalidBps,
+
+    #[msg("Invalid freeze (must start in one of the next 30 periods and cover 1-7 periods)")]
+    InvalidFreeze,
+
+    #[msg("A freeze is already scheduled or in progress")]
+    FreezeAlreadyScheduled,
+
+    #[msg("Freeze limit reached for this stake")]
+    FreezeLimitReached,
+
+    #[msg("No lifelines available")]
+    NoLifelines,
+
+    #[msg("No death to revive from")]
+    NothingToRevive,
+
+    #[msg("Recovery window has expired")]
+    RecoveryWindowExpired,
+
+    #[msg("Invalid recovery window (must be >= 0)")]
+    InvalidRecoveryWindow,
+
+    #[msg("Amount must be greater than zero")]
+    InvalidAmount,
+
+    #[msg("Withdrawal queue is full")]
+    WithdrawQueueFull,
+
+    #[msg("Account does not match the withdrawal queue entry")]
+    InvalidQueueAccount,
+
+    #[msg("Withdrawal queue must be paid down first")]
+    WithdrawQueuePending,
+
+    #[msg("Invalid commitment (must be 0 or 7 to 365 days)")]
+    InvalidCommitment,
+
+    #[msg("Invalid growth tiers (at most 4, ordered by streak length)")]
+    InvalidGrowthTiers,
+
+    #[msg("No growth above principal to harvest")]
+    NothingToHarvest,
+
+    #[msg("Sponsor can't be the beneficiary - use stake instead")]
+    InvalidBeneficiary,
+
+    #[msg("Sponsor account missing or does not match the position's sponsor")]
+    InvalidSponsor,
+
+    #[msg("Wallet is not a member of this cohort")]
+    NotCohortMember,
+
+    #[msg("Cohort budget can't fund another stake")]
+    CohortBudgetExhausted,
+
+    #[msg("No points snapshot has been started")]
+    NoPointsSnapshot,
+
+    #[msg("Player already recorded in this points snapshot")]
+    AlreadySnapshotted,
+
+    #[msg("Reward mint doesn't match the game or isn't minted by the game state PDA")]
+    InvalidRewardMint,
+
+    #[msg("Reward tokens must go to the player's associated token account")]
+    InvalidRewardTokenAccount,
+
+    #[msg("No reward tokens to mint")]
+    NoTokenRewards,
+
+    #[msg("Token-staked games need the stake vault, token accounts and token program")]
+    MissingTokenAccounts,
+
+    #[msg("Token account has the wrong mint or owner")]
+    InvalidTokenAccount,
+
+    #[msg("Only available in SOL-staked games")]
+    SolOnly,
+
+    #[msg("Stake mint can only be set before anyone has staked")]
+    GameAlreadyStarted,
+
+    #[msg("Account belongs to a different arena")]
+    WrongGame,
+
+    #[msg("Arena ID is reserved for the main game")]
+    InvalidGameId,
+
+    #[msg("League account missing or does not match the player's league")]
+    WrongLeague,
+
+    #[msg("Stake is outside the league's band")]
+    OutsideLeagueBand,
+
+    #[msg("Invalid league configuration")]
+    InvalidLeague,
+
+    #[msg("Season account missing or not the game's current season")]
+    WrongSeason,
+
+    #[msg("Invalid season configuration")]
+    InvalidSeason,
+
+    #[msg("Season entry is closed or the player does not meet its entry rules")]
+    SeasonEntryClosed,
+
+    #[msg("Season has not ended yet")]
+    SeasonNotEnded,
+
+    #[msg("Season is already finalized")]
+    SeasonFinalized,
+
+    #[msg("No season reward to claim")]
+    NoSeasonReward,
+
+    #[msg("Claim the reward from your last season before joining another")]
+    UnclaimedSeasonReward,
+
+    #[msg("Invalid tournament configuration")]
+    InvalidTournament,
+
+    #[msg("Tournament registration is closed")]
+    RegistrationClosed,
+
+    #[msg("Tournament is still running")]
+    TournamentRunning,
+
+    #[msg("Tournament has finished")]
+    TournamentFinished,
+
+    #[msg("Entry did not win the tournament or already claimed")]
+    NotTournamentWinner,
+
+    #[msg("Invalid duel parameters")]
+    InvalidDuel,
+
+    #[msg("Duel has already been accepted")]
+    DuelAlreadyAccepted,
+
+    #[msg("Duel has not been accepted")]
+    DuelNotAccepted,
+
+    #[msg("Duel has not ended yet")]
+    DuelNotEnded,
+
+    #[msg("Duel has already been settled")]
+    DuelSettled,
+
+    #[msg("Signer is not part of this duel")]
+    NotDuelParty,
+
+    #[msg("Too many open duels")]
+    TooManyOpenDuels,
+
+    #[msg("Settle open duels before withdrawing")]
+    OpenDuels,
+
+    #[msg("Every open duel must be passed with the opponent's player account")]
+    MissingDuelAccounts,
+
+    #[msg("Nothing to claim from this duel")]
+    NothingToClaim,
+
+    #[msg("Squad account does not match the player's squad")]
+    WrongSquad,
+
+    #[msg("Player is already in a squad")]
+    AlreadyInSquad,
+
+    #[msg("Squad is full")]
+    SquadFull,
+
+    #[msg("Leave your squad before withdrawing")]
+    InSquad,
+
+    #[msg("Jackpot account is required for this game")]
+    MissingJackpot,
+
+    #[msg("No finished period to draw, or its draw slot has not passed")]
+    JackpotNotReady,
+
+    #[msg("Too many jackpot prizes waiting to be credited")]
+    JackpotPrizesFull,
+
+    #[msg("Invalid randomness account")]
+    InvalidRandomness,
+
+    #[msg("Invalid mystery box rewards")]
+    InvalidBoxRewards,
+
+    #[msg("No mystery boxes to open")]
+    NoMysteryBoxes,
+
+    #[msg("Wait for a later slot before opening the box")]
+    BoxTooEarly,
+
+    #[msg("Invalid sponsor pool parameters")]
+    InvalidSponsorPool,
+
+    #[msg("Sponsor pool has expired")]
+    SponsorPoolExpired,
+
+    #[msg("Sponsor pool has not expired yet")]
+    SponsorPoolNotExpired,
+
+    #[msg("Player does not meet the sponsor pool's rules")]
+    NotEligible,
+
+    #[msg("Sponsor pool has run out of funds")]
+    SponsorPoolExhausted,
+
+    #[msg("Revives are disabled for this game")]
+    RevivesDisabled,
+
+    #[msg("Player still has a revive, reward or season claim outstanding")]
+    OutstandingClaims,
+
+    #[msg("Streak broke before the season ended")]
+    SeasonNotSurvived,
+
+    #[msg("Season claims are still open")]
+    SeasonClaimsOpen,
+
+    #[msg("Season claims have closed")]
+    SeasonClaimsClosed,
+
+    #[msg("Every entry still in the tournament must be passed, once each")]
+    MissingTournamentEntries,
+
+    #[msg("Sponsor pool entries have closed")]
+    SponsorEntriesClosed,
+
+    #[msg("Sponsor pool claims open once entries close")]
+    SponsorClaimsNotOpen,
+
+    #[msg("Every tournament winner has to claim before the tournament is closed")]
+    TournamentPrizesUnclaimed,
 }
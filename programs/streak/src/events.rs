@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+/// Emitted when a withdrawal pays an exit fee
+#[event]
+pub struct ExitFeeCharged {
+    pub player: Pubkey,
+    pub streak_days: u32,
+    pub amount_withdrawn: u64,
+    pub fee: u64,
+    pub treasury_share: u64,
+    pub pool_share: u64,
+    pub early_exit_penalty: u64,
+}
+
+/// Emitted when a check-in crosses a growth tier milestone
+#[event]
+pub struct MilestoneReached {
+    pub player: Pubkey,
+    pub streak_days: u32,
+    pub milestone_index: u8,
+    pub reward: u64,
+}
+
+/// Emitted when a player opens a mystery box
+#[event]
+pub struct MysteryBoxOpened {
+    pub player: Pubkey,
+    pub kind: u8,
+    pub amount: u64,
+}
+
+/// Emitted when a player's points are recorded in a snapshot (for airdrop exports)
+#[event]
+pub struct PointsSnapshot {
+    pub player: Pubkey,
+    pub snapshot_id: u64,
+    pub points: u64,
+}
+
+/// Emitted when a late check-in charges the penalty and resets the growth multiplier
+#[event]
+pub struct LateCheckin {
+    pub player: Pubkey,
+    pub penalty: u64,
+    pub growth_streak_days_lost: u32,
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{Duel, GameState, Player};
+use crate::constants::{GAME_STATE_SEED, PLAYER_SEED, DUEL_SEED, MAX_OPEN_DUELS};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct AcceptDuel<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        mut,
+        seeds = [DUEL_SEED, game_state.game_seed().as_ref(), duel.challenger.as_ref(), &duel.duel_id.to_le_bytes()],
+        bump = duel.bump,
+        constraint = duel.opponent == user.key() @ StreakError::NotDuelParty
+    )]
+    pub duel: Account<'info, Duel>,
+
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, game_state.game_seed().as_ref(), duel.challenger.as_ref()],
+        bump = challenger_player.bump
+    )]
+    pub challenger_player: Account<'info, Player>,
+
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, game_state.game_seed().as_ref(), user.key().as_ref()],
+        bump = opponent_player.bump
+    )]
+    pub opponent_player: Account<'info, Player>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AcceptDuel>) -> Result<()> {
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+    let game_state = &ctx.accounts.game_state;
+    let interval = game_state.checkin_interval_seconds;
+    let late_window = game_state.late_window_seconds;
+
+    require!(!ctx.accounts.duel.accepted, StreakError::DuelAlreadyAccepted);
+    require!(current_time < ctx.accounts.duel.ends_at, StreakError::InvalidDuel);
+
+    // Both streaks must still be intact when the duel starts
+    for player in [&ctx.accounts.challenger_player, &ctx.accounts.opponent_player] {
+        require!(player.is_active, StreakError::NotStaked);
+        require!(
+            player.streak_broken_at(current_time, interval, late_window).is_none(),
+            StreakError::PlayerDead
+        );
+        require!(player.open_duels < MAX_OPEN_DUELS, StreakError::TooManyOpenDuels);
+    }
+
+    // Match the challenger's wager
+    let wager = ctx.accounts.duel.wager;
+    let cpi_context = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        system_program::Transfer {
+            from: ctx.accounts.user.to_account_info(),
+            to: ctx.accounts.duel.to_account_info(),
+        },
+    );
+    system_program::transfer(cpi_context, wager)?;
+
+    ctx.accounts.duel.accepted = true;
+    let challenger_player = &mut ctx.accounts.challenger_player;
+    challenger_player.open_duels = challenger_player.open_duels.checked_add(1).ok_or(StreakError::Overflow)?;
+    let opponent_player = &mut ctx.accounts.opponent_player;
+    opponent_player.open_duels = opponent_player.open_duels.checked_add(1).ok_or(StreakError::Overflow)?;
+
+    msg!("Duel accepted! {} lamports at stake", wager.checked_mul(2).ok_or(StreakError::Overflow)?);
+
+    Ok(())
+}
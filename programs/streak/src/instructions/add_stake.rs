@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::{GameState, Player, League};
+use crate::constants::{GAME_STATE_SEED, PLAYER_SEED, STAKE_VAULT_SEED};
+use crate::errors::StreakError;
+use crate::vault::Vault;
+
+#[derive(Accounts)]
+pub struct AddStake<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, game_state.game_seed().as_ref(), user.key().as_ref()],
+        bump = player.bump,
+        constraint = player.wallet == user.key()
+    )]
+    pub player: Account<'info, Player>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Token-staked games only: the game's stake vault
+    #[account(
+        mut,
+        seeds = [STAKE_VAULT_SEED, game_state.game_seed().as_ref()],
+        bump
+    )]
+    pub stake_vault: Option<Account<'info, TokenAccount>>,
+
+    /// Token-staked games only: the user's token account for the stake mint
+    #[account(mut)]
+    pub user_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    #[account(
+        mut,
+        constraint = league.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub league: Option<Account<'info, League>>,
+
+    /// League to move to when the stake leaves the current league's band
+    #[account(
+        mut,
+        constraint = new_league.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub new_league: Option<Account<'info, League>>,
+}
+
+pub fn handler(ctx: Context<AddStake>, amount: u64) -> Result<()> {
+    require!(amount > 0, StreakError::InvalidAmount);
+
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+    let interval = ctx.accounts.game_state.checkin_interval_seconds;
+
+    // Validate player is active and alive
+    let player = &ctx.accounts.player;
+    require!(player.is_active, StreakError::NotStaked);
+    League::check_member(ctx.accounts.league.as_ref(), player)?;
+    let current_period = player.period_at(current_time, interval);
+    require!(
+        !player.has_missed_checkin(current_period, interval),
+        StreakError::PlayerDead
+    );
+
+    // Transfer the top-up (SOL or the game's token) from user to the vault
+    let vault = Vault::load(
+        &ctx.accounts.game_state,
+        ctx.accounts.stake_vault.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    )?;
+    vault.deposit(
+        &ctx.accounts.game_state.to_account_info(),
+        &ctx.accounts.user.to_account_info(),
+        ctx.accounts.user_token_account.as_ref(),
+        &ctx.accounts.system_program.to_account_info(),
+        amount,
+    )?;
+
+    // Top up stake (streak and growth state are untouched)
+    let player = &mut ctx.accounts.player;
+    player.stake = player.stake.checked_add(amount).ok_or(StreakError::Overflow)?;
+    player.principal = player.principal.checked_add(amount).ok_or(StreakError::Overflow)?;
+
+    // A bigger stake may move the player up a league
+    if let Some(league) = ctx.accounts.league.as_mut() {
+        league.add_to_pool(amount)?;
+        league.follow_stake(ctx.accounts.new_league.as_mut(), player)?;
+    }
+
+    let game_state = &mut ctx.accounts.game_state;
+    game_state.total_pool = game_state.total_pool.checked_add(amount).ok_or(StreakError::Overflow)?;
+
+    msg!("Added {} lamports to stake", amount);
+    msg!("New stake: {} lamports", player.stake);
+
+    Ok(())
+}
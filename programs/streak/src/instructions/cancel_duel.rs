@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::state::{Duel, GameState};
+use crate::constants::{GAME_STATE_SEED, DUEL_SEED};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct CancelDuel<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    /// Closing returns the wager and rent to the challenger
+    #[account(
+        mut,
+        close = challenger,
+        seeds = [DUEL_SEED, game_state.game_seed().as_ref(), challenger.key().as_ref(), &duel.duel_id.to_le_bytes()],
+        bump = duel.bump,
+        has_one = challenger @ StreakError::NotDuelParty
+    )]
+    pub duel: Account<'info, Duel>,
+
+    #[account(mut)]
+    pub challenger: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CancelDuel>) -> Result<()> {
+    require!(!ctx.accounts.duel.accepted, StreakError::DuelAlreadyAccepted);
+
+    msg!("Duel {} cancelled, {} lamports refunded", ctx.accounts.duel.duel_id, ctx.accounts.duel.wager);
+
+    Ok(())
+}
@@ -1,26 +1,72 @@
 use anchor_lang::prelude::*;
-use crate::state::{GameState, Player};
-use crate::constants::{GAME_STATE_SEED, PLAYER_SEED, DAILY_GROWTH_BPS, BPS_DENOMINATOR};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::{GameState, Player, League, Season, Squad, Jackpot};
+use crate::constants::{GAME_STATE_SEED, PLAYER_SEED, DAILY_GROWTH_BPS, BPS_DENOMINATOR, BOX_REVEAL_SLOTS};
 use crate::errors::StreakError;
+use crate::instructions::claim_token_rewards::mint_token_rewards;
+use crate::events::{MilestoneReached, LateCheckin};
 
 #[derive(Accounts)]
 pub struct Checkin<'info> {
     #[account(
         mut,
-        seeds = [GAME_STATE_SEED],
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
         bump = game_state.bump
     )]
     pub game_state: Account<'info, GameState>,
 
     #[account(
         mut,
-        seeds = [PLAYER_SEED, user.key().as_ref()],
+        seeds = [PLAYER_SEED, game_state.game_seed().as_ref(), user.key().as_ref()],
         bump = player.bump,
         constraint = player.wallet == user.key()
     )]
     pub player: Account<'info, Player>,
 
     pub user: Signer<'info>,
+
+    /// Reward token accounts (optional - tokens stay owed on the player until minted)
+    #[account(mut)]
+    pub reward_mint: Option<Account<'info, Mint>>,
+
+    #[account(mut)]
+    pub player_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    #[account(
+        mut,
+        constraint = league.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub league: Option<Account<'info, League>>,
+
+    /// League to move to when growth or penalties take the stake out of the current league's band
+    #[account(
+        mut,
+        constraint = new_league.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub new_league: Option<Account<'info, League>>,
+
+    /// Squad the player belongs to (required while they are in one)
+    #[account(
+        mut,
+        constraint = squad.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub squad: Option<Account<'info, Squad>>,
+
+    /// Game's jackpot (required once the game has one)
+    #[account(
+        mut,
+        constraint = jackpot.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub jackpot: Option<Account<'info, Jackpot>>,
+
+    /// Game's current season (required while a shrinking stake lowers the player's season weight)
+    #[account(
+        mut,
+        constraint = season.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub season: Option<Account<'info, Season>>,
 }
 
 pub fn handler(ctx: Context<Checkin>) -> Result<()> {
@@ -29,40 +75,256 @@ pub fn handler(ctx: Context<Checkin>) -> Result<()> {
     let clock = Clock::get()?;
     let current_time = clock.unix_timestamp;
 
-    // Use configurable interval (default 86400 = 24 hours)
+    // Use configurable interval (default 86400 = 24 hours),
+    // shifted by the player's deadline offset
     let interval = game_state.checkin_interval_seconds;
-    let current_period = current_time / interval;
+    let current_period = player.period_at(current_time, interval);
 
     // Validate player is active
     require!(player.is_active, StreakError::NotStaked);
+    League::check_member(ctx.accounts.league.as_ref(), player)?;
+    Squad::check_member(ctx.accounts.squad.as_ref(), player)?;
+    Jackpot::check_present(ctx.accounts.jackpot.as_ref(), game_state)?;
+    let game_period = game_state.period_at(current_time);
+
+    // Frozen periods need no check-in and accrue no growth (squadmates aren't let down)
+    if player.is_frozen(current_period) {
+        if let Some(squad) = ctx.accounts.squad.as_mut() {
+            squad.record_checkin(&player.wallet, game_period)?;
+        }
+        msg!("Streak is frozen until period {}, nothing to do", player.freeze_end_period);
+        return Ok(());
+    }
 
-    // Check if player should be dead (missed previous period)
-    let last_checkin_period = player.last_checkin / interval;
-    let start_period = player.start_day / interval;
-    if last_checkin_period < current_period - 1 && start_period < current_period {
+    // Check if player should be dead (missed previous period),
+    // unless they are still inside the late window
+    let is_late = player.has_missed_checkin(current_period, interval);
+    if is_late && !player.is_in_late_window(current_time, interval, game_state.late_window_seconds) {
         return Err(StreakError::PlayerDead.into());
     }
+    let last_checkin_period = player.period_at(player.last_checkin, interval);
 
     // Check if already checked in this period
     require!(last_checkin_period < current_period, StreakError::AlreadyCheckedIn);
 
-    // Apply daily growth (0.1%)
-    let growth = player.stake
-        .checked_mul(DAILY_GROWTH_BPS)
-        .ok_or(StreakError::Overflow)?
-        .checked_div(BPS_DENOMINATOR)
-        .ok_or(StreakError::Overflow)?;
+    let mut growth: u64 = 0;
+    let mut penalty: u64 = 0;
+    let mut squad_bonus: u64 = 0;
+
+    // Pay any squad penalties from squadmates' deaths before growing the stake
+    // (total_pool is unchanged - the lamports just stop belonging to the player)
+    let squad_penalty = match ctx.accounts.squad.as_mut() {
+        Some(squad) => squad.collect_penalties(player, game_state)?,
+        None => 0,
+    };
+
+    if is_late {
+        // Late check-in: move a share of stake to the pool and reset growth
+        // (total_pool is unchanged - the lamports just stop belonging to the player)
+        penalty = player.stake
+            .checked_mul(game_state.late_penalty_bps)
+            .ok_or(StreakError::Overflow)?
+            .checked_div(BPS_DENOMINATOR)
+            .ok_or(StreakError::Overflow)?;
+
+        player.stake = player.stake.checked_sub(penalty).ok_or(StreakError::Overflow)?;
+
+        emit!(LateCheckin {
+            player: player.wallet,
+            penalty,
+            growth_streak_days_lost: player.growth_streak_days,
+        });
+        player.growth_streak_days = 1;
+    } else if game_state.withdraw_queue_owed > 0 {
+        // Pool is paying down the withdrawal queue - no growth until it's clear
+        player.growth_streak_days = player.growth_streak_days.checked_add(1).ok_or(StreakError::Overflow)?;
+    } else {
+        // Apply daily growth (0.1%, more for long streaks and committed players)
+        let tier_growth_bps = game_state.growth_tier_bonus_bps(player.growth_streak_days);
+        let commitment_growth_bps = player.commitment_tier().map_or(0, |(growth_bps, _)| growth_bps);
+        growth = player.stake
+            .checked_mul(DAILY_GROWTH_BPS + tier_growth_bps + commitment_growth_bps)
+            .ok_or(StreakError::Overflow)?
+            .checked_div(BPS_DENOMINATOR)
+            .ok_or(StreakError::Overflow)?;
+
+        // Squads where everyone checked in last period grow faster
+        if ctx.accounts.squad.as_ref().is_some_and(|squad| squad.is_intact(game_period)) {
+            squad_bonus = growth
+                .checked_mul(game_state.squad_growth_bonus_bps)
+                .ok_or(StreakError::Overflow)?
+                .checked_div(BPS_DENOMINATOR)
+                .ok_or(StreakError::Overflow)?;
+            growth = growth.checked_add(squad_bonus).ok_or(StreakError::Overflow)?;
+        }
+
+        player.stake = player.stake.checked_add(growth).ok_or(StreakError::Overflow)?;
+        player.growth_streak_days = player.growth_streak_days.checked_add(1).ok_or(StreakError::Overflow)?;
+    }
+
+    // Penalties may have left the stake below the season payout weight
+    Season::cap_stake(ctx.accounts.season.as_mut(), player, game_state.current_season_id)?;
 
-    player.stake = player.stake.checked_add(growth).ok_or(StreakError::Overflow)?;
     player.streak_days = player.streak_days.checked_add(1).ok_or(StreakError::Overflow)?;
     player.last_checkin = current_time;
+    if let Some(squad) = ctx.accounts.squad.as_mut() {
+        squad.record_checkin(&player.wallet, game_period)?;
+    }
+
+    // Every Nth day of a streak grants a mystery box, opened later with open_box
+    let box_granted = player.streak_days.checked_rem(game_state.box_interval_days) == Some(0)
+        && player.mystery_boxes < u8::MAX;
+    if box_granted {
+        // Boxes already waiting keep their reveal slot, so a new box can't re-roll them
+        if player.mystery_boxes == 0 {
+            player.box_reveal_slot = clock.slot.checked_add(BOX_REVEAL_SLOTS).ok_or(StreakError::Overflow)?;
+        }
+        player.mystery_boxes += 1;
+    }
+
+    // Every check-in is a ticket for this period's jackpot draw, or a later
+    // one if this period's is full
+    let jackpot_period = ctx.accounts.jackpot
+        .as_mut()
+        .map(|jackpot| jackpot.enter(player, game_period));
+
+    let mut points = player.credit_points(game_state.points_per_checkin, game_state.points_multiplier_bps, game_state.points_snapshot_id)?;
+    let mut tokens = game_state.token_reward(game_state.reward_per_checkin, current_time);
+
+    // Bonus payouts are held while the withdrawal queue is owed; they are
+    // paid on the first check-in after it clears
+    let hold_bonuses = game_state.withdraw_queue_owed > 0;
+
+    // Crossing a growth tier milestone pays a one-off reward from the pool
+    let mut milestone_rewards: u64 = 0;
+    for (index, tier) in game_state.growth_tiers.iter().enumerate() {
+        let bit = 1u8 << index;
+        if hold_bonuses
+            || tier.min_streak_days == 0
+            || player.streak_days < tier.min_streak_days
+            || player.milestones_reached & bit != 0
+        {
+            continue;
+        }
+
+        let reward = player.stake
+            .checked_mul(tier.milestone_reward_bps)
+            .ok_or(StreakError::Overflow)?
+            .checked_div(BPS_DENOMINATOR)
+            .ok_or(StreakError::Overflow)?;
+
+        player.milestones_reached |= bit;
+        milestone_rewards = milestone_rewards.checked_add(reward).ok_or(StreakError::Overflow)?;
+        points = points
+            .checked_add(player.credit_points(game_state.points_per_milestone, game_state.points_multiplier_bps, game_state.points_snapshot_id)?)
+            .ok_or(StreakError::Overflow)?;
+        tokens = tokens
+            .checked_add(game_state.token_reward(game_state.reward_per_milestone, current_time))
+            .ok_or(StreakError::Overflow)?;
+
+        emit!(MilestoneReached {
+            player: player.wallet,
+            streak_days: player.streak_days,
+            milestone_index: index as u8,
+            reward,
+        });
+        msg!("Milestone reached: {} days! Reward: {} lamports", tier.min_streak_days, reward);
+    }
+
+    player.token_rewards_owed = player.token_rewards_owed.checked_add(tokens).ok_or(StreakError::Overflow)?;
+
+    if milestone_rewards > 0 {
+        player.pending_rewards = player.pending_rewards
+            .checked_add(milestone_rewards)
+            .ok_or(StreakError::Overflow)?;
+
+        let game_state = &mut ctx.accounts.game_state;
+        game_state.total_pool = game_state.total_pool
+            .checked_sub(milestone_rewards)
+            .ok_or(StreakError::Overflow)?;
+    }
+
+    // Reaching the commitment target unlocks withdrawal and pays a bonus from the pool
+    let commitment_reached = !hold_bonuses
+        && player.commitment_days > 0
+        && !player.commitment_completed
+        && player.streak_days >= player.commitment_days;
+    let mut commitment_bonus: u64 = 0;
+    if commitment_reached {
+        let bonus_bps = player.commitment_tier().map_or(0, |(_, bonus_bps)| bonus_bps);
+        commitment_bonus = player.stake
+            .checked_mul(bonus_bps)
+            .ok_or(StreakError::Overflow)?
+            .checked_div(BPS_DENOMINATOR)
+            .ok_or(StreakError::Overflow)?;
+
+        player.commitment_completed = true;
+        player.pending_rewards = player.pending_rewards
+            .checked_add(commitment_bonus)
+            .ok_or(StreakError::Overflow)?;
+
+        let game_state = &mut ctx.accounts.game_state;
+        game_state.total_pool = game_state.total_pool
+            .checked_sub(commitment_bonus)
+            .ok_or(StreakError::Overflow)?;
+    }
+
+    // The league's pool grows with the stakes placed in it (penalties stay in it,
+    // milestone and commitment payouts come from the game's pool), and the check-in
+    // moves its leaderboard. The new stake may belong to another league's band.
+    if let Some(league) = ctx.accounts.league.as_mut() {
+        league.add_to_pool(growth)?;
+        league.record_streak(player.wallet, player.streak_days);
+        league.follow_stake(ctx.accounts.new_league.as_mut(), player)?;
+    }
 
     // Note: In a real implementation, growth might come from the pool
     // For MVP, we just increase player's stake (funded by deaths)
 
     msg!("Check-in successful! Day: {}", player.streak_days);
     msg!("Interval: {} seconds ({} minutes)", interval, interval / 60);
+    if is_late {
+        msg!("Late check-in! Penalty: {} lamports moved to pool", penalty);
+    }
+    if squad_penalty > 0 {
+        msg!("Squad penalty: {} lamports moved to pool", squad_penalty);
+    }
     msg!("New stake: {} lamports (+{} growth)", player.stake, growth);
+    if squad_bonus > 0 {
+        msg!("Squad bonus: {} lamports of that growth", squad_bonus);
+    }
+    if points > 0 {
+        msg!("Points: {} (+{})", player.points, points);
+    }
+    if box_granted {
+        msg!("Mystery box granted! Unopened boxes: {}", player.mystery_boxes);
+    }
+    match jackpot_period {
+        Some(Some(period)) => msg!("Entered the jackpot draw for period {}", period),
+        Some(None) => msg!("Jackpot draws are full, check-in not entered"),
+        None => {}
+    }
+    if commitment_reached {
+        msg!("Commitment of {} days completed! Bonus: {} lamports", player.commitment_days, commitment_bonus);
+    }
+
+    // Mint owed reward tokens if the token accounts were passed
+    if let (Some(reward_mint), Some(player_token_account), Some(token_program)) = (
+        ctx.accounts.reward_mint.as_ref(),
+        ctx.accounts.player_token_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    ) {
+        let minted = mint_token_rewards(
+            &ctx.accounts.game_state,
+            &mut ctx.accounts.player,
+            reward_mint,
+            player_token_account,
+            token_program,
+        )?;
+        if minted > 0 {
+            msg!("Minted {} reward tokens", minted);
+        }
+    }
 
     Ok(())
 }
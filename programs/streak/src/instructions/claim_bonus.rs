@@ -1,25 +1,49 @@
 use anchor_lang::prelude::*;
-use crate::state::{GameState, Player};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::{GameState, Player, League};
 use crate::constants::{GAME_STATE_SEED, PLAYER_SEED, BONUS_GROWTH_BPS, BPS_DENOMINATOR};
 use crate::errors::StreakError;
+use crate::instructions::claim_token_rewards::mint_token_rewards;
 
 #[derive(Accounts)]
 pub struct ClaimBonus<'info> {
     #[account(
-        seeds = [GAME_STATE_SEED],
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
         bump = game_state.bump
     )]
     pub game_state: Account<'info, GameState>,
 
     #[account(
         mut,
-        seeds = [PLAYER_SEED, user.key().as_ref()],
+        seeds = [PLAYER_SEED, game_state.game_seed().as_ref(), user.key().as_ref()],
         bump = player.bump,
         constraint = player.wallet == user.key()
     )]
     pub player: Account<'info, Player>,
 
     pub user: Signer<'info>,
+
+    /// Reward token accounts (optional - tokens stay owed on the player until minted)
+    #[account(mut)]
+    pub reward_mint: Option<Account<'info, Mint>>,
+
+    #[account(mut)]
+    pub player_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    #[account(
+        mut,
+        constraint = league.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub league: Option<Account<'info, League>>,
+
+    /// League to move to when bonus growth takes the stake out of the current league's band
+    #[account(
+        mut,
+        constraint = new_league.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub new_league: Option<Account<'info, League>>,
 }
 
 pub fn handler(ctx: Context<ClaimBonus>) -> Result<()> {
@@ -30,33 +54,69 @@ pub fn handler(ctx: Context<ClaimBonus>) -> Result<()> {
 
     // Validate player is active
     require!(player.is_active, StreakError::NotStaked);
+    League::check_member(ctx.accounts.league.as_ref(), player)?;
+
+    // League players claim their league's bonus windows instead of the game's
+    let (window_active, current_window) = match ctx.accounts.league.as_ref() {
+        Some(league) => (league.is_bonus_window_active(current_time), league.current_bonus_window),
+        None => (game_state.is_bonus_window_active(current_time), game_state.current_bonus_window),
+    };
 
     // Check if bonus window is active
-    require!(
-        game_state.is_bonus_window_active(current_time),
-        StreakError::NoBonusWindow
-    );
+    require!(window_active, StreakError::NoBonusWindow);
 
     // Check if player already claimed this window
-    require!(
-        player.last_bonus_claimed < game_state.current_bonus_window,
-        StreakError::AlreadyClaimed
-    );
-
-    // Apply bonus growth (0.05%)
-    let growth = player.stake
-        .checked_mul(BONUS_GROWTH_BPS)
-        .ok_or(StreakError::Overflow)?
-        .checked_div(BPS_DENOMINATOR)
-        .ok_or(StreakError::Overflow)?;
+    require!(player.last_bonus_claimed < current_window, StreakError::AlreadyClaimed);
+
+    // Apply bonus growth (0.05%), paused while the withdrawal queue is owed
+    let growth = if game_state.withdraw_queue_owed > 0 {
+        0
+    } else {
+        player.stake
+            .checked_mul(BONUS_GROWTH_BPS)
+            .ok_or(StreakError::Overflow)?
+            .checked_div(BPS_DENOMINATOR)
+            .ok_or(StreakError::Overflow)?
+    };
 
     player.stake = player.stake.checked_add(growth).ok_or(StreakError::Overflow)?;
-    player.last_bonus_claimed = game_state.current_bonus_window;
+
+    // The league's pool grows with the stake, which may move the player up
+    if let Some(league) = ctx.accounts.league.as_mut() {
+        league.add_to_pool(growth)?;
+        league.follow_stake(ctx.accounts.new_league.as_mut(), player)?;
+    }
+
+    player.last_bonus_claimed = current_window;
     player.total_bonus_claims = player.total_bonus_claims.checked_add(1).ok_or(StreakError::Overflow)?;
+    let points = player.credit_points(game_state.points_per_bonus_claim, game_state.points_multiplier_bps, game_state.points_snapshot_id)?;
+    let tokens = game_state.token_reward(game_state.reward_per_bonus_claim, current_time);
+    player.token_rewards_owed = player.token_rewards_owed.checked_add(tokens).ok_or(StreakError::Overflow)?;
 
-    msg!("Bonus claimed! Window: {}", game_state.current_bonus_window);
+    msg!("Bonus claimed! Window: {}", current_window);
     msg!("Bonus growth: {} lamports", growth);
     msg!("New stake: {} lamports", player.stake);
+    if points > 0 {
+        msg!("Points: {} (+{})", player.points, points);
+    }
+
+    // Mint owed reward tokens if the token accounts were passed
+    if let (Some(reward_mint), Some(player_token_account), Some(token_program)) = (
+        ctx.accounts.reward_mint.as_ref(),
+        ctx.accounts.player_token_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    ) {
+        let minted = mint_token_rewards(
+            &ctx.accounts.game_state,
+            &mut ctx.accounts.player,
+            reward_mint,
+            player_token_account,
+            token_program,
+        )?;
+        if minted > 0 {
+            msg!("Minted {} reward tokens", minted);
+        }
+    }
 
     Ok(())
 }
@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::{Cohort, CohortMember, GameState, Player, League};
+use crate::constants::{
+    GAME_STATE_SEED, PLAYER_SEED, COHORT_SEED, COHORT_MEMBER_SEED, COHORT_VAULT_SEED, STAKE_VAULT_SEED,
+    MAX_MERKLE_PROOF_LEN,
+};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct ClaimCohortStake<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        mut,
+        seeds = [COHORT_SEED, cohort.admin.as_ref(), &cohort.cohort_id.to_le_bytes()],
+        bump = cohort.bump,
+        constraint = cohort.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub cohort: Account<'info, Cohort>,
+
+    #[account(
+        init,
+        payer = member,
+        space = CohortMember::SIZE,
+        seeds = [COHORT_MEMBER_SEED, cohort.key().as_ref(), member.key().as_ref()],
+        bump
+    )]
+    pub cohort_member: Account<'info, CohortMember>,
+
+    #[account(
+        init_if_needed,
+        payer = member,
+        space = Player::SIZE,
+        seeds = [PLAYER_SEED, game_state.game_seed().as_ref(), member.key().as_ref()],
+        bump
+    )]
+    pub player: Account<'info, Player>,
+
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        constraint = league.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub league: Option<Account<'info, League>>,
+
+    /// Token-staked games only: the cohort's token budget
+    #[account(
+        mut,
+        seeds = [COHORT_VAULT_SEED, cohort.key().as_ref()],
+        bump
+    )]
+    pub cohort_vault: Option<Account<'info, TokenAccount>>,
+
+    /// Token-staked games only: the game's stake vault
+    #[account(
+        mut,
+        seeds = [STAKE_VAULT_SEED, game_state.game_seed().as_ref()],
+        bump
+    )]
+    pub stake_vault: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+pub fn handler(ctx: Context<ClaimCohortStake>, proof: Vec<[u8; 32]>) -> Result<()> {
+    let member_key = ctx.accounts.member.key();
+    let cohort_key = ctx.accounts.cohort.key();
+
+    // Token-staked games move the stake between token vaults
+    let is_token_game = ctx.accounts.game_state.is_token_game();
+    require!(
+        !is_token_game || (ctx.accounts.cohort_vault.is_some() && ctx.accounts.stake_vault.is_some()),
+        StreakError::MissingTokenAccounts
+    );
+    let cohort_vault = ctx.accounts.cohort_vault.as_ref().filter(|_| is_token_game);
+
+    // Validate membership
+    require!(proof.len() <= MAX_MERKLE_PROOF_LEN, StreakError::NotCohortMember);
+    require!(
+        ctx.accounts.cohort.is_member(&member_key, &proof),
+        StreakError::NotCohortMember
+    );
+
+    let player = &mut ctx.accounts.player;
+
+    // Member can't already be playing
+    if player.wallet != Pubkey::default() && player.is_active {
+        return Err(StreakError::AlreadyStaked.into());
+    }
+
+    // Validate the cohort can still fund a stake
+    let amount = ctx.accounts.cohort.stake_per_member;
+    let cohort_info = ctx.accounts.cohort.to_account_info();
+    require!(
+        Cohort::budget(&cohort_info, cohort_vault)? >= amount,
+        StreakError::CohortBudgetExhausted
+    );
+
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+    let interval = ctx.accounts.game_state.checkin_interval_seconds;
+    let deadline_offset = ctx.accounts.cohort.deadline_offset;
+
+    // Start this period, or the next one inside the grace period
+    let start_day = Player::start_day_for(current_time, interval, deadline_offset);
+
+    // Move the stake from the cohort budget into the game's vault
+    let destination = match ctx.accounts.stake_vault.as_ref() {
+        Some(stake_vault) if is_token_game => stake_vault.to_account_info(),
+        _ => ctx.accounts.game_state.to_account_info(),
+    };
+    Cohort::pay_out(
+        &ctx.accounts.cohort,
+        cohort_vault,
+        ctx.accounts.token_program.as_ref(),
+        &destination,
+        amount,
+    )?;
+
+    // Initialize/reset the member's player account as a stake sponsored by the cohort
+    player.begin_stake(
+        member_key,
+        ctx.accounts.game_state.game_id,
+        amount,
+        current_time,
+        start_day,
+        deadline_offset,
+        0,
+    );
+    player.bump = ctx.bumps.player;
+    player.sponsor = Some(cohort_key);
+    player.withdraw_to_sponsor = ctx.accounts.cohort.withdraw_to_sponsor;
+    player.sponsor_refund_bps = ctx.accounts.cohort.death_refund_bps;
+    League::place(&ctx.accounts.game_state, ctx.accounts.league.as_mut(), player)?;
+
+    // Record the claim
+    let cohort_member = &mut ctx.accounts.cohort_member;
+    cohort_member.cohort = cohort_key;
+    cohort_member.wallet = member_key;
+    cohort_member.claimed_at = current_time;
+    cohort_member.start_day = start_day;
+    cohort_member.is_active = true;
+    cohort_member.last_growth = 0;
+    cohort_member.bump = ctx.bumps.cohort_member;
+
+    let cohort = &mut ctx.accounts.cohort;
+    cohort.total_funded = cohort.total_funded.checked_add(amount).ok_or(StreakError::Overflow)?;
+    cohort.members_claimed = cohort.members_claimed.checked_add(1).ok_or(StreakError::Overflow)?;
+    cohort.active_members = cohort.active_members.checked_add(1).ok_or(StreakError::Overflow)?;
+
+    // Update game state
+    let game_state = &mut ctx.accounts.game_state;
+    game_state.total_players = game_state.total_players.checked_add(1).ok_or(StreakError::Overflow)?;
+    game_state.total_pool = game_state.total_pool.checked_add(amount).ok_or(StreakError::Overflow)?;
+
+    msg!("Member {} claimed a {} lamport stake from cohort {}", member_key, amount, cohort.cohort_id);
+    msg!("Start day: {}", start_day);
+
+    Ok(())
+}
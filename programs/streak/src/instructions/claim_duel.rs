@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use crate::state::{Duel, GameState, Player};
+use crate::constants::{GAME_STATE_SEED, PLAYER_SEED, DUEL_SEED};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct ClaimDuel<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    /// CHECK: Treasury receives the protocol fee when the duel is settled here
+    #[account(
+        mut,
+        constraint = treasury.key() == game_state.treasury
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [DUEL_SEED, game_state.game_seed().as_ref(), duel.challenger.as_ref(), &duel.duel_id.to_le_bytes()],
+        bump = duel.bump
+    )]
+    pub duel: Account<'info, Duel>,
+
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, game_state.game_seed().as_ref(), duel.challenger.as_ref()],
+        bump = challenger_player.bump
+    )]
+    pub challenger_player: Account<'info, Player>,
+
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, game_state.game_seed().as_ref(), duel.opponent.as_ref()],
+        bump = opponent_player.bump
+    )]
+    pub opponent_player: Account<'info, Player>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// CHECK: Challenger paid for the duel account and gets its rent back after the last payout
+    #[account(mut, address = duel.challenger)]
+    pub challenger: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<ClaimDuel>) -> Result<()> {
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+    let duel_info = ctx.accounts.duel.to_account_info();
+    let duel = &mut ctx.accounts.duel;
+
+    // Duels nobody died in are settled once they end
+    if !duel.settled {
+        require!(current_time >= duel.ends_at, StreakError::DuelNotEnded);
+        let fee = duel.settle(
+            &mut ctx.accounts.challenger_player,
+            &mut ctx.accounts.opponent_player,
+            &ctx.accounts.game_state,
+            current_time,
+        )?;
+        Duel::transfer_lamports(&duel_info, &ctx.accounts.treasury.to_account_info(), fee)?;
+    }
+
+    let user_key = ctx.accounts.user.key();
+    let payout = duel.payout_for(&user_key)?;
+    require!(payout > 0, StreakError::NothingToClaim);
+
+    Duel::transfer_lamports(&duel_info, &ctx.accounts.user.to_account_info(), payout)?;
+    if user_key == duel.challenger {
+        duel.challenger_claimed = true;
+    } else {
+        duel.opponent_claimed = true;
+    }
+
+    match duel.winner {
+        Some(_) => msg!("Duel won! Claimed {} lamports", payout),
+        None => msg!("Duel drawn, wager of {} lamports refunded", payout),
+    }
+
+    // Once nobody is owed anything the account is closed
+    if duel.payout_for(&duel.challenger)? == 0 && duel.payout_for(&duel.opponent)? == 0 {
+        duel.close(ctx.accounts.challenger.to_account_info())?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameState, Jackpot, Player};
+use crate::constants::{GAME_STATE_SEED, PLAYER_SEED, JACKPOT_SEED};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct ClaimJackpot<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        mut,
+        seeds = [JACKPOT_SEED, game_state.game_seed().as_ref()],
+        bump = jackpot.bump
+    )]
+    pub jackpot: Account<'info, Jackpot>,
+
+    /// Winner's player account (anyone can crank the credit)
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, game_state.game_seed().as_ref(), player.wallet.as_ref()],
+        bump = player.bump
+    )]
+    pub player: Account<'info, Player>,
+}
+
+pub fn handler(ctx: Context<ClaimJackpot>) -> Result<()> {
+    let player = &mut ctx.accounts.player;
+
+    // The jackpot balance already left total_pool when deaths fed it
+    let won = ctx.accounts.jackpot.take_prizes(&player.wallet)?;
+    require!(won > 0, StreakError::NoRewards);
+
+    player.pending_rewards = player.pending_rewards.checked_add(won).ok_or(StreakError::Overflow)?;
+
+    msg!("Jackpot winnings of {} lamports added to pending rewards", won);
+
+    Ok(())
+}
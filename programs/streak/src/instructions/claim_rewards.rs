@@ -1,20 +1,31 @@
 use anchor_lang::prelude::*;
-use crate::state::{GameState, Player};
-use crate::constants::{GAME_STATE_SEED, PLAYER_SEED};
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::{GameState, Player, WithdrawQueue};
+use crate::constants::{GAME_STATE_SEED, PLAYER_SEED, WITHDRAW_QUEUE_SEED, STAKE_VAULT_SEED};
 use crate::errors::StreakError;
+use crate::vault::Vault;
 
 #[derive(Accounts)]
 pub struct ClaimRewards<'info> {
     #[account(
         mut,
-        seeds = [GAME_STATE_SEED],
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
         bump = game_state.bump
     )]
     pub game_state: Account<'info, GameState>,
 
+    /// Required while the queue is owed anything; without it (or once it is full)
+    /// the unpaid part stays in pending rewards
     #[account(
         mut,
-        seeds = [PLAYER_SEED, user.key().as_ref()],
+        seeds = [WITHDRAW_QUEUE_SEED, game_state.game_seed().as_ref()],
+        bump = withdraw_queue.bump
+    )]
+    pub withdraw_queue: Option<Account<'info, WithdrawQueue>>,
+
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, game_state.game_seed().as_ref(), user.key().as_ref()],
         bump = player.bump,
         constraint = player.wallet == user.key()
     )]
@@ -24,35 +35,85 @@ pub struct ClaimRewards<'info> {
     pub user: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Token-staked games only: the game's stake vault
+    #[account(
+        mut,
+        seeds = [STAKE_VAULT_SEED, game_state.game_seed().as_ref()],
+        bump
+    )]
+    pub stake_vault: Option<Account<'info, TokenAccount>>,
+
+    /// Token-staked games only: the user's token account for the stake mint
+    #[account(mut)]
+    pub user_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
 }
 
 pub fn handler(ctx: Context<ClaimRewards>) -> Result<()> {
     let player = &mut ctx.accounts.player;
-    let game_state = &ctx.accounts.game_state;
 
     // Validate there are rewards to claim
     require!(player.pending_rewards > 0, StreakError::NoRewards);
 
     let rewards = player.pending_rewards;
+    require!(
+        ctx.accounts.withdraw_queue.is_some() || ctx.accounts.game_state.withdraw_queue_owed == 0,
+        StreakError::WithdrawQueuePending
+    );
 
-    // Transfer SOL from game state PDA to user
+    // Pay what the vault can cover after anything already queued;
+    // the remainder joins the back of the withdrawal queue
     let game_state_info = ctx.accounts.game_state.to_account_info();
-    let user_info = ctx.accounts.user.to_account_info();
+    let vault = Vault::load(
+        &ctx.accounts.game_state,
+        ctx.accounts.stake_vault.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    )?;
+    let recipient = vault.recipient(
+        &ctx.accounts.user.to_account_info(),
+        ctx.accounts.user_token_account.as_ref(),
+    )?;
+    let available = vault.available(&game_state_info)?
+        .saturating_sub(ctx.accounts.game_state.withdraw_queue_owed);
+    let paid = rewards.min(available);
+    let mut queued = rewards - paid;
+    let mut kept_pending: u64 = 0;
+
+    if queued > 0 {
+        match ctx.accounts.withdraw_queue.as_mut() {
+            Some(queue) if queue.has_room() => queue.enqueue(ctx.accounts.user.key(), queued)?,
+            // Nothing to queue into - keep the rest claimable once the vault refills
+            _ => {
+                require!(paid > 0, StreakError::InsufficientFunds);
+                kept_pending = queued;
+                queued = 0;
+            }
+        }
+    }
 
-    **game_state_info.try_borrow_mut_lamports()? = game_state_info
-        .lamports()
-        .checked_sub(rewards)
-        .ok_or(StreakError::InsufficientFunds)?;
+    vault.pay_out(
+        &game_state_info,
+        &recipient,
+        ctx.accounts.user.key(),
+        paid,
+    )?;
 
-    **user_info.try_borrow_mut_lamports()? = user_info
-        .lamports()
-        .checked_add(rewards)
-        .ok_or(StreakError::Overflow)?;
+    // Reset pending rewards (down to whatever couldn't be paid or queued)
+    player.pending_rewards = kept_pending;
 
-    // Reset pending rewards
-    player.pending_rewards = 0;
+    if let Some(withdraw_queue) = ctx.accounts.withdraw_queue.as_ref() {
+        ctx.accounts.game_state.withdraw_queue_owed = withdraw_queue.total_owed;
+    }
 
-    msg!("Claimed {} lamports in rewards", rewards);
+    msg!("Claimed {} lamports in rewards", paid);
+    if queued > 0 {
+        msg!("Vault short - {} lamports added to the withdrawal queue", queued);
+    }
+    if kept_pending > 0 {
+        msg!("Vault short - {} lamports left in pending rewards", kept_pending);
+    }
 
     Ok(())
 }
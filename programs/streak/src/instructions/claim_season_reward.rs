@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameState, Player, Season};
+use crate::constants::{GAME_STATE_SEED, PLAYER_SEED, SEASON_SEED};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct ClaimSeasonReward<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        mut,
+        seeds = [SEASON_SEED, game_state.game_seed().as_ref(), &season.season_id.to_le_bytes()],
+        bump = season.bump
+    )]
+    pub season: Account<'info, Season>,
+
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, game_state.game_seed().as_ref(), user.key().as_ref()],
+        bump = player.bump,
+        constraint = player.wallet == user.key()
+    )]
+    pub player: Account<'info, Player>,
+
+    pub user: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ClaimSeasonReward>) -> Result<()> {
+    let season = &mut ctx.accounts.season;
+    let player = &mut ctx.accounts.player;
+
+    require!(season.finalized, StreakError::SeasonNotEnded);
+    require!(!season.swept, StreakError::SeasonClaimsClosed);
+    require!(
+        player.season_id == season.season_id && player.season_stake > 0,
+        StreakError::NoSeasonReward
+    );
+
+    // Entrants whose streak broke before the end (even if their death was never
+    // processed during the season) don't share the payout
+    let game_state = &ctx.accounts.game_state;
+    let interval = game_state.checkin_interval_seconds;
+    let season_end = season.end_period.checked_mul(interval).ok_or(StreakError::Overflow)?;
+    let current_time = Clock::get()?.unix_timestamp;
+    if let Some(broken_at) = player.streak_broken_at(current_time, interval, game_state.late_window_seconds) {
+        require!(broken_at >= season_end, StreakError::SeasonNotSurvived);
+    }
+
+    // Pro-rata by entry stake (u128 so large pools and stakes can't overflow)
+    let share = (season.payout_pool as u128)
+        .checked_mul(player.season_stake as u128)
+        .ok_or(StreakError::Overflow)?
+        .checked_div(season.survivor_stake as u128)
+        .ok_or(StreakError::Overflow)? as u64;
+
+    player.season_stake = 0;
+    player.seasons_survived = player.seasons_survived.checked_add(1).ok_or(StreakError::Overflow)?;
+    player.season_rewards = player.season_rewards.checked_add(share).ok_or(StreakError::Overflow)?;
+    player.pending_rewards = player.pending_rewards.checked_add(share).ok_or(StreakError::Overflow)?;
+
+    season.total_claimed = season.total_claimed.checked_add(share).ok_or(StreakError::Overflow)?;
+
+    msg!("Survived season {}! Reward: {} lamports", season.season_id, share);
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameState, SponsorPool, SponsorClaim};
+use crate::constants::{GAME_STATE_SEED, SPONSOR_POOL_SEED, SPONSOR_CLAIM_SEED};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct ClaimSponsorReward<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        mut,
+        seeds = [
+            SPONSOR_POOL_SEED,
+            game_state.game_seed().as_ref(),
+            sponsor_pool.sponsor.as_ref(),
+            &sponsor_pool.pool_id.to_le_bytes()
+        ],
+        bump = sponsor_pool.bump
+    )]
+    pub sponsor_pool: Account<'info, SponsorPool>,
+
+    /// Entry made with enter_sponsor_pool
+    #[account(
+        mut,
+        seeds = [SPONSOR_CLAIM_SEED, sponsor_pool.key().as_ref(), user.key().as_ref()],
+        bump = sponsor_claim.bump
+    )]
+    pub sponsor_claim: Account<'info, SponsorClaim>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ClaimSponsorReward>) -> Result<()> {
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+    let pool_info = ctx.accounts.sponsor_pool.to_account_info();
+    let user_info = ctx.accounts.user.to_account_info();
+    let sponsor_pool = &mut ctx.accounts.sponsor_pool;
+
+    require!(current_time >= sponsor_pool.entries_close_at, StreakError::SponsorClaimsNotOpen);
+    require!(current_time < sponsor_pool.expires_at, StreakError::SponsorPoolExpired);
+    require!(ctx.accounts.sponsor_claim.claimed_at == 0, StreakError::AlreadyClaimed);
+
+    // Eligibility was checked on entry; every entrant gets an equal share
+    let reward = sponsor_pool.share(&pool_info)?;
+    require!(
+        SponsorPool::available_budget(&pool_info)? >= reward,
+        StreakError::SponsorPoolExhausted
+    );
+
+    **pool_info.try_borrow_mut_lamports()? = pool_info
+        .lamports()
+        .checked_sub(reward)
+        .ok_or(StreakError::InsufficientFunds)?;
+
+    **user_info.try_borrow_mut_lamports()? = user_info
+        .lamports()
+        .checked_add(reward)
+        .ok_or(StreakError::Overflow)?;
+
+    let sponsor_claim = &mut ctx.accounts.sponsor_claim;
+    sponsor_claim.amount = reward;
+    sponsor_claim.claimed_at = current_time;
+
+    sponsor_pool.total_claimed = sponsor_pool.total_claimed.checked_add(reward).ok_or(StreakError::Overflow)?;
+    sponsor_pool.claims = sponsor_pool.claims.checked_add(1).ok_or(StreakError::Overflow)?;
+
+    msg!("Claimed {} lamports from sponsor pool {}", reward, sponsor_pool.pool_id);
+
+    Ok(())
+}
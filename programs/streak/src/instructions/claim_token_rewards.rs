@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+use crate::state::{GameState, Player};
+use crate::constants::{GAME_STATE_SEED, PLAYER_SEED};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct ClaimTokenRewards<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, game_state.game_seed().as_ref(), user.key().as_ref()],
+        bump = player.bump,
+        constraint = player.wallet == user.key()
+    )]
+    pub player: Account<'info, Player>,
+
+    #[account(mut)]
+    pub reward_mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub player_token_account: Account<'info, TokenAccount>,
+
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<ClaimTokenRewards>) -> Result<()> {
+    require!(ctx.accounts.player.token_rewards_owed > 0, StreakError::NoTokenRewards);
+
+    let minted = mint_token_rewards(
+        &ctx.accounts.game_state,
+        &mut ctx.accounts.player,
+        &ctx.accounts.reward_mint,
+        &ctx.accounts.player_token_account,
+        &ctx.accounts.token_program,
+    )?;
+
+    msg!("Minted {} reward tokens", minted);
+
+    Ok(())
+}
+
+/// Mint a player's owed reward tokens to their associated token account,
+/// signed by the game state PDA (the mint authority)
+pub(crate) fn mint_token_rewards<'info>(
+    game_state: &Account<'info, GameState>,
+    player: &mut Account<'info, Player>,
+    reward_mint: &Account<'info, Mint>,
+    player_token_account: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+) -> Result<u64> {
+    let amount = player.token_rewards_owed;
+    if amount == 0 {
+        return Ok(0);
+    }
+
+    require!(reward_mint.key() == game_state.reward_mint, StreakError::InvalidRewardMint);
+    require!(
+        player_token_account.key() == get_associated_token_address(&player.wallet, &reward_mint.key()),
+        StreakError::InvalidRewardTokenAccount
+    );
+
+    let game_seed = game_state.game_seed();
+    let signer_seeds: &[&[&[u8]]] = &[&[GAME_STATE_SEED, &game_seed, &[game_state.bump]]];
+    let cpi_context = CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        MintTo {
+            mint: reward_mint.to_account_info(),
+            to: player_token_account.to_account_info(),
+            authority: game_state.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token::mint_to(cpi_context, amount)?;
+
+    player.token_rewards_owed = 0;
+    player.total_tokens_minted = player.total_tokens_minted
+        .checked_add(amount)
+        .ok_or(StreakError::Overflow)?;
+
+    Ok(amount)
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameState, Tournament, TournamentEntry};
+use crate::constants::{GAME_STATE_SEED, TOURNAMENT_SEED, TOURNAMENT_ENTRY_SEED};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct ClaimTournamentPrize<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        mut,
+        seeds = [TOURNAMENT_SEED, game_state.game_seed().as_ref(), &tournament.tournament_id.to_le_bytes()],
+        bump = tournament.bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(
+        mut,
+        seeds = [TOURNAMENT_ENTRY_SEED, tournament.key().as_ref(), user.key().as_ref()],
+        bump = entry.bump
+    )]
+    pub entry: Account<'info, TournamentEntry>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ClaimTournamentPrize>) -> Result<()> {
+    let tournament = &ctx.accounts.tournament;
+    let entry = &ctx.accounts.entry;
+
+    require!(tournament.finished, StreakError::TournamentRunning);
+    require!(!entry.claimed, StreakError::NotTournamentWinner);
+
+    // Survivors were checked at the finish; tied entries must have gone out
+    // in the final elimination period
+    let is_winner = if tournament.survivors_at_finish > 0 {
+        entry.is_alive
+    } else {
+        !entry.is_alive && entry.death_period == tournament.last_death_period
+    };
+    require!(is_winner, StreakError::NotTournamentWinner);
+
+    let prize = tournament.prize_per_winner;
+    let tournament_info = ctx.accounts.tournament.to_account_info();
+    let user_info = ctx.accounts.user.to_account_info();
+
+    **tournament_info.try_borrow_mut_lamports()? = tournament_info
+        .lamports()
+        .checked_sub(prize)
+        .ok_or(StreakError::InsufficientFunds)?;
+
+    **user_info.try_borrow_mut_lamports()? = user_info
+        .lamports()
+        .checked_add(prize)
+        .ok_or(StreakError::Overflow)?;
+
+    ctx.accounts.entry.claimed = true;
+    let tournament = &mut ctx.accounts.tournament;
+    tournament.prizes_claimed = tournament.prizes_claimed.checked_add(1).ok_or(StreakError::Overflow)?;
+
+    msg!("Tournament prize claimed: {} lamports", prize);
+
+    Ok(())
+}
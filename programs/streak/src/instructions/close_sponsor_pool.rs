@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameState, SponsorPool};
+use crate::constants::SPONSOR_POOL_SEED;
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct CloseSponsorPool<'info> {
+    /// Closing returns the remainder and rent to the sponsor
+    #[account(
+        mut,
+        close = sponsor,
+        seeds = [
+            SPONSOR_POOL_SEED,
+            GameState::seed_for(sponsor_pool.game_id).as_ref(),
+            sponsor.key().as_ref(),
+            &sponsor_pool.pool_id.to_le_bytes()
+        ],
+        bump = sponsor_pool.bump,
+        has_one = sponsor @ StreakError::InvalidSponsor
+    )]
+    pub sponsor_pool: Account<'info, SponsorPool>,
+
+    /// CHECK: Validated against sponsor_pool.sponsor
+    #[account(mut)]
+    pub sponsor: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CloseSponsorPool>) -> Result<()> {
+    let clock = Clock::get()?;
+    let sponsor_pool = &ctx.accounts.sponsor_pool;
+
+    require!(clock.unix_timestamp >= sponsor_pool.expires_at, StreakError::SponsorPoolNotExpired);
+
+    let remainder = SponsorPool::available_budget(&sponsor_pool.to_account_info())?;
+
+    msg!("Sponsor pool {} closed after {} claims", sponsor_pool.pool_id, sponsor_pool.claims);
+    msg!("Returned {} lamports to the sponsor", remainder);
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameState, Tournament};
+use crate::constants::{GAME_STATE_SEED, TOURNAMENT_SEED};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct CloseTournament<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump,
+        has_one = authority @ StreakError::Unauthorized
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    /// Closing returns the rounding dust and rent to the authority
+    #[account(
+        mut,
+        close = authority,
+        seeds = [TOURNAMENT_SEED, game_state.game_seed().as_ref(), &tournament.tournament_id.to_le_bytes()],
+        bump = tournament.bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CloseTournament>) -> Result<()> {
+    let tournament = &ctx.accounts.tournament;
+
+    require!(tournament.finished, StreakError::TournamentRunning);
+    require!(
+        tournament.prizes_claimed == tournament.winner_count,
+        StreakError::TournamentPrizesUnclaimed
+    );
+
+    // Whatever the even split couldn't hand out is left over
+    let rent = Rent::get()?.minimum_balance(Tournament::SIZE);
+    let dust = tournament.to_account_info().lamports().saturating_sub(rent);
+
+    msg!("Tournament {} closed after {} prizes", tournament.tournament_id, tournament.prizes_claimed);
+    msg!("Returned {} lamports of dust to the authority", dust);
+
+    Ok(())
+}
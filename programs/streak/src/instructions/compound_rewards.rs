@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameState, Player, League};
+use crate::constants::{GAME_STATE_SEED, PLAYER_SEED};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct CompoundRewards<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, game_state.game_seed().as_ref(), user.key().as_ref()],
+        bump = player.bump,
+        constraint = player.wallet == user.key()
+    )]
+    pub player: Account<'info, Player>,
+
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = league.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub league: Option<Account<'info, League>>,
+
+    /// League to move to when the stake leaves the current league's band
+    #[account(
+        mut,
+        constraint = new_league.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub new_league: Option<Account<'info, League>>,
+}
+
+pub fn handler(ctx: Context<CompoundRewards>) -> Result<()> {
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+    let interval = ctx.accounts.game_state.checkin_interval_seconds;
+
+    let player = &mut ctx.accounts.player;
+
+    // Validate player is active and alive
+    require!(player.is_active, StreakError::NotStaked);
+    League::check_member(ctx.accounts.league.as_ref(), player)?;
+    let current_period = player.period_at(current_time, interval);
+    require!(
+        !player.has_missed_checkin(current_period, interval),
+        StreakError::PlayerDead
+    );
+
+    // Validate there are rewards to compound
+    require!(player.pending_rewards > 0, StreakError::NoRewards);
+
+    let rewards = player.pending_rewards;
+    player.pending_rewards = 0;
+    player.compound(rewards)?;
+
+    // Compounding counts as a top-up and may move the player up a league
+    if let Some(league) = ctx.accounts.league.as_mut() {
+        league.add_to_pool(rewards)?;
+        league.follow_stake(ctx.accounts.new_league.as_mut(), player)?;
+    }
+
+    // Rewards re-enter the pool as stake
+    let game_state = &mut ctx.accounts.game_state;
+    game_state.total_pool = game_state.total_pool.checked_add(rewards).ok_or(StreakError::Overflow)?;
+
+    msg!("Compounded {} lamports into stake", rewards);
+    msg!("New stake: {} lamports", player.stake);
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameState, Player, Squad};
+use crate::constants::{GAME_STATE_SEED, PLAYER_SEED, SQUAD_SEED};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct ContributeSquadLifeline<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        mut,
+        seeds = [SQUAD_SEED, game_state.game_seed().as_ref(), &squad.squad_id.to_le_bytes()],
+        bump = squad.bump
+    )]
+    pub squad: Account<'info, Squad>,
+
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, game_state.game_seed().as_ref(), user.key().as_ref()],
+        bump = player.bump,
+        constraint = player.wallet == user.key(),
+        constraint = player.squad == Some(squad.key()) @ StreakError::WrongSquad
+    )]
+    pub player: Account<'info, Player>,
+
+    pub user: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ContributeSquadLifeline>) -> Result<()> {
+    let squad = &mut ctx.accounts.squad;
+    let player = &mut ctx.accounts.player;
+
+    require!(player.lifelines > 0, StreakError::NoLifelines);
+
+    player.lifelines -= 1;
+    squad.lifelines = squad.lifelines.checked_add(1).ok_or(StreakError::Overflow)?;
+
+    msg!("Lifeline moved to squad {} pool ({} available)", squad.squad_id, squad.lifelines);
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::GameState;
+use crate::constants::{GAME_STATE_SEED, STAKE_VAULT_SEED, MAIN_GAME_ID};
+use crate::errors::StreakError;
+use crate::instructions::initialize::{init_game_state, set_stake_mint};
+
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct CreateArena<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = GameState::SIZE,
+        seeds = [GAME_STATE_SEED, GameState::seed_for(game_id).as_ref()],
+        bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    /// CHECK: Treasury wallet to receive this arena's protocol fees
+    pub treasury: UncheckedAccount<'info>,
+
+    /// Becomes the arena's authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Token-staked arenas only: the SPL token the arena stakes (fixed for its lifetime)
+    pub stake_mint: Option<Account<'info, Mint>>,
+
+    /// Token-staked arenas only: the arena's stake vault, owned by its game state PDA
+    #[account(
+        init,
+        payer = authority,
+        seeds = [STAKE_VAULT_SEED, GameState::seed_for(game_id).as_ref()],
+        bump,
+        token::mint = stake_mint,
+        token::authority = game_state
+    )]
+    pub stake_vault: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+pub fn handler(ctx: Context<CreateArena>, game_id: u64) -> Result<()> {
+    // The main game lives at the original singleton address (see initialize)
+    require!(game_id != MAIN_GAME_ID, StreakError::InvalidGameId);
+
+    init_game_state(
+        &mut ctx.accounts.game_state,
+        ctx.accounts.authority.key(),
+        ctx.accounts.treasury.key(),
+        ctx.bumps.game_state,
+        game_id,
+    );
+    set_stake_mint(
+        &mut ctx.accounts.game_state,
+        ctx.accounts.stake_mint.as_ref(),
+        ctx.accounts.stake_vault.as_ref(),
+    )?;
+
+    let game_state = &ctx.accounts.game_state;
+    msg!("Arena {} created with authority: {}", game_id, game_state.authority);
+    msg!("Treasury: {}", game_state.treasury);
+    msg!("Check-in interval: {} seconds", game_state.checkin_interval_seconds);
+    if game_state.is_token_game() {
+        msg!("Stakes mint {}", game_state.stake_mint);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::{Cohort, GameState};
+use crate::constants::{
+    GAME_STATE_SEED, COHORT_SEED, COHORT_VAULT_SEED, MIN_STAKE, MAX_DEADLINE_OFFSET_SECONDS, BPS_DENOMINATOR,
+};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+#[instruction(cohort_id: u64)]
+pub struct CreateCohort<'info> {
+    /// Arena members will stake into
+    #[account(
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = Cohort::SIZE,
+        seeds = [COHORT_SEED, admin.key().as_ref(), &cohort_id.to_le_bytes()],
+        bump
+    )]
+    pub cohort: Account<'info, Cohort>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Token-staked games only: the game's stake mint
+    #[account(address = game_state.stake_mint @ StreakError::InvalidTokenAccount)]
+    pub stake_mint: Option<Account<'info, Mint>>,
+
+    /// Token-staked games only: the cohort's token budget, owned by the cohort PDA
+    #[account(
+        init,
+        payer = admin,
+        seeds = [COHORT_VAULT_SEED, cohort.key().as_ref()],
+        bump,
+        token::mint = stake_mint,
+        token::authority = cohort
+    )]
+    pub cohort_vault: Option<Account<'info, TokenAccount>>,
+
+    /// Token-staked games only: the admin's token account for the stake mint
+    #[account(mut)]
+    pub admin_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn handler(
+    ctx: Context<CreateCohort>,
+    cohort_id: u64,
+    merkle_root: [u8; 32],
+    stake_per_member: u64,
+    deadline_offset: i64,
+    withdraw_to_sponsor: bool,
+    death_refund_bps: u64,
+    budget: u64,
+) -> Result<()> {
+    // Validate shared stake rules
+    require!(stake_per_member >= MIN_STAKE, StreakError::BelowMinimumStake);
+    require!(
+        (0..MAX_DEADLINE_OFFSET_SECONDS).contains(&deadline_offset),
+        StreakError::InvalidDeadlineOffset
+    );
+    require!(death_refund_bps <= BPS_DENOMINATOR, StreakError::InvalidBps);
+
+    // Token-staked games keep the budget in the cohort's own token vault
+    require!(
+        ctx.accounts.cohort_vault.is_some() == ctx.accounts.game_state.is_token_game(),
+        StreakError::MissingTokenAccounts
+    );
+
+    // Deposit the starting budget into the cohort
+    if budget > 0 {
+        Cohort::deposit(
+            &ctx.accounts.cohort.to_account_info(),
+            ctx.accounts.cohort_vault.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+            &ctx.accounts.admin.to_account_info(),
+            ctx.accounts.admin_token_account.as_ref(),
+            &ctx.accounts.system_program.to_account_info(),
+            budget,
+        )?;
+    }
+
+    let cohort = &mut ctx.accounts.cohort;
+    cohort.admin = ctx.accounts.admin.key();
+    cohort.cohort_id = cohort_id;
+    cohort.merkle_root = merkle_root;
+    cohort.stake_per_member = stake_per_member;
+    cohort.deadline_offset = deadline_offset;
+    cohort.withdraw_to_sponsor = withdraw_to_sponsor;
+    cohort.death_refund_bps = death_refund_bps;
+    cohort.total_funded = 0;
+    cohort.members_claimed = 0;
+    cohort.active_members = 0;
+    cohort.deaths = 0;
+    cohort.total_growth = 0;
+    cohort.bump = ctx.bumps.cohort;
+    cohort.game_id = ctx.accounts.game_state.game_id;
+
+    msg!("Cohort {} created with a {} lamport budget", cohort_id, budget);
+    msg!("Stake per member: {} lamports", stake_per_member);
+
+    Ok(())
+}
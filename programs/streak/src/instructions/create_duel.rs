@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{Duel, GameState, Player};
+use crate::constants::{GAME_STATE_SEED, PLAYER_SEED, DUEL_SEED};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+#[instruction(duel_id: u64)]
+pub struct CreateDuel<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        init,
+        payer = challenger,
+        space = Duel::SIZE,
+        seeds = [DUEL_SEED, game_state.game_seed().as_ref(), challenger.key().as_ref(), &duel_id.to_le_bytes()],
+        bump
+    )]
+    pub duel: Account<'info, Duel>,
+
+    #[account(
+        seeds = [PLAYER_SEED, game_state.game_seed().as_ref(), challenger.key().as_ref()],
+        bump = challenger_player.bump
+    )]
+    pub challenger_player: Account<'info, Player>,
+
+    #[account(
+        seeds = [PLAYER_SEED, game_state.game_seed().as_ref(), opponent.key().as_ref()],
+        bump = opponent_player.bump
+    )]
+    pub opponent_player: Account<'info, Player>,
+
+    /// CHECK: Only seeds the opponent's player PDA
+    pub opponent: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub challenger: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateDuel>, duel_id: u64, wager: u64, ends_at: i64) -> Result<()> {
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+
+    require!(wager > 0 && ends_at > current_time, StreakError::InvalidDuel);
+    require!(
+        ctx.accounts.opponent.key() != ctx.accounts.challenger.key(),
+        StreakError::InvalidDuel
+    );
+    require!(
+        ctx.accounts.challenger_player.is_active && ctx.accounts.opponent_player.is_active,
+        StreakError::NotStaked
+    );
+
+    // Hold the challenger's wager on the duel account until the opponent accepts
+    let cpi_context = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        system_program::Transfer {
+            from: ctx.accounts.challenger.to_account_info(),
+            to: ctx.accounts.duel.to_account_info(),
+        },
+    );
+    system_program::transfer(cpi_context, wager)?;
+
+    let duel = &mut ctx.accounts.duel;
+    duel.game_id = ctx.accounts.game_state.game_id;
+    duel.duel_id = duel_id;
+    duel.challenger = ctx.accounts.challenger.key();
+    duel.opponent = ctx.accounts.opponent.key();
+    duel.wager = wager;
+    duel.ends_at = ends_at;
+    duel.bump = ctx.bumps.duel;
+
+    msg!("{} challenged {} to a duel for {} lamports", duel.challenger, duel.opponent, wager);
+    msg!("Duel ends at {}", ends_at);
+
+    Ok(())
+}
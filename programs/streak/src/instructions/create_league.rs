@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameState, League};
+use crate::constants::{GAME_STATE_SEED, LEAGUE_SEED, MAX_LEAGUES, MIN_STAKE};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct CreateLeague<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump,
+        has_one = authority @ StreakError::Unauthorized
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    /// Leagues are numbered in creation order, starting at 1
+    #[account(
+        init,
+        payer = authority,
+        space = League::SIZE,
+        seeds = [LEAGUE_SEED, game_state.game_seed().as_ref(), &[game_state.league_count + 1]],
+        bump
+    )]
+    pub league: Account<'info, League>,
+
+    /// The latest league so far (required once the game has one): the new band starts where it ends
+    #[account(
+        seeds = [LEAGUE_SEED, game_state.game_seed().as_ref(), &[game_state.league_count]],
+        bump = previous_league.bump
+    )]
+    pub previous_league: Option<Account<'info, League>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateLeague>, min_stake: u64, max_stake: u64) -> Result<()> {
+    let game_state = &mut ctx.accounts.game_state;
+
+    // New stakes are placed from now on; players already staked opt in with join_league
+    require!(game_state.league_count < MAX_LEAGUES, StreakError::InvalidLeague);
+    require!(min_stake >= MIN_STAKE, StreakError::BelowMinimumStake);
+    require!(max_stake == 0 || max_stake > min_stake, StreakError::InvalidLeague);
+
+    // Bands stack without gaps or overlaps: the first starts at the minimum stake,
+    // each later one where the previous (bounded) band ends
+    match ctx.accounts.previous_league.as_ref() {
+        Some(previous) => require!(
+            previous.max_stake != 0 && previous.max_stake == min_stake,
+            StreakError::InvalidLeague
+        ),
+        None => require!(
+            game_state.league_count == 0 && min_stake == MIN_STAKE,
+            StreakError::InvalidLeague
+        ),
+    }
+
+    game_state.league_count += 1;
+
+    let league = &mut ctx.accounts.league;
+    league.game_id = game_state.game_id;
+    league.league_id = game_state.league_count;
+    league.min_stake = min_stake;
+    league.max_stake = max_stake;
+    league.total_pool = 0;
+    league.active_players = 0;
+    league.total_deaths = 0;
+    league.total_death_pool = 0;
+    league.current_bonus_window = 0;
+    league.bonus_window_end = 0;
+    league.bump = ctx.bumps.league;
+
+    msg!("League {} created for stakes from {} lamports", league.league_id, min_stake);
+    if max_stake > 0 {
+        msg!("Up to {} lamports (exclusive)", max_stake);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameState, SponsorPool};
+use crate::constants::{GAME_STATE_SEED, SPONSOR_POOL_SEED};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+#[instruction(pool_id: u64)]
+pub struct CreateSponsorPool<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        init,
+        payer = sponsor,
+        space = SponsorPool::SIZE,
+        seeds = [SPONSOR_POOL_SEED, game_state.game_seed().as_ref(), sponsor.key().as_ref(), &pool_id.to_le_bytes()],
+        bump
+    )]
+    pub sponsor_pool: Account<'info, SponsorPool>,
+
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn handler(
+    ctx: Context<CreateSponsorPool>,
+    pool_id: u64,
+    min_streak_days: u32,
+    min_stake: u64,
+    checkin_start_period: i64,
+    checkin_end_period: i64,
+    entries_close_at: i64,
+    expires_at: i64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(entries_close_at > clock.unix_timestamp, StreakError::InvalidSponsorPool);
+    require!(expires_at > entries_close_at, StreakError::InvalidSponsorPool);
+
+    // The period rule is either off (both 0) or a non-empty range
+    require!(
+        (checkin_start_period == 0 && checkin_end_period == 0) || checkin_start_period < checkin_end_period,
+        StreakError::InvalidSponsorPool
+    );
+
+    let sponsor_pool = &mut ctx.accounts.sponsor_pool;
+    sponsor_pool.game_id = ctx.accounts.game_state.game_id;
+    sponsor_pool.pool_id = pool_id;
+    sponsor_pool.sponsor = ctx.accounts.sponsor.key();
+    sponsor_pool.min_streak_days = min_streak_days;
+    sponsor_pool.min_stake = min_stake;
+    sponsor_pool.checkin_start_period = checkin_start_period;
+    sponsor_pool.checkin_end_period = checkin_end_period;
+    sponsor_pool.entries_close_at = entries_close_at;
+    sponsor_pool.expires_at = expires_at;
+    sponsor_pool.bump = ctx.bumps.sponsor_pool;
+
+    msg!("Sponsor pool {} created by {}", pool_id, sponsor_pool.sponsor);
+    msg!("Entries close at {}, claims close at {}", entries_close_at, expires_at);
+
+    Ok(())
+}
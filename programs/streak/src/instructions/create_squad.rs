@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameState, Player, Squad};
+use crate::constants::{GAME_STATE_SEED, PLAYER_SEED, SQUAD_SEED};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+#[instruction(squad_id: u64)]
+pub struct CreateSquad<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        init,
+        payer = user,
+        space = Squad::SIZE,
+        seeds = [SQUAD_SEED, game_state.game_seed().as_ref(), &squad_id.to_le_bytes()],
+        bump
+    )]
+    pub squad: Account<'info, Squad>,
+
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, game_state.game_seed().as_ref(), user.key().as_ref()],
+        bump = player.bump,
+        constraint = player.wallet == user.key()
+    )]
+    pub player: Account<'info, Player>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateSquad>, squad_id: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let current_period = ctx.accounts.game_state.period_at(clock.unix_timestamp);
+
+    require!(ctx.accounts.player.is_active, StreakError::NotStaked);
+
+    let squad_key = ctx.accounts.squad.key();
+    let squad = &mut ctx.accounts.squad;
+    squad.game_id = ctx.accounts.game_state.game_id;
+    squad.squad_id = squad_id;
+    squad.leader = ctx.accounts.user.key();
+    squad.bump = ctx.bumps.squad;
+    squad.join(squad_key, &mut ctx.accounts.player, current_period)?;
+
+    msg!("Squad {} created by {}", squad_id, squad.leader);
+
+    Ok(())
+}
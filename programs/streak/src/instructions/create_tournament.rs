@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameState, Tournament};
+use crate::constants::{GAME_STATE_SEED, TOURNAMENT_SEED, MAX_TOURNAMENT_WINNERS};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+#[instruction(tournament_id: u64)]
+pub struct CreateTournament<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump,
+        has_one = authority @ StreakError::Unauthorized
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Tournament::SIZE,
+        seeds = [TOURNAMENT_SEED, game_state.game_seed().as_ref(), &tournament_id.to_le_bytes()],
+        bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateTournament>,
+    tournament_id: u64,
+    entry_fee: u64,
+    registration_end_period: i64,
+    max_winners: u32,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let game_state = &ctx.accounts.game_state;
+
+    require!(
+        entry_fee > 0 && max_winners > 0 && max_winners <= MAX_TOURNAMENT_WINNERS,
+        StreakError::InvalidTournament
+    );
+    require!(
+        registration_end_period > game_state.period_at(clock.unix_timestamp),
+        StreakError::InvalidTournament
+    );
+
+    let tournament = &mut ctx.accounts.tournament;
+    tournament.game_id = game_state.game_id;
+    tournament.tournament_id = tournament_id;
+    tournament.entry_fee = entry_fee;
+    tournament.registration_end_period = registration_end_period;
+    tournament.max_winners = max_winners;
+    tournament.bump = ctx.bumps.tournament;
+
+    msg!("Tournament {} created: {} lamport entry fee", tournament_id, entry_fee);
+    msg!("Registration closes at period {}, last {} standing win", registration_end_period, max_winners);
+
+    Ok(())
+}
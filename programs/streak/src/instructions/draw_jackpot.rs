@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameState, Jackpot};
+use crate::constants::{GAME_STATE_SEED, JACKPOT_SEED, JACKPOT_REVEAL_SLOTS};
+use crate::errors::StreakError;
+use crate::randomness::Randomness;
+
+#[derive(Accounts)]
+pub struct DrawJackpot<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        mut,
+        seeds = [JACKPOT_SEED, game_state.game_seed().as_ref()],
+        bump = jackpot.bump
+    )]
+    pub jackpot: Account<'info, Jackpot>,
+
+    /// CHECK: Randomness source, validated by Randomness::load_at (the SlotHashes sysvar)
+    pub randomness: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<DrawJackpot>) -> Result<()> {
+    let clock = Clock::get()?;
+    let current_period = ctx.accounts.game_state.period_at(clock.unix_timestamp);
+    let jackpot_key = ctx.accounts.jackpot.key();
+    let jackpot = &mut ctx.accounts.jackpot;
+
+    // Draw the oldest period once it has ended
+    let period = jackpot.next_draw_period().ok_or(StreakError::JackpotNotReady)?;
+    require!(period < current_period, StreakError::JackpotNotReady);
+
+    // The first crank closes the round and commits its draw to a slot still
+    // ahead, so the winner can't be known when the round closes or picked by
+    // choosing when to draw. A round whose slot has left the SlotHashes window
+    // is voided with its balance carried over, so it can't be rerolled.
+    if jackpot.draw_slot == 0 {
+        jackpot.draw_slot = clock.slot.checked_add(JACKPOT_REVEAL_SLOTS).ok_or(StreakError::Overflow)?;
+        msg!("Jackpot round for period {} closed, drawn at slot {}", period, jackpot.draw_slot);
+        return Ok(());
+    }
+    require!(clock.slot > jackpot.draw_slot, StreakError::JackpotNotReady);
+    let randomness = match Randomness::load_at(&ctx.accounts.randomness, jackpot.draw_slot)? {
+        Some(randomness) => randomness,
+        None => {
+            jackpot.void_draw(period);
+            msg!("Jackpot draw slot for period {} expired, round voided", period);
+            return Ok(());
+        }
+    };
+
+    let random = randomness.value(&[jackpot_key.as_ref(), &period.to_le_bytes()]);
+    match jackpot.draw(period, random)? {
+        Some(prize) => msg!("Jackpot for period {} won by {}: {} lamports", period, prize.wallet, prize.amount),
+        None => msg!("Jackpot for period {} drawn, nothing to win", period),
+    }
+
+    Ok(())
+}
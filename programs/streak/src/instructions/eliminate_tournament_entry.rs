@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameState, Tournament, TournamentEntry};
+use crate::constants::{GAME_STATE_SEED, TOURNAMENT_SEED, TOURNAMENT_ENTRY_SEED};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct EliminateTournamentEntry<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        mut,
+        seeds = [TOURNAMENT_SEED, game_state.game_seed().as_ref(), &tournament.tournament_id.to_le_bytes()],
+        bump = tournament.bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(
+        mut,
+        seeds = [TOURNAMENT_ENTRY_SEED, tournament.key().as_ref(), entry.wallet.as_ref()],
+        bump = entry.bump
+    )]
+    pub entry: Account<'info, TournamentEntry>,
+}
+
+pub fn handler(ctx: Context<EliminateTournamentEntry>) -> Result<()> {
+    let clock = Clock::get()?;
+    let game_state = &ctx.accounts.game_state;
+    let tournament = &mut ctx.accounts.tournament;
+    let entry = &mut ctx.accounts.entry;
+    let current_period = game_state.period_at(clock.unix_timestamp);
+
+    // Winners are fixed once the tournament finishes
+    require!(!tournament.finished, StreakError::TournamentFinished);
+    require!(entry.is_alive, StreakError::PlayerDead);
+    require!(
+        entry.has_missed_checkin(current_period, game_state),
+        StreakError::PlayerNotDead
+    );
+
+    tournament.eliminate(entry, game_state)?;
+
+    msg!("Eliminated {} in period {}", entry.wallet, entry.death_period);
+    msg!("{} still standing, pot: {} lamports", tournament.alive, tournament.pot);
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameState, Player, SponsorPool, SponsorClaim};
+use crate::constants::{GAME_STATE_SEED, PLAYER_SEED, SPONSOR_POOL_SEED, SPONSOR_CLAIM_SEED};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct EnterSponsorPool<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        mut,
+        seeds = [
+            SPONSOR_POOL_SEED,
+            game_state.game_seed().as_ref(),
+            sponsor_pool.sponsor.as_ref(),
+            &sponsor_pool.pool_id.to_le_bytes()
+        ],
+        bump = sponsor_pool.bump
+    )]
+    pub sponsor_pool: Account<'info, SponsorPool>,
+
+    /// One entry per wallet
+    #[account(
+        init,
+        payer = user,
+        space = SponsorClaim::SIZE,
+        seeds = [SPONSOR_CLAIM_SEED, sponsor_pool.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub sponsor_claim: Account<'info, SponsorClaim>,
+
+    #[account(
+        seeds = [PLAYER_SEED, game_state.game_seed().as_ref(), user.key().as_ref()],
+        bump = player.bump,
+        constraint = player.wallet == user.key()
+    )]
+    pub player: Account<'info, Player>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<EnterSponsorPool>) -> Result<()> {
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+    let sponsor_pool = &mut ctx.accounts.sponsor_pool;
+
+    require!(current_time < sponsor_pool.entries_close_at, StreakError::SponsorEntriesClosed);
+    require!(
+        sponsor_pool.is_eligible(&ctx.accounts.player, &ctx.accounts.game_state, current_time),
+        StreakError::NotEligible
+    );
+
+    sponsor_pool.entries = sponsor_pool.entries.checked_add(1).ok_or(StreakError::Overflow)?;
+
+    let sponsor_claim = &mut ctx.accounts.sponsor_claim;
+    sponsor_claim.pool = sponsor_pool.key();
+    sponsor_claim.wallet = ctx.accounts.user.key();
+    sponsor_claim.bump = ctx.bumps.sponsor_claim;
+
+    msg!("Entered sponsor pool {} ({} entrants)", sponsor_pool.pool_id, sponsor_pool.entries);
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{GameState, Player, Tournament, TournamentEntry};
+use crate::constants::{GAME_STATE_SEED, TOURNAMENT_SEED, TOURNAMENT_ENTRY_SEED};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct EnterTournament<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        mut,
+        seeds = [TOURNAMENT_SEED, game_state.game_seed().as_ref(), &tournament.tournament_id.to_le_bytes()],
+        bump = tournament.bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// One entry per wallet - eliminated players cannot re-enter
+    #[account(
+        init,
+        payer = user,
+        space = TournamentEntry::SIZE,
+        seeds = [TOURNAMENT_ENTRY_SEED, tournament.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub entry: Account<'info, TournamentEntry>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<EnterTournament>) -> Result<()> {
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+    let game_state = &ctx.accounts.game_state;
+
+    require!(
+        game_state.period_at(current_time) < ctx.accounts.tournament.registration_end_period,
+        StreakError::RegistrationClosed
+    );
+
+    // Pay the entry fee into the tournament account
+    let entry_fee = ctx.accounts.tournament.entry_fee;
+    let cpi_context = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        system_program::Transfer {
+            from: ctx.accounts.user.to_account_info(),
+            to: ctx.accounts.tournament.to_account_info(),
+        },
+    );
+    system_program::transfer(cpi_context, entry_fee)?;
+
+    // Same start rule as stakes: entering at the very end of a period starts the next one
+    let tournament_key = ctx.accounts.tournament.key();
+    let entry = &mut ctx.accounts.entry;
+    entry.tournament = tournament_key;
+    entry.wallet = ctx.accounts.user.key();
+    entry.start_day = Player::start_day_for(current_time, game_state.checkin_interval_seconds, 0);
+    entry.last_checkin = current_time;
+    entry.streak_days = 1;
+    entry.is_alive = true;
+    entry.bump = ctx.bumps.entry;
+
+    let tournament = &mut ctx.accounts.tournament;
+    tournament.entrants = tournament.entrants.checked_add(1).ok_or(StreakError::Overflow)?;
+    tournament.alive = tournament.alive.checked_add(1).ok_or(StreakError::Overflow)?;
+
+    msg!("Entered tournament {} ({} entrants)", tournament.tournament_id, tournament.entrants);
+
+    Ok(())
+}
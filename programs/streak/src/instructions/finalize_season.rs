@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameState, Season};
+use crate::constants::{GAME_STATE_SEED, SEASON_SEED, BPS_DENOMINATOR};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct FinalizeSeason<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        mut,
+        seeds = [SEASON_SEED, game_state.game_seed().as_ref(), &season.season_id.to_le_bytes()],
+        bump = season.bump,
+        constraint = season.season_id == game_state.current_season_id @ StreakError::WrongSeason
+    )]
+    pub season: Account<'info, Season>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = Season::SIZE,
+        seeds = [SEASON_SEED, game_state.game_seed().as_ref(), &(season.season_id + 1).to_le_bytes()],
+        bump
+    )]
+    pub next_season: Account<'info, Season>,
+
+    /// Anyone can crank the season over and pay for the next season's account
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<FinalizeSeason>) -> Result<()> {
+    let clock = Clock::get()?;
+    let game_state = &mut ctx.accounts.game_state;
+    let season = &mut ctx.accounts.season;
+    let current_period = game_state.period_at(clock.unix_timestamp);
+
+    require!(!season.finalized, StreakError::SeasonFinalized);
+    require!(current_period >= season.end_period, StreakError::SeasonNotEnded);
+
+    // Snapshot the winners: survivors' share of the death pool leaves the pool
+    // and waits on the season until each survivor claims it
+    season.payout_pool = if season.survivors > 0 {
+        season.death_pool
+            .checked_mul(season.payout_bps)
+            .ok_or(StreakError::Overflow)?
+            .checked_div(BPS_DENOMINATOR)
+            .ok_or(StreakError::Overflow)?
+            .min(game_state.total_pool)
+    } else {
+        0
+    };
+    season.finalized = true;
+    game_state.total_pool = game_state.total_pool
+        .checked_sub(season.payout_pool)
+        .ok_or(StreakError::Overflow)?;
+
+    // Open the next season with the same rules, starting now if the crank ran late
+    let length = season.end_period - season.start_period;
+    let entry_window = season.entry_deadline_period - season.start_period;
+    let next_start = season.end_period.max(current_period);
+
+    let next_season = &mut ctx.accounts.next_season;
+    next_season.game_id = season.game_id;
+    next_season.season_id = season.season_id + 1;
+    next_season.start_period = next_start;
+    next_season.end_period = next_start + length;
+    next_season.entry_deadline_period = next_start + entry_window;
+    next_season.min_stake = season.min_stake;
+    next_season.payout_bps = season.payout_bps;
+    next_season.bump = ctx.bumps.next_season;
+    game_state.current_season_id = next_season.season_id;
+
+    msg!("Season {} finalized: {} of {} entrants survived", season.season_id, season.survivors, season.entrants);
+    msg!("Death pool: {} lamports, paid to survivors: {}", season.death_pool, season.payout_pool);
+    msg!("Season {} opens at period {}", next_season.season_id, next_start);
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameState, Tournament, TournamentEntry};
+use crate::constants::{GAME_STATE_SEED, TOURNAMENT_SEED};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct FinishTournament<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        mut,
+        seeds = [TOURNAMENT_SEED, game_state.game_seed().as_ref(), &tournament.tournament_id.to_le_bytes()],
+        bump = tournament.bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+    // Remaining accounts: every entry still alive, in ascending address order
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, FinishTournament<'info>>) -> Result<()> {
+    let clock = Clock::get()?;
+    let game_state = &ctx.accounts.game_state;
+    let current_period = game_state.period_at(clock.unix_timestamp);
+    let tournament_key = ctx.accounts.tournament.key();
+    let tournament = &mut ctx.accounts.tournament;
+
+    require!(!tournament.finished, StreakError::TournamentFinished);
+    require!(current_period >= tournament.registration_end_period, StreakError::TournamentRunning);
+
+    // Check every remaining entry so only players who are really still standing
+    // win; anyone who missed a check-in without being cranked out goes out now
+    require!(
+        ctx.remaining_accounts.len() == tournament.alive as usize,
+        StreakError::MissingTournamentEntries
+    );
+    let mut previous_key: Option<Pubkey> = None;
+    for entry_info in ctx.remaining_accounts.iter() {
+        if let Some(previous_key) = previous_key {
+            require!(previous_key < entry_info.key(), StreakError::MissingTournamentEntries);
+        }
+        previous_key = Some(entry_info.key());
+
+        let mut entry: Account<TournamentEntry> = Account::try_from(entry_info)?;
+        require!(
+            entry.tournament == tournament_key && entry.is_alive,
+            StreakError::MissingTournamentEntries
+        );
+        if entry.has_missed_checkin(current_period, game_state) {
+            tournament.eliminate(&mut entry, game_state)?;
+            entry.exit(&crate::ID)?;
+            msg!("Eliminated {} in period {}", entry.wallet, entry.death_period);
+        }
+    }
+    require!(tournament.alive <= tournament.max_winners, StreakError::TournamentRunning);
+
+    // The survivors win. If the last players were all eliminated in the same
+    // period, everyone knocked out in that final period shares the pot instead.
+    tournament.finished = true;
+    tournament.finish_period = current_period;
+    tournament.survivors_at_finish = tournament.alive;
+    tournament.winner_count = if tournament.alive > 0 {
+        tournament.alive
+    } else {
+        tournament.last_period_deaths
+    };
+
+    // Winners split every entry fee (their own and the pot)
+    let total_fees = tournament.entry_fee
+        .checked_mul(tournament.entrants as u64)
+        .ok_or(StreakError::Overflow)?;
+    tournament.prize_per_winner = if tournament.winner_count > 0 {
+        total_fees / tournament.winner_count as u64
+    } else {
+        0
+    };
+
+    msg!("Tournament {} finished with {} winners", tournament.tournament_id, tournament.winner_count);
+    msg!("Prize per winner: {} lamports", tournament.prize_per_winner);
+
+    Ok(())
+}
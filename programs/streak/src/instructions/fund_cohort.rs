@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::Cohort;
+use crate::constants::{COHORT_SEED, COHORT_VAULT_SEED};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct FundCohort<'info> {
+    #[account(
+        mut,
+        seeds = [COHORT_SEED, cohort.admin.as_ref(), &cohort.cohort_id.to_le_bytes()],
+        bump = cohort.bump
+    )]
+    pub cohort: Account<'info, Cohort>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Token-staked games only: the cohort's token budget
+    #[account(
+        mut,
+        seeds = [COHORT_VAULT_SEED, cohort.key().as_ref()],
+        bump
+    )]
+    pub cohort_vault: Option<Account<'info, TokenAccount>>,
+
+    /// Token-staked games only: the funder's token account for the stake mint
+    #[account(mut)]
+    pub funder_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+pub fn handler(ctx: Context<FundCohort>, amount: u64) -> Result<()> {
+    require!(amount > 0, StreakError::InvalidAmount);
+
+    // Transfer SOL (or the game's token, for cohorts with a token vault) to the cohort budget
+    let cohort_info = ctx.accounts.cohort.to_account_info();
+    Cohort::deposit(
+        &cohort_info,
+        ctx.accounts.cohort_vault.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        &ctx.accounts.funder.to_account_info(),
+        ctx.accounts.funder_token_account.as_ref(),
+        &ctx.accounts.system_program.to_account_info(),
+        amount,
+    )?;
+
+    if let Some(cohort_vault) = ctx.accounts.cohort_vault.as_mut() {
+        cohort_vault.reload()?;
+    }
+    let budget = Cohort::budget(&cohort_info, ctx.accounts.cohort_vault.as_ref())?;
+
+    msg!("Cohort {} funded with {} lamports", ctx.accounts.cohort.cohort_id, amount);
+    msg!("Available budget: {} lamports", budget);
+
+    Ok(())
+}
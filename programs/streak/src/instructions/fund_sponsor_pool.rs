@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{GameState, SponsorPool};
+use crate::constants::SPONSOR_POOL_SEED;
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct FundSponsorPool<'info> {
+    #[account(
+        mut,
+        seeds = [
+            SPONSOR_POOL_SEED,
+            GameState::seed_for(sponsor_pool.game_id).as_ref(),
+            sponsor_pool.sponsor.as_ref(),
+            &sponsor_pool.pool_id.to_le_bytes()
+        ],
+        bump = sponsor_pool.bump
+    )]
+    pub sponsor_pool: Account<'info, SponsorPool>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<FundSponsorPool>, amount: u64) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(amount > 0, StreakError::InvalidAmount);
+    // The split is worked out when entries close, so funding stops then
+    require!(clock.unix_timestamp < ctx.accounts.sponsor_pool.entries_close_at, StreakError::SponsorEntriesClosed);
+
+    // Transfer SOL from funder to the pool
+    let cpi_context = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        system_program::Transfer {
+            from: ctx.accounts.funder.to_account_info(),
+            to: ctx.accounts.sponsor_pool.to_account_info(),
+        },
+    );
+    system_program::transfer(cpi_context, amount)?;
+
+    let sponsor_pool = &mut ctx.accounts.sponsor_pool;
+    sponsor_pool.total_funded = sponsor_pool.total_funded.checked_add(amount).ok_or(StreakError::Overflow)?;
+
+    let budget = SponsorPool::available_budget(&sponsor_pool.to_account_info())?;
+
+    msg!("Sponsor pool {} funded with {} lamports", sponsor_pool.pool_id, amount);
+    msg!("Available budget: {} lamports", budget);
+
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameState, Player, League, Season, Squad};
+use crate::constants::{GAME_STATE_SEED, PLAYER_SEED};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct Harvest<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, game_state.game_seed().as_ref(), user.key().as_ref()],
+        bump = player.bump,
+        constraint = player.wallet == user.key()
+    )]
+    pub player: Account<'info, Player>,
+
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = league.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub league: Option<Account<'info, League>>,
+
+    /// League to move to when the harvest takes the stake out of the current league's band
+    #[account(
+        mut,
+        constraint = new_league.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub new_league: Option<Account<'info, League>>,
+
+    /// Squad the player belongs to (required while they are in one)
+    #[account(
+        mut,
+        constraint = squad.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub squad: Option<Account<'info, Squad>>,
+
+    /// Game's current season (required while a shrinking stake lowers the player's season weight)
+    #[account(
+        mut,
+        constraint = season.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub season: Option<Account<'info, Season>>,
+}
+
+pub fn handler(ctx: Context<Harvest>) -> Result<()> {
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+    let interval = ctx.accounts.game_state.checkin_interval_seconds;
+
+    let player = &mut ctx.accounts.player;
+
+    // Validate player is active and alive (a dead player's growth belongs to the pool)
+    require!(player.is_active, StreakError::NotStaked);
+    League::check_member(ctx.accounts.league.as_ref(), player)?;
+    Squad::check_member(ctx.accounts.squad.as_ref(), player)?;
+    let current_period = player.period_at(current_time, interval);
+    require!(
+        !player.has_missed_checkin(current_period, interval),
+        StreakError::PlayerDead
+    );
+
+    // Squad penalties owed from squadmates' deaths come out of the stake first
+    if let Some(squad) = ctx.accounts.squad.as_mut() {
+        squad.collect_penalties(player, &ctx.accounts.game_state)?;
+    }
+
+    let harvested = player.harvestable_growth();
+    require!(harvested > 0, StreakError::NothingToHarvest);
+
+    // Move growth out of the stake: it survives death but stops compounding
+    player.stake = player.principal;
+    Season::cap_stake(ctx.accounts.season.as_mut(), player, ctx.accounts.game_state.current_season_id)?;
+    player.pending_rewards = player.pending_rewards
+        .checked_add(harvested)
+        .ok_or(StreakError::Overflow)?;
+    player.total_harvested = player.total_harvested
+        .checked_add(harvested)
+        .ok_or(StreakError::Overflow)?;
+
+    // Harvested growth leaves the league pool too, and the smaller stake may move the player down
+    if let Some(league) = ctx.accounts.league.as_mut() {
+        league.remove_from_pool(harvested)?;
+        league.follow_stake(ctx.accounts.new_league.as_mut(), player)?;
+    }
+
+    // Pending rewards live outside the pool
+    let game_state = &mut ctx.accounts.game_state;
+    game_state.total_pool = game_state.total_pool
+        .checked_sub(harvested)
+        .ok_or(StreakError::Overflow)?;
+
+    msg!("Harvested {} lamports of growth", harvested);
+    msg!("Stake back to principal: {} lamports", player.stake);
+
+    Ok(())
+}
@@ -1,6 +1,10 @@
 use anchor_lang::prelude::*;
-use crate::state::GameState;
-use crate::constants::{GAME_STATE_SEED, SECONDS_PER_DAY};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::errors::StreakError;
+use crate::state::{GameState, GrowthTier, BoxReward};
+use crate::constants::{
+    GAME_STATE_SEED, STAKE_VAULT_SEED, SECONDS_PER_DAY, DEFAULT_GROWTH_TIERS, MAIN_GAME_ID, MAX_BOX_REWARDS,
+};
 
 #[derive(Accounts)]
 pub struct Initialize<'info> {
@@ -20,13 +24,74 @@ pub struct Initialize<'info> {
     pub authority: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Token-staked games only: the SPL token the game stakes (fixed for its lifetime)
+    pub stake_mint: Option<Account<'info, Mint>>,
+
+    /// Token-staked games only: the game's stake vault, owned by the game state PDA
+    #[account(
+        init,
+        payer = authority,
+        seeds = [STAKE_VAULT_SEED, GameState::seed_for(MAIN_GAME_ID).as_ref()],
+        bump,
+        token::mint = stake_mint,
+        token::authority = game_state
+    )]
+    pub stake_vault: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
 }
 
 pub fn handler(ctx: Context<Initialize>) -> Result<()> {
-    let game_state = &mut ctx.accounts.game_state;
+    init_game_state(
+        &mut ctx.accounts.game_state,
+        ctx.accounts.authority.key(),
+        ctx.accounts.treasury.key(),
+        ctx.bumps.game_state,
+        MAIN_GAME_ID,
+    );
+    set_stake_mint(
+        &mut ctx.accounts.game_state,
+        ctx.accounts.stake_mint.as_ref(),
+        ctx.accounts.stake_vault.as_ref(),
+    )?;
+
+    let game_state = &ctx.accounts.game_state;
+    msg!("Game initialized with authority: {}", game_state.authority);
+    msg!("Treasury: {}", game_state.treasury);
+    msg!("Check-in interval: {} seconds", game_state.checkin_interval_seconds);
+    if game_state.is_token_game() {
+        msg!("Stakes mint {}", game_state.stake_mint);
+    }
 
-    game_state.authority = ctx.accounts.authority.key();
-    game_state.treasury = ctx.accounts.treasury.key();
+    Ok(())
+}
+
+/// Fix a new game's stake currency: an SPL mint when a mint and vault were
+/// passed, SOL otherwise
+pub(crate) fn set_stake_mint(
+    game_state: &mut GameState,
+    stake_mint: Option<&Account<Mint>>,
+    stake_vault: Option<&Account<TokenAccount>>,
+) -> Result<()> {
+    match (stake_mint, stake_vault) {
+        (Some(stake_mint), Some(_)) => game_state.stake_mint = stake_mint.key(),
+        (None, None) => {}
+        _ => return Err(StreakError::MissingTokenAccounts.into()),
+    }
+    Ok(())
+}
+
+/// Set up a fresh game state with every optional feature switched off
+pub(crate) fn init_game_state(
+    game_state: &mut GameState,
+    authority: Pubkey,
+    treasury: Pubkey,
+    bump: u8,
+    game_id: u64,
+) {
+    game_state.authority = authority;
+    game_state.treasury = treasury;
     game_state.total_players = 0;
     game_state.total_pool = 0;
     game_state.last_death_timestamp = 0;
@@ -34,11 +99,39 @@ pub fn handler(ctx: Context<Initialize>) -> Result<()> {
     game_state.current_bonus_window = 0;
     game_state.bonus_window_end = 0;
     game_state.checkin_interval_seconds = SECONDS_PER_DAY; // Default: 24 hours
-    game_state.bump = ctx.bumps.game_state;
-
-    msg!("Game initialized with authority: {}", game_state.authority);
-    msg!("Treasury: {}", game_state.treasury);
-    msg!("Check-in interval: {} seconds", game_state.checkin_interval_seconds);
-
-    Ok(())
+    game_state.bump = bump;
+    game_state.late_window_seconds = 0; // Late check-ins disabled by default
+    game_state.late_penalty_bps = 0;
+    game_state.revive_window_seconds = 0; // Revives disabled by default
+    game_state.revive_cost_bps = 0;
+    game_state.revive_stake_bps = 0;
+    game_state.exit_fee_max_bps = 0; // No exit fee by default
+    game_state.exit_fee_zero_streak_days = 0;
+    game_state.exit_fee_treasury_bps = 0;
+    for (tier, (min_streak_days, extra_growth_bps, milestone_reward_bps)) in
+        game_state.growth_tiers.iter_mut().zip(DEFAULT_GROWTH_TIERS)
+    {
+        *tier = GrowthTier { min_streak_days, extra_growth_bps, milestone_reward_bps };
+    }
+    game_state.points_per_checkin = 0; // Points disabled by default
+    game_state.points_per_bonus_claim = 0;
+    game_state.points_per_referral = 0;
+    game_state.points_per_milestone = 0;
+    game_state.points_multiplier_bps = 0;
+    game_state.points_snapshot_id = 0;
+    game_state.reward_mint = Pubkey::default(); // Token rewards off until a mint is set
+    game_state.reward_per_checkin = 0;
+    game_state.reward_per_bonus_claim = 0;
+    game_state.reward_per_milestone = 0;
+    game_state.reward_emission_start = 0;
+    game_state.reward_halving_seconds = 0;
+    game_state.stake_mint = Pubkey::default(); // SOL stakes by default
+    game_state.game_id = game_id;
+    game_state.league_count = 0; // Leagues off by default
+    game_state.current_season_id = 0; // No seasons until one is started
+    game_state.squad_growth_bonus_bps = 0; // Squads share no bonus or penalty by default
+    game_state.squad_penalty_bps = 0;
+    game_state.jackpot_bps = 0; // No jackpot until one is initialized
+    game_state.box_interval_days = 0; // Mystery boxes off by default
+    game_state.box_rewards = [BoxReward::default(); MAX_BOX_REWARDS];
 }
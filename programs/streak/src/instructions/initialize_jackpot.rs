@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameState, Jackpot};
+use crate::constants::{GAME_STATE_SEED, JACKPOT_SEED, BPS_DENOMINATOR};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct InitializeJackpot<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump,
+        has_one = authority @ StreakError::Unauthorized
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Jackpot::SIZE,
+        seeds = [JACKPOT_SEED, game_state.game_seed().as_ref()],
+        bump
+    )]
+    pub jackpot: Account<'info, Jackpot>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeJackpot>, death_share_bps: u64, weight_by_streak: bool) -> Result<()> {
+    require!(death_share_bps <= BPS_DENOMINATOR, StreakError::InvalidBps);
+
+    let jackpot = &mut ctx.accounts.jackpot;
+    jackpot.game_id = ctx.accounts.game_state.game_id;
+    jackpot.weight_by_streak = weight_by_streak;
+    jackpot.bump = ctx.bumps.jackpot;
+
+    // From here on checkin and process_death need the jackpot account
+    ctx.accounts.game_state.jackpot_bps = death_share_bps;
+
+    msg!("Jackpot created: {} bps of each death", death_share_bps);
+    msg!("Draws weighted by {}", if weight_by_streak { "streak" } else { "stake" });
+
+    Ok(())
+}
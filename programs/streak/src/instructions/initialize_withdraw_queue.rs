@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameState, WithdrawQueue};
+use crate::constants::{GAME_STATE_SEED, WITHDRAW_QUEUE_SEED};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct InitializeWithdrawQueue<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump,
+        has_one = authority @ StreakError::Unauthorized
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = WithdrawQueue::SIZE,
+        seeds = [WITHDRAW_QUEUE_SEED, game_state.game_seed().as_ref()],
+        bump
+    )]
+    pub withdraw_queue: Account<'info, WithdrawQueue>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeWithdrawQueue>) -> Result<()> {
+    let withdraw_queue = &mut ctx.accounts.withdraw_queue;
+
+    withdraw_queue.entries = Vec::new();
+    withdraw_queue.total_owed = 0;
+    withdraw_queue.total_paid = 0;
+    withdraw_queue.bump = ctx.bumps.withdraw_queue;
+
+    msg!("Withdrawal queue initialized");
+
+    Ok(())
+}
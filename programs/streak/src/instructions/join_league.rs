@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameState, Player, League};
+use crate::constants::{GAME_STATE_SEED, PLAYER_SEED, LEAGUE_SEED};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct JoinLeague<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, game_state.game_seed().as_ref(), user.key().as_ref()],
+        bump = player.bump,
+        constraint = player.wallet == user.key()
+    )]
+    pub player: Account<'info, Player>,
+
+    /// League whose band the player's stake falls in
+    #[account(
+        mut,
+        seeds = [LEAGUE_SEED, game_state.game_seed().as_ref(), &[league.league_id]],
+        bump = league.bump
+    )]
+    pub league: Account<'info, League>,
+
+    pub user: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<JoinLeague>) -> Result<()> {
+    let player = &mut ctx.accounts.player;
+    let league = &mut ctx.accounts.league;
+
+    // Players who staked before the game had leagues opt in with their current stake
+    require!(player.is_active, StreakError::NotStaked);
+    require!(player.league_id == 0, StreakError::WrongLeague);
+    require!(league.contains(player.stake), StreakError::OutsideLeagueBand);
+
+    league.join(player)?;
+    league.add_to_pool(player.stake)?;
+
+    msg!("Joined league {} with a {} lamport stake", league.league_id, player.stake);
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameState, Player, Season};
+use crate::constants::{GAME_STATE_SEED, PLAYER_SEED, SEASON_SEED};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct JoinSeason<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        mut,
+        seeds = [SEASON_SEED, game_state.game_seed().as_ref(), &season.season_id.to_le_bytes()],
+        bump = season.bump,
+        constraint = season.season_id == game_state.current_season_id @ StreakError::WrongSeason
+    )]
+    pub season: Account<'info, Season>,
+
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, game_state.game_seed().as_ref(), user.key().as_ref()],
+        bump = player.bump,
+        constraint = player.wallet == user.key()
+    )]
+    pub player: Account<'info, Player>,
+
+    pub user: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<JoinSeason>) -> Result<()> {
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+    let game_state = &ctx.accounts.game_state;
+    let interval = game_state.checkin_interval_seconds;
+    let season = &mut ctx.accounts.season;
+    let player = &mut ctx.accounts.player;
+
+    // Validate player is active and alive
+    require!(player.is_active, StreakError::NotStaked);
+    let current_period = player.period_at(current_time, interval);
+    require!(
+        !player.has_missed_checkin(current_period, interval),
+        StreakError::PlayerDead
+    );
+
+    // Entry rules
+    require!(
+        !season.finalized && game_state.period_at(current_time) < season.entry_deadline_period,
+        StreakError::SeasonEntryClosed
+    );
+    require!(player.stake >= season.min_stake, StreakError::SeasonEntryClosed);
+    require!(player.season_id != season.season_id, StreakError::SeasonEntryClosed);
+
+    // Winnings from an earlier season must be claimed before they are overwritten
+    require!(player.season_stake == 0, StreakError::UnclaimedSeasonReward);
+
+    player.season_id = season.season_id;
+    player.season_stake = player.stake;
+    player.seasons_played = player.seasons_played.checked_add(1).ok_or(StreakError::Overflow)?;
+
+    season.entrants = season.entrants.checked_add(1).ok_or(StreakError::Overflow)?;
+    season.survivors = season.survivors.checked_add(1).ok_or(StreakError::Overflow)?;
+    season.survivor_stake = season.survivor_stake
+        .checked_add(player.season_stake)
+        .ok_or(StreakError::Overflow)?;
+
+    msg!("Joined season {} with a {} lamport stake", season.season_id, player.season_stake);
+    msg!("Entrants: {}", season.entrants);
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameState, Player, Squad};
+use crate::constants::{GAME_STATE_SEED, PLAYER_SEED, SQUAD_SEED};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct JoinSquad<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        mut,
+        seeds = [SQUAD_SEED, game_state.game_seed().as_ref(), &squad.squad_id.to_le_bytes()],
+        bump = squad.bump,
+        has_one = leader @ StreakError::Unauthorized
+    )]
+    pub squad: Account<'info, Squad>,
+
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, game_state.game_seed().as_ref(), user.key().as_ref()],
+        bump = player.bump,
+        constraint = player.wallet == user.key()
+    )]
+    pub player: Account<'info, Player>,
+
+    pub user: Signer<'info>,
+
+    /// Squad leader approves every new member
+    pub leader: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<JoinSquad>) -> Result<()> {
+    let clock = Clock::get()?;
+    let current_period = ctx.accounts.game_state.period_at(clock.unix_timestamp);
+
+    require!(ctx.accounts.player.is_active, StreakError::NotStaked);
+
+    let squad_key = ctx.accounts.squad.key();
+    let squad = &mut ctx.accounts.squad;
+    squad.join(squad_key, &mut ctx.accounts.player, current_period)?;
+
+    msg!("{} joined squad {} ({} members)", ctx.accounts.user.key(), squad.squad_id, squad.members.len());
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameState, Player, Season, Squad};
+use crate::constants::{GAME_STATE_SEED, PLAYER_SEED, SQUAD_SEED};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct LeaveSquad<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        mut,
+        seeds = [SQUAD_SEED, game_state.game_seed().as_ref(), &squad.squad_id.to_le_bytes()],
+        bump = squad.bump
+    )]
+    pub squad: Account<'info, Squad>,
+
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, game_state.game_seed().as_ref(), user.key().as_ref()],
+        bump = player.bump,
+        constraint = player.wallet == user.key(),
+        constraint = player.squad == Some(squad.key()) @ StreakError::WrongSquad
+    )]
+    pub player: Account<'info, Player>,
+
+    pub user: Signer<'info>,
+
+    /// Game's current season (required while a shrinking stake lowers the player's season weight)
+    #[account(
+        mut,
+        constraint = season.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub season: Option<Account<'info, Season>>,
+}
+
+pub fn handler(ctx: Context<LeaveSquad>) -> Result<()> {
+    let squad = &mut ctx.accounts.squad;
+    let player = &mut ctx.accounts.player;
+
+    // Penalties owed from earlier deaths are paid on the way out
+    // (total_pool is unchanged - the lamports just stop belonging to the player)
+    let penalty = squad.collect_penalties(player, &ctx.accounts.game_state)?;
+    Season::cap_stake(ctx.accounts.season.as_mut(), player, ctx.accounts.game_state.current_season_id)?;
+    squad.leave(player);
+
+    msg!("Left squad {} ({} members left)", squad.squad_id, squad.members.len());
+    if penalty > 0 {
+        msg!("Squad penalty: {} lamports moved to pool", penalty);
+    }
+
+    Ok(())
+}
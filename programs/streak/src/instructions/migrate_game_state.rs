@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::GameState;
+use crate::constants::GAME_STATE_SEED;
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct MigrateGameState<'info> {
+    /// CHECK: Deserialized manually - accounts created before GameState grew
+    /// are too small for `Account<GameState>` until they are reallocated here.
+    /// Only the main game predates arenas; its game_id stays MAIN_GAME_ID.
+    #[account(
+        mut,
+        seeds = [GAME_STATE_SEED],
+        bump
+    )]
+    pub game_state: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateGameState>) -> Result<()> {
+    let game_state_info = ctx.accounts.game_state.to_account_info();
+    require!(game_state_info.owner == ctx.program_id, StreakError::Unauthorized);
+
+    // Check authority (offset 8, right after the discriminator)
+    {
+        let data = game_state_info.try_borrow_data()?;
+        require!(data.len() >= 8 + 32, StreakError::Unauthorized);
+        let authority_bytes: [u8; 32] = data[8..40].try_into().unwrap();
+        require!(
+            Pubkey::from(authority_bytes) == ctx.accounts.authority.key(),
+            StreakError::Unauthorized
+        );
+    }
+
+    let old_len = game_state_info.data_len();
+    if old_len >= GameState::SIZE {
+        msg!("Game state already at {} bytes, nothing to migrate", old_len);
+        return Ok(());
+    }
+
+    // Top up rent for the larger account
+    let rent = Rent::get()?;
+    let required = rent.minimum_balance(GameState::SIZE);
+    let shortfall = required.saturating_sub(game_state_info.lamports());
+    if shortfall > 0 {
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.authority.to_account_info(),
+                to: game_state_info.clone(),
+            },
+        );
+        system_program::transfer(cpi_context, shortfall)?;
+    }
+
+    // New fields are zero-initialized (all new features start disabled)
+    game_state_info.realloc(GameState::SIZE, true)?;
+
+    msg!("Game state migrated: {} -> {} bytes", old_len, GameState::SIZE);
+
+    Ok(())
+}
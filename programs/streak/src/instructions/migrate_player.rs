@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+use crate::state::Player;
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct MigratePlayer<'info> {
+    /// CHECK: Deserialized manually - accounts created before Player grew
+    /// are too small for `Account<Player>` until they are reallocated here
+    #[account(mut)]
+    pub player: UncheckedAccount<'info>,
+
+    /// Anyone can pay the extra rent to migrate a player
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigratePlayer>) -> Result<()> {
+    let player_info = ctx.accounts.player.to_account_info();
+    require!(player_info.owner == ctx.program_id, StreakError::Unauthorized);
+    {
+        let data = player_info.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && data[..8] == Player::DISCRIMINATOR,
+            StreakError::Unauthorized
+        );
+    }
+
+    let old_len = player_info.data_len();
+    if old_len >= Player::SIZE {
+        msg!("Player already at {} bytes, nothing to migrate", old_len);
+        return Ok(());
+    }
+
+    // Top up rent for the larger account
+    let rent = Rent::get()?;
+    let required = rent.minimum_balance(Player::SIZE);
+    let shortfall = required.saturating_sub(player_info.lamports());
+    if shortfall > 0 {
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.payer.to_account_info(),
+                to: player_info.clone(),
+            },
+        );
+        system_program::transfer(cpi_context, shortfall)?;
+    }
+
+    // New fields are zero-initialized
+    player_info.realloc(Player::SIZE, true)?;
+
+    // Stakes made before principal was tracked start with everything as principal
+    let mut data = player_info.try_borrow_mut_data()?;
+    let mut player = Player::try_deserialize(&mut &data[..])?;
+    if player.principal == 0 {
+        player.principal = player.stake;
+    }
+    player.try_serialize(&mut &mut data[..])?;
+
+    msg!("Player migrated: {} -> {} bytes", old_len, Player::SIZE);
+
+    Ok(())
+}
@@ -7,6 +7,68 @@ pub mod withdraw;
 pub mod claim_rewards;
 pub mod start_bonus_window;
 pub mod set_checkin_interval;
+pub mod set_late_checkin_config;
+pub mod schedule_freeze;
+pub mod revive;
+pub mod set_revive_config;
+pub mod add_stake;
+pub mod partial_withdraw;
+pub mod set_exit_fee_config;
+pub mod migrate_game_state;
+pub mod initialize_withdraw_queue;
+pub mod process_withdraw_queue;
+pub mod set_growth_tiers;
+pub mod harvest;
+pub mod migrate_player;
+pub mod compound_rewards;
+pub mod set_auto_compound;
+pub mod sponsor_stake;
+pub mod create_cohort;
+pub mod fund_cohort;
+pub mod claim_cohort_stake;
+pub mod sync_cohort_member;
+pub mod withdraw_cohort_budget;
+pub mod set_points_config;
+pub mod start_points_snapshot;
+pub mod snapshot_points;
+pub mod set_reward_mint;
+pub mod claim_token_rewards;
+pub mod create_arena;
+pub mod create_league;
+pub mod start_league_bonus_window;
+pub mod join_league;
+pub mod start_season;
+pub mod join_season;
+pub mod finalize_season;
+pub mod claim_season_reward;
+pub mod sweep_season;
+pub mod create_tournament;
+pub mod enter_tournament;
+pub mod tournament_checkin;
+pub mod eliminate_tournament_entry;
+pub mod finish_tournament;
+pub mod claim_tournament_prize;
+pub mod close_tournament;
+pub mod create_duel;
+pub mod accept_duel;
+pub mod cancel_duel;
+pub mod claim_duel;
+pub mod set_squad_config;
+pub mod create_squad;
+pub mod join_squad;
+pub mod leave_squad;
+pub mod contribute_squad_lifeline;
+pub mod initialize_jackpot;
+pub mod set_jackpot_config;
+pub mod draw_jackpot;
+pub mod claim_jackpot;
+pub mod set_mystery_box_config;
+pub mod open_box;
+pub mod create_sponsor_pool;
+pub mod fund_sponsor_pool;
+pub mod enter_sponsor_pool;
+pub mod claim_sponsor_reward;
+pub mod close_sponsor_pool;
 
 pub use initialize::*;
 pub use stake::*;
@@ -17,3 +79,65 @@ pub use withdraw::*;
 pub use claim_rewards::*;
 pub use start_bonus_window::*;
 pub use set_checkin_interval::*;
+pub use set_late_checkin_config::*;
+pub use schedule_freeze::*;
+pub use revive::*;
+pub use set_revive_config::*;
+pub use add_stake::*;
+pub use partial_withdraw::*;
+pub use set_exit_fee_config::*;
+pub use migrate_game_state::*;
+pub use initialize_withdraw_queue::*;
+pub use process_withdraw_queue::*;
+pub use set_growth_tiers::*;
+pub use harvest::*;
+pub use migrate_player::*;
+pub use compound_rewards::*;
+pub use set_auto_compound::*;
+pub use sponsor_stake::*;
+pub use create_cohort::*;
+pub use fund_cohort::*;
+pub use claim_cohort_stake::*;
+pub use sync_cohort_member::*;
+pub use withdraw_cohort_budget::*;
+pub use set_points_config::*;
+pub use start_points_snapshot::*;
+pub use snapshot_points::*;
+pub use set_reward_mint::*;
+pub use claim_token_rewards::*;
+pub use create_arena::*;
+pub use create_league::*;
+pub use start_league_bonus_window::*;
+pub use join_league::*;
+pub use start_season::*;
+pub use join_season::*;
+pub use finalize_season::*;
+pub use claim_season_reward::*;
+pub use sweep_season::*;
+pub use create_tournament::*;
+pub use enter_tournament::*;
+pub use tournament_checkin::*;
+pub use eliminate_tournament_entry::*;
+pub use finish_tournament::*;
+pub use claim_tournament_prize::*;
+pub use close_tournament::*;
+pub use create_duel::*;
+pub use accept_duel::*;
+pub use cancel_duel::*;
+pub use claim_duel::*;
+pub use set_squad_config::*;
+pub use create_squad::*;
+pub use join_squad::*;
+pub use leave_squad::*;
+pub use contribute_squad_lifeline::*;
+pub use initialize_jackpot::*;
+pub use set_jackpot_config::*;
+pub use draw_jackpot::*;
+pub use claim_jackpot::*;
+pub use set_mystery_box_config::*;
+pub use open_box::*;
+pub use create_sponsor_pool::*;
+pub use fund_sponsor_pool::*;
+pub use enter_sponsor_pool::*;
+pub use claim_sponsor_reward::*;
+pub use close_sponsor_pool::*;
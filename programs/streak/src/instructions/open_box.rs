@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameState, Player, League, Jackpot};
+use crate::constants::{
+    GAME_STATE_SEED, PLAYER_SEED, BPS_DENOMINATOR, BOX_REWARD_GROWTH, BOX_REWARD_LIFELINE,
+    BOX_REWARD_POINTS, BOX_REWARD_JACKPOT_TICKET,
+};
+use crate::errors::StreakError;
+use crate::events::MysteryBoxOpened;
+use crate::randomness::Randomness;
+
+#[derive(Accounts)]
+pub struct OpenBox<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, game_state.game_seed().as_ref(), user.key().as_ref()],
+        bump = player.bump,
+        constraint = player.wallet == user.key()
+    )]
+    pub player: Account<'info, Player>,
+
+    pub user: Signer<'info>,
+
+    /// CHECK: Randomness source, validated by Randomness::load_at (the SlotHashes sysvar)
+    pub randomness: UncheckedAccount<'info>,
+
+    /// Game's jackpot (required once the game has one)
+    #[account(
+        mut,
+        constraint = jackpot.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub jackpot: Option<Account<'info, Jackpot>>,
+
+    #[account(
+        mut,
+        constraint = league.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub league: Option<Account<'info, League>>,
+
+    /// League to move to when growth takes the stake out of the current league's band
+    #[account(
+        mut,
+        constraint = new_league.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub new_league: Option<Account<'info, League>>,
+}
+
+pub fn handler(ctx: Context<OpenBox>) -> Result<()> {
+    let clock = Clock::get()?;
+    let game_state = &ctx.accounts.game_state;
+    let player = &mut ctx.accounts.player;
+
+    require!(player.is_active, StreakError::NotStaked);
+    require!(player.mystery_boxes > 0, StreakError::NoMysteryBoxes);
+    Jackpot::check_present(ctx.accounts.jackpot.as_ref(), game_state)?;
+    League::check_member(ctx.accounts.league.as_ref(), player)?;
+
+    // The outcome comes from the reveal slot fixed at grant time, so it can't be
+    // known when the box is earned or picked by choosing when to open it. Boxes
+    // left unopened until their slot leaves the SlotHashes window are forfeited,
+    // so waiting out a bad roll never pays.
+    require!(clock.slot > player.box_reveal_slot, StreakError::BoxTooEarly);
+    let randomness = match Randomness::load_at(&ctx.accounts.randomness, player.box_reveal_slot)? {
+        Some(randomness) => randomness,
+        None => {
+            msg!("Box reveal slot expired, {} unopened boxes forfeited", player.mystery_boxes);
+            player.mystery_boxes = 0;
+            return Ok(());
+        }
+    };
+
+    // Jackpot tickets only count while the game runs a jackpot
+    let has_jackpot = game_state.jackpot_bps > 0;
+    let rewards: Vec<_> = game_state.box_rewards
+        .iter()
+        .filter(|reward| reward.kind != 0)
+        .filter(|reward| has_jackpot || reward.kind != BOX_REWARD_JACKPOT_TICKET)
+        .collect();
+    let total_weight = rewards.iter().map(|reward| reward.weight as u64).sum::<u64>();
+    require!(total_weight > 0, StreakError::InvalidBoxRewards);
+
+    let random = randomness.value(&[player.wallet.as_ref(), &player.boxes_opened.to_le_bytes()]);
+    let mut ticket = random % total_weight;
+    let reward = rewards
+        .iter()
+        .find(|reward| {
+            if ticket < reward.weight as u64 {
+                return true;
+            }
+            ticket -= reward.weight as u64;
+            false
+        })
+        .ok_or(StreakError::InvalidBoxRewards)?;
+
+    player.mystery_boxes -= 1;
+    player.boxes_opened = player.boxes_opened.checked_add(1).ok_or(StreakError::Overflow)?;
+
+    // Growth is added to stake the same way check-in growth is (funded by deaths)
+    let amount = match reward.kind {
+        BOX_REWARD_GROWTH => {
+            let growth = player.stake
+                .checked_mul(reward.amount)
+                .ok_or(StreakError::Overflow)?
+                .checked_div(BPS_DENOMINATOR)
+                .ok_or(StreakError::Overflow)?;
+            player.stake = player.stake.checked_add(growth).ok_or(StreakError::Overflow)?;
+
+            // The league's pool grows with the stake, which may move the player up
+            if let Some(league) = ctx.accounts.league.as_mut() {
+                league.add_to_pool(growth)?;
+                league.follow_stake(ctx.accounts.new_league.as_mut(), player)?;
+            }
+            msg!("Mystery box: +{} lamports growth", growth);
+            growth
+        }
+        BOX_REWARD_LIFELINE => {
+            let lifelines = reward.amount.min(u8::MAX as u64) as u8;
+            player.lifelines = player.lifelines.saturating_add(lifelines);
+            msg!("Mystery box: +{} lifelines", lifelines);
+            lifelines as u64
+        }
+        BOX_REWARD_POINTS => {
+            let points = player.credit_points(reward.amount, game_state.points_multiplier_bps, game_state.points_snapshot_id)?;
+            msg!("Mystery box: +{} points", points);
+            points
+        }
+        _ => {
+            let game_period = game_state.period_at(clock.unix_timestamp);
+            let jackpot = ctx.accounts.jackpot.as_mut().ok_or(StreakError::MissingJackpot)?;
+            let mut tickets: u64 = 0;
+            while tickets < reward.amount && jackpot.enter(player, game_period).is_some() {
+                tickets += 1;
+            }
+            msg!("Mystery box: {} jackpot tickets from period {}", tickets, game_period);
+            tickets
+        }
+    };
+
+    emit!(MysteryBoxOpened {
+        player: player.wallet,
+        kind: reward.kind,
+        amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,250 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::{GameState, Player, League, Season, Squad};
+use crate::constants::{
+    GAME_STATE_SEED, PLAYER_SEED, STAKE_VAULT_SEED, MIN_STAKE, BPS_DENOMINATOR,
+    EARLY_EXIT_PENALTY_BPS,
+};
+use crate::errors::StreakError;
+use crate::events::ExitFeeCharged;
+use crate::vault::Vault;
+
+#[derive(Accounts)]
+pub struct PartialWithdraw<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    /// CHECK: Treasury receives its share of exit fees
+    #[account(
+        mut,
+        constraint = treasury.key() == game_state.treasury
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, game_state.game_seed().as_ref(), user.key().as_ref()],
+        bump = player.bump,
+        constraint = player.wallet == user.key()
+    )]
+    pub player: Account<'info, Player>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Sponsor of a sponsored stake (required when the stake returns to them)
+    /// CHECK: Validated against player.sponsor in handler
+    #[account(mut)]
+    pub sponsor: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Token-staked games only: the game's stake vault
+    #[account(
+        mut,
+        seeds = [STAKE_VAULT_SEED, game_state.game_seed().as_ref()],
+        bump
+    )]
+    pub stake_vault: Option<Account<'info, TokenAccount>>,
+
+    /// Token-staked games only: the user's token account for the stake mint
+    #[account(mut)]
+    pub user_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Token-staked games only: the treasury's token account for the stake mint
+    #[account(mut)]
+    pub treasury_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Token-staked games only: the sponsor's token account (when the stake returns to them)
+    #[account(mut)]
+    pub sponsor_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    #[account(
+        mut,
+        constraint = league.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub league: Option<Account<'info, League>>,
+
+    /// League to move to when the stake leaves the current league's band
+    #[account(
+        mut,
+        constraint = new_league.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub new_league: Option<Account<'info, League>>,
+
+    /// Game's current season (required while a shrinking stake lowers the player's season weight)
+    #[account(
+        mut,
+        constraint = season.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub season: Option<Account<'info, Season>>,
+
+    /// Squad the player belongs to (required while they are in one)
+    #[account(
+        mut,
+        constraint = squad.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub squad: Option<Account<'info, Squad>>,
+}
+
+pub fn handler(ctx: Context<PartialWithdraw>, amount: u64) -> Result<u64> {
+    require!(amount > 0, StreakError::InvalidAmount);
+
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+    let game_state = &ctx.accounts.game_state;
+    let interval = game_state.checkin_interval_seconds;
+
+    let player = &mut ctx.accounts.player;
+
+    // Validate player is active and alive (dead stakes belong to the pool)
+    require!(player.is_active, StreakError::NotStaked);
+    League::check_member(ctx.accounts.league.as_ref(), player)?;
+    Squad::check_member(ctx.accounts.squad.as_ref(), player)?;
+    let current_period = player.period_at(current_time, interval);
+    require!(
+        !player.has_missed_checkin(current_period, interval),
+        StreakError::PlayerDead
+    );
+
+    // Queued withdrawals are paid first
+    require!(game_state.withdraw_queue_owed == 0, StreakError::WithdrawQueuePending);
+
+    // Squad penalties owed from squadmates' deaths come out of the stake first
+    // (total_pool is unchanged - the lamports just stop belonging to the player)
+    if let Some(squad) = ctx.accounts.squad.as_mut() {
+        squad.collect_penalties(player, game_state)?;
+    }
+
+    // Remaining stake must stay above the minimum
+    let remaining = player.stake
+        .checked_sub(amount)
+        .ok_or(StreakError::InsufficientFunds)?;
+    require!(remaining >= MIN_STAKE, StreakError::BelowMinimumStake);
+
+    // Exit fee on the withdrawn amount, decaying with streak length
+    let fee = amount
+        .checked_mul(game_state.exit_fee_bps(player.streak_days))
+        .ok_or(StreakError::Overflow)?
+        .checked_div(BPS_DENOMINATOR)
+        .ok_or(StreakError::Overflow)?;
+    let treasury_share = fee
+        .checked_mul(game_state.exit_fee_treasury_bps)
+        .ok_or(StreakError::Overflow)?
+        .checked_div(BPS_DENOMINATOR)
+        .ok_or(StreakError::Overflow)?;
+
+    // Withdrawing before an unfinished commitment target costs an extra penalty (kept by the pool)
+    let early_exit_penalty = if player.is_commitment_locked() {
+        amount
+            .checked_mul(EARLY_EXIT_PENALTY_BPS)
+            .ok_or(StreakError::Overflow)?
+            .checked_div(BPS_DENOMINATOR)
+            .ok_or(StreakError::Overflow)?
+    } else {
+        0
+    };
+
+    let pool_share = fee
+        .checked_sub(treasury_share)
+        .ok_or(StreakError::Overflow)?
+        .checked_add(early_exit_penalty)
+        .ok_or(StreakError::Overflow)?;
+    let payout = amount
+        .checked_sub(fee)
+        .ok_or(StreakError::Overflow)?
+        .checked_sub(early_exit_penalty)
+        .ok_or(StreakError::Overflow)?;
+
+    // Sponsored stakes can be set up to return the stake to the sponsor
+    let to_sponsor = player.withdraw_to_sponsor;
+    if to_sponsor {
+        let sponsor_key = ctx.accounts.sponsor.as_ref().map(|s| s.key());
+        require!(
+            sponsor_key.is_some() && sponsor_key == player.sponsor,
+            StreakError::InvalidSponsor
+        );
+    }
+
+    // Withdrawals come out of growth first, then principal
+    player.stake = remaining;
+    player.principal = player.principal.min(remaining);
+    Season::cap_stake(ctx.accounts.season.as_mut(), player, game_state.current_season_id)?;
+    let streak_days = player.streak_days;
+
+    // Pay out of the vault to user (or sponsor) and treasury
+    let game_state_info = ctx.accounts.game_state.to_account_info();
+    let vault = Vault::load(
+        &ctx.accounts.game_state,
+        ctx.accounts.stake_vault.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    )?;
+    let (recipient, wallet) = match ctx.accounts.sponsor.as_ref() {
+        Some(sponsor) if to_sponsor => (
+            vault.recipient(&sponsor.to_account_info(), ctx.accounts.sponsor_token_account.as_ref())?,
+            sponsor.key(),
+        ),
+        _ => (
+            vault.recipient(&ctx.accounts.user.to_account_info(), ctx.accounts.user_token_account.as_ref())?,
+            ctx.accounts.user.key(),
+        ),
+    };
+    let treasury_recipient = vault.recipient(
+        &ctx.accounts.treasury.to_account_info(),
+        ctx.accounts.treasury_token_account.as_ref(),
+    )?;
+
+    require!(
+        vault.available(&game_state_info)? >= payout.checked_add(treasury_share).ok_or(StreakError::Overflow)?,
+        StreakError::InsufficientFunds
+    );
+    vault.pay_out(&game_state_info, &recipient, wallet, payout)?;
+    vault.pay_out(
+        &game_state_info,
+        &treasury_recipient,
+        ctx.accounts.treasury.key(),
+        treasury_share,
+    )?;
+
+    // The league keeps its share too, and a smaller stake may move the player down
+    if let Some(league) = ctx.accounts.league.as_mut() {
+        league.remove_from_pool(amount.checked_sub(pool_share).ok_or(StreakError::Overflow)?)?;
+        league.follow_stake(ctx.accounts.new_league.as_mut(), &mut ctx.accounts.player)?;
+    }
+
+    // Update game state (pool keeps its share of the exit fee and any penalty)
+    let game_state = &mut ctx.accounts.game_state;
+    game_state.total_pool = game_state.total_pool
+        .checked_sub(amount)
+        .ok_or(StreakError::Overflow)?
+        .checked_add(pool_share)
+        .ok_or(StreakError::Overflow)?;
+
+    emit!(ExitFeeCharged {
+        player: ctx.accounts.user.key(),
+        streak_days,
+        amount_withdrawn: amount,
+        fee,
+        treasury_share,
+        pool_share,
+        early_exit_penalty,
+    });
+
+    msg!("Withdrew {} lamports", payout);
+    if to_sponsor {
+        msg!("Returned to the sponsor");
+    }
+    msg!("Exit fee: {} lamports ({} to treasury, {} to pool)", fee, treasury_share, pool_share);
+    if early_exit_penalty > 0 {
+        msg!("Early exit penalty: {} lamports", early_exit_penalty);
+    }
+    msg!("Remaining stake: {} lamports", remaining);
+
+    Ok(fee)
+}
@@ -1,13 +1,17 @@
 use anchor_lang::prelude::*;
-use crate::state::{GameState, Player};
-use crate::constants::{GAME_STATE_SEED, PLAYER_SEED, PROTOCOL_FEE_BPS, REFERRAL_CUT_BPS, BPS_DENOMINATOR};
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::{Duel, GameState, Player, League, Season, Squad, Jackpot};
+use crate::constants::{
+    GAME_STATE_SEED, PLAYER_SEED, STAKE_VAULT_SEED, PROTOCOL_FEE_BPS, REFERRAL_CUT_BPS, BPS_DENOMINATOR,
+};
 use crate::errors::StreakError;
+use crate::vault::Vault;
 
 #[derive(Accounts)]
 pub struct ProcessDeath<'info> {
     #[account(
         mut,
-        seeds = [GAME_STATE_SEED],
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
         bump = game_state.bump
     )]
     pub game_state: Account<'info, GameState>,
@@ -21,7 +25,7 @@ pub struct ProcessDeath<'info> {
 
     #[account(
         mut,
-        seeds = [PLAYER_SEED, dead_player.wallet.as_ref()],
+        seeds = [PLAYER_SEED, game_state.game_seed().as_ref(), dead_player.wallet.as_ref()],
         bump = dead_player.bump
     )]
     pub dead_player: Account<'info, Player>,
@@ -38,28 +42,78 @@ pub struct ProcessDeath<'info> {
     #[account(mut)]
     pub referrer_3: Option<Account<'info, Player>>,
 
+    /// Sponsor of a sponsored stake (required when they get a refund on death)
+    /// CHECK: Validated against dead_player.sponsor in handler
+    #[account(mut)]
+    pub sponsor: Option<UncheckedAccount<'info>>,
+
     pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        constraint = league.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub league: Option<Account<'info, League>>,
+
+    /// Game's current season (required once the game has seasons)
+    #[account(
+        mut,
+        constraint = season.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub season: Option<Account<'info, Season>>,
+
+    /// Squad the player belongs to (required while they are in one)
+    #[account(
+        mut,
+        constraint = squad.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub squad: Option<Account<'info, Squad>>,
+
+    /// Game's jackpot (required once the game has one)
+    #[account(
+        mut,
+        constraint = jackpot.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub jackpot: Option<Account<'info, Jackpot>>,
+
+    /// Token-staked games only: the game's stake vault
+    #[account(
+        mut,
+        seeds = [STAKE_VAULT_SEED, game_state.game_seed().as_ref()],
+        bump
+    )]
+    pub stake_vault: Option<Account<'info, TokenAccount>>,
+
+    /// Token-staked games only: the sponsor's token account for the stake mint
+    #[account(mut)]
+    pub sponsor_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+    // Remaining accounts: one (duel, opponent's player) pair per open duel
 }
 
-pub fn handler(ctx: Context<ProcessDeath>) -> Result<()> {
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ProcessDeath<'info>>) -> Result<()> {
     let dead_player = &mut ctx.accounts.dead_player;
     let game_state = &mut ctx.accounts.game_state;
     let clock = Clock::get()?;
     let current_time = clock.unix_timestamp;
 
-    // Use configurable interval
+    // Use configurable interval, shifted by the player's deadline offset
     let interval = game_state.checkin_interval_seconds;
-    let current_period = current_time / interval;
+    let current_period = dead_player.period_at(current_time, interval);
 
     // Validate player is active
     require!(dead_player.is_active, StreakError::NotStaked);
+    League::check_member(ctx.accounts.league.as_ref(), dead_player)?;
+    Season::check_current(ctx.accounts.season.as_ref(), game_state)?;
+    Squad::check_member(ctx.accounts.squad.as_ref(), dead_player)?;
+    Jackpot::check_present(ctx.accounts.jackpot.as_ref(), game_state)?;
 
-    // Check if player should actually die
-    let last_checkin_period = dead_player.last_checkin / interval;
-    let start_period = dead_player.start_day / interval;
-
-    // Player dies if they haven't checked in this period and it's not their start period
-    let should_die = last_checkin_period < current_period && start_period < current_period;
+    // Player dies once a whole period passed without a check-in and the late
+    // window for making it up has closed. The current period's check-in is still
+    // open to them, so this is the same rule checkin uses to reject dead players.
+    let should_die = dead_player.has_missed_checkin(current_period, interval)
+        && !dead_player.is_in_late_window(current_time, interval, game_state.late_window_seconds);
     require!(should_die, StreakError::PlayerNotDead);
 
     // Check for lifeline
@@ -73,6 +127,18 @@ pub fn handler(ctx: Context<ProcessDeath>) -> Result<()> {
         return Ok(());
     }
 
+    // Then the squad's shared lifeline pool
+    if let Some(squad) = ctx.accounts.squad.as_mut() {
+        if squad.lifelines > 0 {
+            squad.lifelines -= 1;
+            dead_player.lifelines_used = dead_player.lifelines_used.saturating_add(1);
+            dead_player.last_checkin = current_time; // Reset check-in to today
+
+            msg!("Squad lifeline used! Player survives. Squad lifelines left: {}", squad.lifelines);
+            return Ok(());
+        }
+    }
+
     // No lifeline - player dies
     let stake = dead_player.stake;
 
@@ -89,27 +155,42 @@ pub fn handler(ctx: Context<ProcessDeath>) -> Result<()> {
         .checked_div(BPS_DENOMINATOR)
         .ok_or(StreakError::Overflow)?;
 
+    // Referrers also earn engagement points for each payout
+    let referral_points = game_state.points_per_referral;
+    let points_multiplier_bps = game_state.points_multiplier_bps;
+    let points_snapshot_id = game_state.points_snapshot_id;
+
     let mut total_referral_paid: u64 = 0;
+    let mut total_referral_compounded: u64 = 0;
 
-    // Pay referrers (5% each, up to 3 levels)
+    // Pay referrers (5% each, up to 3 levels, auto-compounding if they opted in).
+    // Only referrer accounts from this arena count.
+    let game_id = game_state.game_id;
+    let dead_league_id = dead_player.league_id;
     if let Some(ref mut r1) = ctx.accounts.referrer_1 {
-        if dead_player.referrer == Some(r1.wallet) {
-            r1.pending_rewards = r1.pending_rewards.checked_add(referral_cut).ok_or(StreakError::Overflow)?;
-            r1.referral_earnings = r1.referral_earnings.checked_add(referral_cut).ok_or(StreakError::Overflow)?;
+        if dead_player.referrer == Some(r1.wallet) && r1.game_id == game_id {
+            r1.credit_points(referral_points, points_multiplier_bps, points_snapshot_id)?;
+            if r1.credit_referral_reward(referral_cut, dead_league_id)? {
+                total_referral_compounded = total_referral_compounded.checked_add(referral_cut).ok_or(StreakError::Overflow)?;
+            }
             total_referral_paid = total_referral_paid.checked_add(referral_cut).ok_or(StreakError::Overflow)?;
 
             // Level 2
             if let Some(ref mut r2) = ctx.accounts.referrer_2 {
-                if r1.referrer == Some(r2.wallet) {
-                    r2.pending_rewards = r2.pending_rewards.checked_add(referral_cut).ok_or(StreakError::Overflow)?;
-                    r2.referral_earnings = r2.referral_earnings.checked_add(referral_cut).ok_or(StreakError::Overflow)?;
+                if r1.referrer == Some(r2.wallet) && r2.game_id == game_id {
+                    r2.credit_points(referral_points, points_multiplier_bps, points_snapshot_id)?;
+                    if r2.credit_referral_reward(referral_cut, dead_league_id)? {
+                        total_referral_compounded = total_referral_compounded.checked_add(referral_cut).ok_or(StreakError::Overflow)?;
+                    }
                     total_referral_paid = total_referral_paid.checked_add(referral_cut).ok_or(StreakError::Overflow)?;
 
                     // Level 3
                     if let Some(ref mut r3) = ctx.accounts.referrer_3 {
-                        if r2.referrer == Some(r3.wallet) {
-                            r3.pending_rewards = r3.pending_rewards.checked_add(referral_cut).ok_or(StreakError::Overflow)?;
-                            r3.referral_earnings = r3.referral_earnings.checked_add(referral_cut).ok_or(StreakError::Overflow)?;
+                        if r2.referrer == Some(r3.wallet) && r3.game_id == game_id {
+                            r3.credit_points(referral_points, points_multiplier_bps, points_snapshot_id)?;
+                            if r3.credit_referral_reward(referral_cut, dead_league_id)? {
+                                total_referral_compounded = total_referral_compounded.checked_add(referral_cut).ok_or(StreakError::Overflow)?;
+                            }
                             total_referral_paid = total_referral_paid.checked_add(referral_cut).ok_or(StreakError::Overflow)?;
                         }
                     }
@@ -119,12 +200,56 @@ pub fn handler(ctx: Context<ProcessDeath>) -> Result<()> {
     }
 
     // Calculate pool addition (stake - protocol fee - referral cuts)
-    let pool_addition = stake
+    let mut pool_addition = stake
         .checked_sub(protocol_fee)
         .ok_or(StreakError::Overflow)?
         .checked_sub(total_referral_paid)
         .ok_or(StreakError::Overflow)?;
 
+    // Sponsored stakes can refund part of the stake to the sponsor, out of the pool's share
+    let mut sponsor_refund: u64 = 0;
+    if dead_player.sponsor.is_some() && dead_player.sponsor_refund_bps > 0 {
+        let sponsor_info = match ctx.accounts.sponsor.as_ref() {
+            Some(sponsor) if Some(sponsor.key()) == dead_player.sponsor => sponsor.to_account_info(),
+            _ => return Err(StreakError::InvalidSponsor.into()),
+        };
+        let vault = Vault::load(
+            game_state,
+            ctx.accounts.stake_vault.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        )?;
+        let recipient = vault.recipient(&sponsor_info, ctx.accounts.sponsor_token_account.as_ref())?;
+
+        // Only what the vault can cover after queued withdrawals; the rest stays in the pool
+        let game_state_info = game_state.to_account_info();
+        let available = vault.available(&game_state_info)?
+            .saturating_sub(game_state.withdraw_queue_owed);
+        sponsor_refund = stake
+            .checked_mul(dead_player.sponsor_refund_bps)
+            .ok_or(StreakError::Overflow)?
+            .checked_div(BPS_DENOMINATOR)
+            .ok_or(StreakError::Overflow)?
+            .min(pool_addition)
+            .min(available);
+
+        vault.pay_out(&game_state_info, &recipient, sponsor_info.key(), sponsor_refund)?;
+
+        pool_addition -= sponsor_refund;
+    }
+
+    // A slice of the pool's share feeds the jackpot (the lamports stay in the vault)
+    let mut jackpot_share: u64 = 0;
+    if let Some(jackpot) = ctx.accounts.jackpot.as_mut() {
+        jackpot_share = stake
+            .checked_mul(game_state.jackpot_bps)
+            .ok_or(StreakError::Overflow)?
+            .checked_div(BPS_DENOMINATOR)
+            .ok_or(StreakError::Overflow)?
+            .min(pool_addition);
+        jackpot.balance = jackpot.balance.checked_add(jackpot_share).ok_or(StreakError::Overflow)?;
+        pool_addition -= jackpot_share;
+    }
+
     // Transfer protocol fee to treasury
     // Note: The actual SOL transfer happens from the game_state PDA
     // For now we track it in the pool and handle transfers separately
@@ -134,12 +259,97 @@ pub fn handler(ctx: Context<ProcessDeath>) -> Result<()> {
     game_state.total_players = game_state.total_players.checked_sub(1).ok_or(StreakError::Overflow)?;
     game_state.last_death_timestamp = current_time;
     // Pool changes: loses the player's stake, but keeps pool_addition
+    // plus any referral cuts compounded straight into referrers' stakes
     game_state.total_pool = game_state.total_pool
         .checked_sub(stake)
         .ok_or(StreakError::Overflow)?
         .checked_add(pool_addition)
+        .ok_or(StreakError::Overflow)?
+        .checked_add(total_referral_compounded)
         .ok_or(StreakError::Overflow)?;
 
+    // The death only feeds the pool of the player's own league. Referral cuts only
+    // compound into stakes in that same league, so those stay in its pool too.
+    if let Some(league) = ctx.accounts.league.as_mut() {
+        let pool_removed = stake
+            .checked_sub(pool_addition)
+            .ok_or(StreakError::Overflow)?
+            .checked_sub(total_referral_compounded)
+            .ok_or(StreakError::Overflow)?;
+        league.leave(dead_player.wallet, pool_removed)?;
+        league.total_deaths = league.total_deaths.checked_add(1).ok_or(StreakError::Overflow)?;
+        league.total_death_pool = league.total_death_pool.checked_add(pool_addition).ok_or(StreakError::Overflow)?;
+    }
+
+    // Deaths during a running season build its death pool; entrants whose streak
+    // broke before the season ended drop out, however late the death is processed
+    if let Some(season) = ctx.accounts.season.as_mut() {
+        let game_period = game_state.period_at(current_time);
+        if season.is_running(game_period) {
+            season.death_pool = season.death_pool.checked_add(pool_addition).ok_or(StreakError::Overflow)?;
+            season.deaths = season.deaths.checked_add(1).ok_or(StreakError::Overflow)?;
+        }
+        let broken_at = dead_player
+            .streak_broken_at(current_time, interval, game_state.late_window_seconds)
+            .unwrap_or(current_time);
+        season.forfeit(dead_player, game_state.period_at(broken_at))?;
+    }
+
+    // Settle every open duel - the opponent wins unless their streak broke first
+    require!(
+        ctx.remaining_accounts.len() == 2 * dead_player.open_duels as usize,
+        StreakError::MissingDuelAccounts
+    );
+    let mut duel_fees: u64 = 0;
+    for pair in ctx.remaining_accounts.chunks(2) {
+        let mut duel: Account<Duel> = Account::try_from(&pair[0])?;
+        require!(duel.game_id == game_state.game_id, StreakError::WrongGame);
+
+        // An opponent who is also a referrer above is updated through that account,
+        // otherwise its own write-back would overwrite the settlement
+        let mut loaded_opponent: Option<Account<Player>> = None;
+        let opponent: &mut Player = match [
+            ctx.accounts.referrer_1.as_mut(),
+            ctx.accounts.referrer_2.as_mut(),
+            ctx.accounts.referrer_3.as_mut(),
+        ]
+        .into_iter()
+        .flatten()
+        .find(|referrer| referrer.key() == pair[1].key())
+        {
+            Some(referrer) => referrer,
+            None => loaded_opponent.insert(Account::try_from(&pair[1])?),
+        };
+        require!(
+            opponent.wallet == duel.counterpart(&dead_player.wallet)? && opponent.game_id == game_state.game_id,
+            StreakError::MissingDuelAccounts
+        );
+
+        let fee = if duel.challenger == dead_player.wallet {
+            duel.settle(dead_player, opponent, game_state, current_time)?
+        } else {
+            duel.settle(opponent, dead_player, game_state, current_time)?
+        };
+        Duel::transfer_lamports(&pair[0], &ctx.accounts.treasury.to_account_info(), fee)?;
+        duel_fees = duel_fees.checked_add(fee).ok_or(StreakError::Overflow)?;
+
+        duel.exit(&crate::ID)?;
+        if let Some(opponent) = loaded_opponent {
+            opponent.exit(&crate::ID)?;
+        }
+    }
+
+    // The player leaves their squad, and every remaining member owes a penalty
+    if let Some(squad) = ctx.accounts.squad.as_mut() {
+        squad.record_death(dead_player)?;
+    }
+
+    // Keep a death record so the player can revive within the recovery window
+    dead_player.stake_at_death = stake;
+    dead_player.streak_at_death = dead_player.streak_days;
+    dead_player.died_at = current_time;
+    dead_player.death_pool_share = pool_addition;
+
     // Mark player as dead
     dead_player.is_active = false;
     dead_player.stake = 0;
@@ -147,8 +357,17 @@ pub fn handler(ctx: Context<ProcessDeath>) -> Result<()> {
 
     msg!("Player died! Lost: {} lamports", stake);
     msg!("Protocol fee: {} lamports", protocol_fee);
-    msg!("Referral payouts: {} lamports", total_referral_paid);
+    msg!("Referral payouts: {} lamports ({} compounded)", total_referral_paid, total_referral_compounded);
+    if sponsor_refund > 0 {
+        msg!("Refunded to sponsor: {} lamports", sponsor_refund);
+    }
+    if jackpot_share > 0 {
+        msg!("Added to jackpot: {} lamports", jackpot_share);
+    }
     msg!("Added to pool: {} lamports", pool_addition);
+    if !ctx.remaining_accounts.is_empty() {
+        msg!("Settled {} duels ({} lamports in fees)", ctx.remaining_accounts.len() / 2, duel_fees);
+    }
 
     Ok(())
 }
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::{GameState, WithdrawQueue};
+use crate::constants::{GAME_STATE_SEED, STAKE_VAULT_SEED, WITHDRAW_QUEUE_SEED};
+use crate::errors::StreakError;
+use crate::vault::Vault;
+
+#[derive(Accounts)]
+pub struct ProcessWithdrawQueue<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        mut,
+        seeds = [WITHDRAW_QUEUE_SEED, game_state.game_seed().as_ref()],
+        bump = withdraw_queue.bump
+    )]
+    pub withdraw_queue: Account<'info, WithdrawQueue>,
+
+    /// Token-staked games only: the game's stake vault
+    #[account(
+        mut,
+        seeds = [STAKE_VAULT_SEED, game_state.game_seed().as_ref()],
+        bump
+    )]
+    pub stake_vault: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+    // Remaining accounts: wallets of the queue entries in queue order
+    // (their stake mint token accounts in token-staked games)
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ProcessWithdrawQueue<'info>>) -> Result<()> {
+    let game_state_info = ctx.accounts.game_state.to_account_info();
+    let vault = Vault::load(
+        &ctx.accounts.game_state,
+        ctx.accounts.stake_vault.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    )?;
+    let withdraw_queue = &mut ctx.accounts.withdraw_queue;
+
+    let mut available = vault.available(&game_state_info)?;
+    let mut total_paid: u64 = 0;
+
+    // Pay entries front to back until the vault or the passed wallets run out
+    for wallet_info in ctx.remaining_accounts.iter() {
+        if available == 0 || withdraw_queue.entries.is_empty() {
+            break;
+        }
+
+        let entry = &mut withdraw_queue.entries[0];
+        // Token accounts are checked against the entry's wallet by the vault
+        if let Vault::Sol = vault {
+            require!(wallet_info.key() == entry.wallet, StreakError::InvalidQueueAccount);
+        }
+
+        let payment = entry.amount_owed.min(available);
+
+        vault.pay_out(&game_state_info, wallet_info, entry.wallet, payment)?;
+
+        entry.amount_owed -= payment;
+        available -= payment;
+        total_paid = total_paid.checked_add(payment).ok_or(StreakError::Overflow)?;
+
+        if entry.amount_owed == 0 {
+            withdraw_queue.entries.remove(0);
+        }
+    }
+
+    withdraw_queue.total_owed = withdraw_queue.total_owed
+        .checked_sub(total_paid)
+        .ok_or(StreakError::Overflow)?;
+    withdraw_queue.total_paid = withdraw_queue.total_paid
+        .checked_add(total_paid)
+        .ok_or(StreakError::Overflow)?;
+
+    let game_state = &mut ctx.accounts.game_state;
+    game_state.withdraw_queue_owed = withdraw_queue.total_owed;
+
+    msg!("Withdrawal queue paid {} lamports", total_paid);
+    msg!("Still owed: {} lamports across {} entries", withdraw_queue.total_owed, withdraw_queue.entries.len());
+
+    Ok(())
+}
@@ -0,0 +1,162 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::{GameState, Player, League, Season};
+use crate::constants::{GAME_STATE_SEED, PLAYER_SEED, STAKE_VAULT_SEED, BPS_DENOMINATOR, MIN_STAKE};
+use crate::errors::StreakError;
+use crate::vault::Vault;
+
+#[derive(Accounts)]
+pub struct Revive<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, game_state.game_seed().as_ref(), user.key().as_ref()],
+        bump = player.bump,
+        constraint = player.wallet == user.key()
+    )]
+    pub player: Account<'info, Player>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        constraint = league.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub league: Option<Account<'info, League>>,
+
+    /// League to move to when the restored stake belongs to another league's band
+    #[account(
+        mut,
+        constraint = new_league.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub new_league: Option<Account<'info, League>>,
+
+    /// Game's current season (required once the game has seasons)
+    #[account(
+        mut,
+        constraint = season.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub season: Option<Account<'info, Season>>,
+
+    /// Token-staked games only: the game's stake vault
+    #[account(
+        mut,
+        seeds = [STAKE_VAULT_SEED, game_state.game_seed().as_ref()],
+        bump
+    )]
+    pub stake_vault: Option<Account<'info, TokenAccount>>,
+
+    /// Token-staked games only: the user's token account for the stake mint
+    #[account(mut)]
+    pub user_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+pub fn handler(ctx: Context<Revive>) -> Result<()> {
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+    let game_state = &ctx.accounts.game_state;
+    let player = &ctx.accounts.player;
+
+    // Validate there is a death to revive from
+    require!(!player.is_active, StreakError::AlreadyStaked);
+    require!(player.stake_at_death > 0, StreakError::NothingToRevive);
+    League::check_member(ctx.accounts.league.as_ref(), player)?;
+    Season::check_current(ctx.accounts.season.as_ref(), game_state)?;
+
+    // Validate revives are enabled and the recovery window is still open
+    require!(game_state.revive_window_seconds > 0, StreakError::RevivesDisabled);
+    let window_end = player.died_at
+        .checked_add(game_state.revive_window_seconds)
+        .ok_or(StreakError::Overflow)?;
+    require!(current_time <= window_end, StreakError::RecoveryWindowExpired);
+
+    // Price of the revive (paid by the player into the pool)
+    let cost = player.stake_at_death
+        .checked_mul(game_state.revive_cost_bps)
+        .ok_or(StreakError::Overflow)?
+        .checked_div(BPS_DENOMINATOR)
+        .ok_or(StreakError::Overflow)?;
+
+    // Restored stake is clawed back from the pool's share of the death only -
+    // the protocol fee and referral payouts stay where they went
+    let restored_stake = player.stake_at_death
+        .checked_mul(game_state.revive_stake_bps)
+        .ok_or(StreakError::Overflow)?
+        .checked_div(BPS_DENOMINATOR)
+        .ok_or(StreakError::Overflow)?
+        .min(player.death_pool_share);
+    require!(restored_stake >= MIN_STAKE, StreakError::BelowMinimumStake);
+
+    // Transfer revive cost (SOL or the game's token) from user to the vault
+    if cost > 0 {
+        let vault = Vault::load(
+            game_state,
+            ctx.accounts.stake_vault.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        )?;
+        vault.deposit(
+            &ctx.accounts.game_state.to_account_info(),
+            &ctx.accounts.user.to_account_info(),
+            ctx.accounts.user_token_account.as_ref(),
+            &ctx.accounts.system_program.to_account_info(),
+            cost,
+        )?;
+    }
+
+    // Restart the check-in clock from the current period
+    let interval = game_state.checkin_interval_seconds;
+    let current_period = player.period_at(current_time, interval);
+
+    let player = &mut ctx.accounts.player;
+    player.stake = restored_stake;
+    player.principal = restored_stake;
+    player.streak_days = player.streak_at_death;
+    player.growth_streak_days = 1;
+    player.last_checkin = current_time;
+    player.start_day = current_period * interval + player.deadline_offset;
+    player.is_active = true;
+    player.freeze_start_period = 0;
+    player.freeze_end_period = 0;
+
+    // A death that built the running season's death pool takes the restored stake back out of it
+    if let Some(season) = ctx.accounts.season.as_mut() {
+        if season.is_running(game_state.period_at(player.died_at)) {
+            season.death_pool = season.death_pool.checked_sub(restored_stake).ok_or(StreakError::Overflow)?;
+        }
+    }
+
+    // Clear the death record (a revive can only happen once per death)
+    player.stake_at_death = 0;
+    player.streak_at_death = 0;
+    player.death_pool_share = 0;
+
+    // Rejoin the league the player died in (the restored stake never left its pool),
+    // moving on if the smaller stake belongs to another band
+    if let Some(league) = ctx.accounts.league.as_mut() {
+        league.join(player)?;
+        league.add_to_pool(cost)?;
+        league.follow_stake(ctx.accounts.new_league.as_mut(), player)?;
+    }
+
+    // Update game state (restored stake was already counted in the pool)
+    let game_state = &mut ctx.accounts.game_state;
+    game_state.total_players = game_state.total_players.checked_add(1).ok_or(StreakError::Overflow)?;
+    game_state.total_pool = game_state.total_pool.checked_add(cost).ok_or(StreakError::Overflow)?;
+
+    msg!("Player revived! Streak restored: {} days", player.streak_days);
+    msg!("Revive cost: {} lamports", cost);
+    msg!("Restored stake: {} lamports", restored_stake);
+
+    Ok(())
+}
@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameState, Player, League, Season};
+use crate::constants::{
+    GAME_STATE_SEED, PLAYER_SEED, FREEZE_FEE_BPS, MAX_FREEZE_PERIODS, MAX_FREEZES_PER_STAKE,
+    MAX_FREEZE_LEAD_PERIODS, BPS_DENOMINATOR,
+};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct ScheduleFreeze<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, game_state.game_seed().as_ref(), user.key().as_ref()],
+        bump = player.bump,
+        constraint = player.wallet == user.key()
+    )]
+    pub player: Account<'info, Player>,
+
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = league.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub league: Option<Account<'info, League>>,
+
+    /// League to move to when the freeze fee takes the stake out of the current league's band
+    #[account(
+        mut,
+        constraint = new_league.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub new_league: Option<Account<'info, League>>,
+
+    /// Game's current season (required while a shrinking stake lowers the player's season weight)
+    #[account(
+        mut,
+        constraint = season.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub season: Option<Account<'info, Season>>,
+}
+
+pub fn handler(
+    ctx: Context<ScheduleFreeze>,
+    start_period: i64,
+    n_periods: u32,
+    use_lifeline: bool,
+) -> Result<()> {
+    let player = &mut ctx.accounts.player;
+    let game_state = &ctx.accounts.game_state;
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+
+    let interval = game_state.checkin_interval_seconds;
+    let current_period = player.period_at(current_time, interval);
+
+    // Validate player is active and alive
+    require!(player.is_active, StreakError::NotStaked);
+    League::check_member(ctx.accounts.league.as_ref(), player)?;
+    require!(
+        !player.has_missed_checkin(current_period, interval),
+        StreakError::PlayerDead
+    );
+
+    // Validate the requested freeze
+    require!(
+        start_period > current_period && start_period - current_period <= MAX_FREEZE_LEAD_PERIODS,
+        StreakError::InvalidFreeze
+    );
+    require!(
+        n_periods > 0 && n_periods <= MAX_FREEZE_PERIODS,
+        StreakError::InvalidFreeze
+    );
+    require!(
+        player.freeze_end_period <= current_period,
+        StreakError::FreezeAlreadyScheduled
+    );
+    require!(
+        player.freezes_used < MAX_FREEZES_PER_STAKE,
+        StreakError::FreezeLimitReached
+    );
+
+    // Pay for the freeze with a lifeline or an upfront fee from stake
+    let mut fee: u64 = 0;
+    if use_lifeline {
+        require!(player.lifelines > 0, StreakError::NoLifelines);
+        player.lifelines = player.lifelines.checked_sub(1).unwrap();
+        player.lifelines_used = player.lifelines_used.saturating_add(1);
+    } else {
+        // Fee moves from stake to the pool (total_pool is unchanged)
+        fee = player.stake
+            .checked_mul(FREEZE_FEE_BPS)
+            .ok_or(StreakError::Overflow)?
+            .checked_mul(n_periods as u64)
+            .ok_or(StreakError::Overflow)?
+            .checked_div(BPS_DENOMINATOR)
+            .ok_or(StreakError::Overflow)?;
+
+        player.stake = player.stake.checked_sub(fee).ok_or(StreakError::Overflow)?;
+        Season::cap_stake(ctx.accounts.season.as_mut(), player, game_state.current_season_id)?;
+
+        // The fee stays in the league's pool, but the smaller stake may move the player down
+        if let Some(league) = ctx.accounts.league.as_mut() {
+            league.follow_stake(ctx.accounts.new_league.as_mut(), player)?;
+        }
+    }
+
+    player.freeze_start_period = start_period;
+    player.freeze_end_period = start_period
+        .checked_add(n_periods as i64)
+        .ok_or(StreakError::Overflow)?;
+    player.freezes_used = player.freezes_used.checked_add(1).ok_or(StreakError::Overflow)?;
+
+    msg!("Freeze scheduled: periods {} to {}", start_period, player.freeze_end_period - 1);
+    if use_lifeline {
+        msg!("Paid with a lifeline. Remaining lifelines: {}", player.lifelines);
+    } else {
+        msg!("Freeze fee: {} lamports moved to pool", fee);
+    }
+
+    Ok(())
+}
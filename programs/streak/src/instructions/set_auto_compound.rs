@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+use crate::state::Player;
+use crate::constants::PLAYER_SEED;
+
+#[derive(Accounts)]
+pub struct SetAutoCompound<'info> {
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, player.game_seed().as_ref(), user.key().as_ref()],
+        bump = player.bump,
+        constraint = player.wallet == user.key()
+    )]
+    pub player: Account<'info, Player>,
+
+    pub user: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetAutoCompound>, enabled: bool) -> Result<()> {
+    let player = &mut ctx.accounts.player;
+    player.auto_compound = enabled;
+
+    msg!("Auto-compound referral rewards: {}", enabled);
+
+    Ok(())
+}
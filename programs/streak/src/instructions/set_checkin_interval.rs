@@ -7,7 +7,7 @@ use crate::errors::StreakError;
 pub struct SetCheckinInterval<'info> {
     #[account(
         mut,
-        seeds = [GAME_STATE_SEED],
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
         bump = game_state.bump,
         has_one = authority @ StreakError::Unauthorized
     )]
@@ -19,7 +19,13 @@ pub struct SetCheckinInterval<'info> {
 pub fn handler(ctx: Context<SetCheckinInterval>, interval_seconds: i64) -> Result<()> {
     require!(interval_seconds > 0, StreakError::InvalidInterval);
 
+    // The late window has to fit inside the new interval
     let game_state = &mut ctx.accounts.game_state;
+    require!(
+        game_state.late_window_seconds < interval_seconds,
+        StreakError::InvalidLateWindow
+    );
+
     let old_interval = game_state.checkin_interval_seconds;
     game_state.checkin_interval_seconds = interval_seconds;
 
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::state::GameState;
+use crate::constants::{GAME_STATE_SEED, BPS_DENOMINATOR};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct SetExitFeeConfig<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump,
+        has_one = authority @ StreakError::Unauthorized
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetExitFeeConfig>,
+    max_bps: u64,
+    zero_fee_streak_days: u32,
+    treasury_bps: u64,
+) -> Result<()> {
+    require!(max_bps <= BPS_DENOMINATOR, StreakError::InvalidBps);
+    require!(treasury_bps <= BPS_DENOMINATOR, StreakError::InvalidBps);
+
+    let game_state = &mut ctx.accounts.game_state;
+    game_state.exit_fee_max_bps = max_bps;
+    game_state.exit_fee_zero_streak_days = zero_fee_streak_days;
+    game_state.exit_fee_treasury_bps = treasury_bps;
+
+    msg!("Exit fee: {} bps, zero after {} days", max_bps, zero_fee_streak_days);
+    msg!("Treasury share: {} bps", treasury_bps);
+
+    Ok(())
+}
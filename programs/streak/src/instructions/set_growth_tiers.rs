@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameState, GrowthTier};
+use crate::constants::{GAME_STATE_SEED, BPS_DENOMINATOR, MAX_GROWTH_TIERS};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct SetGrowthTiers<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump,
+        has_one = authority @ StreakError::Unauthorized
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetGrowthTiers>, tiers: Vec<GrowthTier>) -> Result<()> {
+    require!(tiers.len() <= MAX_GROWTH_TIERS, StreakError::InvalidGrowthTiers);
+
+    // Tiers must be strictly increasing and non-empty
+    let mut previous_min = 0;
+    for tier in tiers.iter() {
+        require!(tier.min_streak_days > previous_min, StreakError::InvalidGrowthTiers);
+        require!(tier.extra_growth_bps <= BPS_DENOMINATOR, StreakError::InvalidBps);
+        require!(tier.milestone_reward_bps <= BPS_DENOMINATOR, StreakError::InvalidBps);
+        previous_min = tier.min_streak_days;
+    }
+
+    // Unused slots are cleared
+    let game_state = &mut ctx.accounts.game_state;
+    game_state.growth_tiers = [GrowthTier::default(); MAX_GROWTH_TIERS];
+    game_state.growth_tiers[..tiers.len()].copy_from_slice(&tiers);
+
+    msg!("Growth tiers updated: {} tiers", tiers.len());
+
+    Ok(())
+}
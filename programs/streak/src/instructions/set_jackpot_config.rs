@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameState, Jackpot};
+use crate::constants::{GAME_STATE_SEED, JACKPOT_SEED, BPS_DENOMINATOR};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct SetJackpotConfig<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump,
+        has_one = authority @ StreakError::Unauthorized
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        mut,
+        seeds = [JACKPOT_SEED, game_state.game_seed().as_ref()],
+        bump = jackpot.bump
+    )]
+    pub jackpot: Account<'info, Jackpot>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetJackpotConfig>, death_share_bps: u64, weight_by_streak: bool) -> Result<()> {
+    require!(death_share_bps <= BPS_DENOMINATOR, StreakError::InvalidBps);
+
+    ctx.accounts.game_state.jackpot_bps = death_share_bps;
+    ctx.accounts.jackpot.weight_by_streak = weight_by_streak;
+
+    msg!("Jackpot share: {} bps of each death", death_share_bps);
+    msg!("Draws weighted by {}", if weight_by_streak { "streak" } else { "stake" });
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::state::GameState;
+use crate::constants::{GAME_STATE_SEED, BPS_DENOMINATOR};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct SetLateCheckinConfig<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump,
+        has_one = authority @ StreakError::Unauthorized
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetLateCheckinConfig>, window_seconds: i64, penalty_bps: u64) -> Result<()> {
+    let game_state = &mut ctx.accounts.game_state;
+
+    require!(
+        window_seconds >= 0 && window_seconds < game_state.checkin_interval_seconds,
+        StreakError::InvalidLateWindow
+    );
+    require!(penalty_bps <= BPS_DENOMINATOR, StreakError::InvalidBps);
+
+    game_state.late_window_seconds = window_seconds;
+    game_state.late_penalty_bps = penalty_bps;
+
+    msg!("Late window: {} seconds", window_seconds);
+    msg!("Late penalty: {} bps", penalty_bps);
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameState, BoxReward};
+use crate::constants::{
+    GAME_STATE_SEED, BPS_DENOMINATOR, MAX_BOX_REWARDS, BOX_REWARD_GROWTH, BOX_REWARD_JACKPOT_TICKET,
+};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct SetMysteryBoxConfig<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump,
+        has_one = authority @ StreakError::Unauthorized
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetMysteryBoxConfig>, interval_days: u32, rewards: Vec<BoxReward>) -> Result<()> {
+    require!(rewards.len() <= MAX_BOX_REWARDS, StreakError::InvalidBoxRewards);
+
+    // Every outcome needs a known kind and a chance of being rolled
+    for reward in rewards.iter() {
+        require!(
+            (BOX_REWARD_GROWTH..=BOX_REWARD_JACKPOT_TICKET).contains(&reward.kind) && reward.weight > 0,
+            StreakError::InvalidBoxRewards
+        );
+        if reward.kind == BOX_REWARD_GROWTH {
+            require!(reward.amount <= BPS_DENOMINATOR, StreakError::InvalidBps);
+        }
+    }
+    require!(interval_days == 0 || !rewards.is_empty(), StreakError::InvalidBoxRewards);
+
+    // Unused slots are cleared
+    let game_state = &mut ctx.accounts.game_state;
+    game_state.box_interval_days = interval_days;
+    game_state.box_rewards = [BoxReward::default(); MAX_BOX_REWARDS];
+    game_state.box_rewards[..rewards.len()].copy_from_slice(&rewards);
+
+    msg!("Mystery box every {} days, {} outcomes", interval_days, rewards.len());
+
+    Ok(())
+}
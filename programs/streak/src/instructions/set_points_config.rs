@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::GameState;
+use crate::constants::GAME_STATE_SEED;
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct SetPointsConfig<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump,
+        has_one = authority @ StreakError::Unauthorized
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetPointsConfig>,
+    per_checkin: u64,
+    per_bonus_claim: u64,
+    per_referral: u64,
+    per_milestone: u64,
+    multiplier_bps: u64,
+) -> Result<()> {
+    let game_state = &mut ctx.accounts.game_state;
+
+    game_state.points_per_checkin = per_checkin;
+    game_state.points_per_bonus_claim = per_bonus_claim;
+    game_state.points_per_referral = per_referral;
+    game_state.points_per_milestone = per_milestone;
+    game_state.points_multiplier_bps = multiplier_bps;
+
+    msg!(
+        "Points per check-in: {}, bonus claim: {}, referral: {}, milestone: {}",
+        per_checkin,
+        per_bonus_claim,
+        per_referral,
+        per_milestone
+    );
+    msg!("Points multiplier: {} bps", multiplier_bps);
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::state::GameState;
+use crate::constants::{GAME_STATE_SEED, BPS_DENOMINATOR};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct SetReviveConfig<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump,
+        has_one = authority @ StreakError::Unauthorized
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetReviveConfig>,
+    window_seconds: i64,
+    cost_bps: u64,
+    stake_bps: u64,
+) -> Result<()> {
+    require!(window_seconds >= 0, StreakError::InvalidRecoveryWindow);
+    require!(cost_bps <= BPS_DENOMINATOR, StreakError::InvalidBps);
+    require!(stake_bps <= BPS_DENOMINATOR, StreakError::InvalidBps);
+
+    let game_state = &mut ctx.accounts.game_state;
+    game_state.revive_window_seconds = window_seconds;
+    game_state.revive_cost_bps = cost_bps;
+    game_state.revive_stake_bps = stake_bps;
+
+    msg!("Recovery window: {} seconds", window_seconds);
+    msg!("Revive cost: {} bps, restored stake: {} bps", cost_bps, stake_bps);
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use crate::state::GameState;
+use crate::constants::GAME_STATE_SEED;
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct SetRewardMint<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump,
+        has_one = authority @ StreakError::Unauthorized
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    /// Mint authority must already be the game state PDA
+    #[account(
+        constraint = reward_mint.mint_authority == Some(game_state.key()).into() @ StreakError::InvalidRewardMint
+    )]
+    pub reward_mint: Account<'info, Mint>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetRewardMint>,
+    per_checkin: u64,
+    per_bonus_claim: u64,
+    per_milestone: u64,
+    halving_seconds: i64,
+) -> Result<()> {
+    require!(halving_seconds >= 0, StreakError::InvalidAmount);
+
+    let clock = Clock::get()?;
+    let game_state = &mut ctx.accounts.game_state;
+
+    // Halvings count from when this mint started emitting
+    if game_state.reward_mint != ctx.accounts.reward_mint.key() {
+        game_state.reward_mint = ctx.accounts.reward_mint.key();
+        game_state.reward_emission_start = clock.unix_timestamp;
+    }
+    game_state.reward_per_checkin = per_checkin;
+    game_state.reward_per_bonus_claim = per_bonus_claim;
+    game_state.reward_per_milestone = per_milestone;
+    game_state.reward_halving_seconds = halving_seconds;
+
+    msg!("Reward mint: {}", game_state.reward_mint);
+    msg!(
+        "Tokens per check-in: {}, bonus claim: {}, milestone: {}",
+        per_checkin,
+        per_bonus_claim,
+        per_milestone
+    );
+    msg!("Halving every {} seconds", halving_seconds);
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::state::GameState;
+use crate::constants::{GAME_STATE_SEED, BPS_DENOMINATOR};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct SetSquadConfig<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump,
+        has_one = authority @ StreakError::Unauthorized
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetSquadConfig>, growth_bonus_bps: u64, penalty_bps: u64) -> Result<()> {
+    let game_state = &mut ctx.accounts.game_state;
+
+    require!(growth_bonus_bps <= BPS_DENOMINATOR, StreakError::InvalidBps);
+    require!(penalty_bps <= BPS_DENOMINATOR, StreakError::InvalidBps);
+
+    game_state.squad_growth_bonus_bps = growth_bonus_bps;
+    game_state.squad_penalty_bps = penalty_bps;
+
+    msg!("Squad growth bonus: {} bps", growth_bonus_bps);
+    msg!("Squad penalty: {} bps", penalty_bps);
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameState, Player};
+use crate::constants::{GAME_STATE_SEED, PLAYER_SEED};
+use crate::errors::StreakError;
+use crate::events::PointsSnapshot;
+
+#[derive(Accounts)]
+pub struct SnapshotPoints<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, game_state.game_seed().as_ref(), player.wallet.as_ref()],
+        bump = player.bump
+    )]
+    pub player: Account<'info, Player>,
+}
+
+pub fn handler(ctx: Context<SnapshotPoints>) -> Result<()> {
+    let snapshot_id = ctx.accounts.game_state.points_snapshot_id;
+    let player = &mut ctx.accounts.player;
+
+    // Each player is recorded once per snapshot
+    require!(snapshot_id > 0, StreakError::NoPointsSnapshot);
+    require!(player.points_snapshot_id < snapshot_id, StreakError::AlreadySnapshotted);
+
+    // Points are frozen at the snapshot boundary; if nothing was credited
+    // since it started, the live balance is still the boundary value
+    player.freeze_snapshot_points(snapshot_id);
+    player.points_snapshot_id = snapshot_id;
+
+    emit!(PointsSnapshot {
+        player: player.wallet,
+        snapshot_id,
+        points: player.snapshot_points,
+    });
+
+    msg!("Snapshot {}: {} has {} points", snapshot_id, player.wallet, player.snapshot_points);
+
+    Ok(())
+}
@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::{GameState, Player, League};
+use crate::constants::{
+    GAME_STATE_SEED, PLAYER_SEED, STAKE_VAULT_SEED, MIN_STAKE, MAX_DEADLINE_OFFSET_SECONDS, BPS_DENOMINATOR,
+};
+use crate::errors::StreakError;
+use crate::vault::Vault;
+
+#[derive(Accounts)]
+pub struct SponsorStake<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        init_if_needed,
+        payer = sponsor,
+        space = Player::SIZE,
+        seeds = [PLAYER_SEED, game_state.game_seed().as_ref(), beneficiary.key().as_ref()],
+        bump
+    )]
+    pub player: Account<'info, Player>,
+
+    /// Wallet receiving the stake; signs to accept it
+    pub beneficiary: Signer<'info>,
+
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        constraint = league.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub league: Option<Account<'info, League>>,
+
+    /// Token-staked games only: the game's stake vault
+    #[account(
+        mut,
+        seeds = [STAKE_VAULT_SEED, game_state.game_seed().as_ref()],
+        bump
+    )]
+    pub stake_vault: Option<Account<'info, TokenAccount>>,
+
+    /// Token-staked games only: the sponsor's token account for the stake mint
+    #[account(mut)]
+    pub sponsor_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+pub fn handler(
+    ctx: Context<SponsorStake>,
+    amount: u64,
+    deadline_offset: i64,
+    withdraw_to_sponsor: bool,
+    death_refund_bps: u64,
+) -> Result<()> {
+    // Validate minimum stake
+    require!(amount >= MIN_STAKE, StreakError::BelowMinimumStake);
+
+    // Validate deadline offset (seconds after the UTC period boundary)
+    require!(
+        (0..MAX_DEADLINE_OFFSET_SECONDS).contains(&deadline_offset),
+        StreakError::InvalidDeadlineOffset
+    );
+
+    // Validate refund share of the stake on death
+    require!(death_refund_bps <= BPS_DENOMINATOR, StreakError::InvalidBps);
+
+    // Sponsoring yourself is just a stake
+    require!(
+        ctx.accounts.beneficiary.key() != ctx.accounts.sponsor.key(),
+        StreakError::InvalidBeneficiary
+    );
+
+    let player = &mut ctx.accounts.player;
+
+    // Beneficiary can't already be playing, or have anything left to claim
+    if player.wallet != Pubkey::default() {
+        require!(!player.is_active, StreakError::AlreadyStaked);
+        require!(!player.has_outstanding_claims(), StreakError::OutstandingClaims);
+    }
+
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+    let interval = ctx.accounts.game_state.checkin_interval_seconds;
+
+    // Start this period, or the next one inside the grace period
+    let start_day = Player::start_day_for(current_time, interval, deadline_offset);
+
+    // Transfer the stake (SOL or the game's token) from sponsor to the vault
+    let vault = Vault::load(
+        &ctx.accounts.game_state,
+        ctx.accounts.stake_vault.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    )?;
+    vault.deposit(
+        &ctx.accounts.game_state.to_account_info(),
+        &ctx.accounts.sponsor.to_account_info(),
+        ctx.accounts.sponsor_token_account.as_ref(),
+        &ctx.accounts.system_program.to_account_info(),
+        amount,
+    )?;
+
+    // Initialize/reset the beneficiary's player account (lifetime stats survive re-stakes)
+    player.begin_stake(
+        ctx.accounts.beneficiary.key(),
+        ctx.accounts.game_state.game_id,
+        amount,
+        current_time,
+        start_day,
+        deadline_offset,
+        0,
+    );
+    player.bump = ctx.bumps.player;
+    player.sponsor = Some(ctx.accounts.sponsor.key());
+    player.withdraw_to_sponsor = withdraw_to_sponsor;
+    player.sponsor_refund_bps = death_refund_bps;
+    League::place(&ctx.accounts.game_state, ctx.accounts.league.as_mut(), player)?;
+
+    // Update game state
+    let game_state = &mut ctx.accounts.game_state;
+    game_state.total_players = game_state.total_players.checked_add(1).ok_or(StreakError::Overflow)?;
+    game_state.total_pool = game_state.total_pool.checked_add(amount).ok_or(StreakError::Overflow)?;
+
+    msg!("Sponsor {} staked {} lamports for {}", ctx.accounts.sponsor.key(), amount, ctx.accounts.beneficiary.key());
+    msg!("Start day: {}", start_day);
+    if withdraw_to_sponsor {
+        msg!("Withdrawn stake returns to the sponsor");
+    }
+    if death_refund_bps > 0 {
+        msg!("Sponsor refund on death: {} bps", death_refund_bps);
+    }
+
+    Ok(())
+}
@@ -1,14 +1,18 @@
 use anchor_lang::prelude::*;
-use anchor_lang::system_program;
-use crate::state::{GameState, Player};
-use crate::constants::{GAME_STATE_SEED, PLAYER_SEED, MIN_STAKE, GRACE_PERIOD_SECONDS, REFS_PER_LIFELINE};
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::{GameState, Player, League};
+use crate::constants::{
+    GAME_STATE_SEED, PLAYER_SEED, MIN_STAKE, REFS_PER_LIFELINE,
+    MAX_DEADLINE_OFFSET_SECONDS, COMMITMENT_TIERS, MAX_COMMITMENT_DAYS, STAKE_VAULT_SEED,
+};
 use crate::errors::StreakError;
+use crate::vault::Vault;
 
 #[derive(Accounts)]
 pub struct Stake<'info> {
     #[account(
         mut,
-        seeds = [GAME_STATE_SEED],
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
         bump = game_state.bump
     )]
     pub game_state: Account<'info, GameState>,
@@ -17,7 +21,7 @@ pub struct Stake<'info> {
         init_if_needed,
         payer = user,
         space = Player::SIZE,
-        seeds = [PLAYER_SEED, user.key().as_ref()],
+        seeds = [PLAYER_SEED, game_state.game_seed().as_ref(), user.key().as_ref()],
         bump
     )]
     pub player: Account<'info, Player>,
@@ -32,16 +36,49 @@ pub struct Stake<'info> {
     pub user: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Token-staked games only: the game's stake vault
+    #[account(
+        mut,
+        seeds = [STAKE_VAULT_SEED, game_state.game_seed().as_ref()],
+        bump
+    )]
+    pub stake_vault: Option<Account<'info, TokenAccount>>,
+
+    /// Token-staked games only: the user's token account for the stake mint
+    #[account(mut)]
+    pub user_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    #[account(
+        mut,
+        constraint = league.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub league: Option<Account<'info, League>>,
 }
 
-pub fn handler(ctx: Context<Stake>, amount: u64, referrer: Option<Pubkey>) -> Result<()> {
+pub fn handler(
+    ctx: Context<Stake>,
+    amount: u64,
+    referrer: Option<Pubkey>,
+    deadline_offset: i64,
+    commitment_days: u32,
+) -> Result<()> {
     // Validate minimum stake
     require!(amount >= MIN_STAKE, StreakError::BelowMinimumStake);
 
-    // Validate user has enough funds
+    // Validate deadline offset (seconds after the UTC period boundary)
     require!(
-        ctx.accounts.user.lamports() >= amount,
-        StreakError::InsufficientFunds
+        (0..MAX_DEADLINE_OFFSET_SECONDS).contains(&deadline_offset),
+        StreakError::InvalidDeadlineOffset
+    );
+
+    // Validate commitment target (0 = no commitment)
+    require!(
+        commitment_days == 0
+            || (commitment_days >= COMMITMENT_TIERS[0].0 && commitment_days <= MAX_COMMITMENT_DAYS),
+        StreakError::InvalidCommitment
     );
 
     let player = &mut ctx.accounts.player;
@@ -55,6 +92,11 @@ pub fn handler(ctx: Context<Stake>, amount: u64, referrer: Option<Pubkey>) -> Re
     // Check if this is a re-stake (player died and is starting over)
     let is_restake = player.wallet != Pubkey::default() && !player.is_active;
 
+    // A re-stake gives up any pending revive, but rewards have to be claimed first
+    if is_restake {
+        require!(!player.has_unclaimed_rewards(), StreakError::OutstandingClaims);
+    }
+
     // Validate referrer if provided (only for new players, not re-stakes)
     if !is_restake {
         if let Some(ref_pubkey) = referrer {
@@ -64,7 +106,7 @@ pub fn handler(ctx: Context<Stake>, amount: u64, referrer: Option<Pubkey>) -> Re
             if let Some(ref referrer_acc_info) = ctx.accounts.referrer_player {
                 // Verify it's the correct PDA
                 let (expected_pda, _bump) = Pubkey::find_program_address(
-                    &[PLAYER_SEED, ref_pubkey.as_ref()],
+                    &[PLAYER_SEED, ctx.accounts.game_state.game_seed().as_ref(), ref_pubkey.as_ref()],
                     ctx.program_id
                 );
                 require!(referrer_acc_info.key() == expected_pda, StreakError::InvalidReferrer);
@@ -92,52 +134,38 @@ pub fn handler(ctx: Context<Stake>, amount: u64, referrer: Option<Pubkey>) -> Re
     let game_state = &ctx.accounts.game_state;
     let interval = game_state.checkin_interval_seconds;
 
-    // Calculate current period based on configurable interval
-    let current_period = current_time / interval;
-
-    // Check for grace period (last 5 minutes of period)
-    let seconds_into_period = current_time % interval;
-    let seconds_until_next_period = interval - seconds_into_period;
-    let start_day = if seconds_until_next_period <= GRACE_PERIOD_SECONDS {
-        // Grace period: start next period (store as timestamp of period start)
-        (current_period + 1) * interval
-    } else {
-        // Normal: start this period (store as timestamp of period start)
-        current_period * interval
-    };
-
-    // Transfer SOL from user to game state PDA
-    let cpi_context = CpiContext::new(
-        ctx.accounts.system_program.to_account_info(),
-        system_program::Transfer {
-            from: ctx.accounts.user.to_account_info(),
-            to: ctx.accounts.game_state.to_account_info(),
-        },
+    // Start this period, or the next one inside the grace period
+    let start_day = Player::start_day_for(current_time, interval, deadline_offset);
+
+    // Transfer the stake (SOL or the game's token) from user to the vault
+    let vault = Vault::load(
+        &ctx.accounts.game_state,
+        ctx.accounts.stake_vault.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    )?;
+    vault.deposit(
+        &ctx.accounts.game_state.to_account_info(),
+        &ctx.accounts.user.to_account_info(),
+        ctx.accounts.user_token_account.as_ref(),
+        &ctx.accounts.system_program.to_account_info(),
+        amount,
+    )?;
+
+    // Initialize/reset player account (lifetime stats survive re-stakes)
+    if !is_restake {
+        player.referrer = referrer;
+    }
+    player.begin_stake(
+        ctx.accounts.user.key(),
+        ctx.accounts.game_state.game_id,
+        amount,
+        current_time,
+        start_day,
+        deadline_offset,
+        commitment_days,
     );
-    system_program::transfer(cpi_context, amount)?;
-
-    // Preserve some stats for re-stakers
-    let prev_referral_earnings = if is_restake { player.referral_earnings } else { 0 };
-    let prev_direct_referrals = if is_restake { player.direct_referrals } else { 0 };
-    let prev_lifelines = if is_restake { player.lifelines } else { 0 };
-    let prev_referrer = if is_restake { player.referrer } else { referrer };
-
-    // Initialize/reset player account
-    player.wallet = ctx.accounts.user.key();
-    player.stake = amount;
-    player.streak_days = 1;
-    player.last_checkin = current_time;
-    player.start_day = start_day;
-    player.pending_rewards = 0;
-    player.is_active = true;
-    player.referrer = prev_referrer;
-    player.direct_referrals = prev_direct_referrals;
-    player.lifelines = prev_lifelines;
-    player.lifelines_used = 0;
-    player.last_bonus_claimed = 0;
-    player.total_bonus_claims = 0;
-    player.referral_earnings = prev_referral_earnings;
     player.bump = ctx.bumps.player;
+    League::place(&ctx.accounts.game_state, ctx.accounts.league.as_mut(), player)?;
 
     // Update referrer's direct_referrals count and lifelines (only for new players)
     // Since referrer_player is an UncheckedAccount, we need to manually update it
@@ -187,6 +215,10 @@ pub fn handler(ctx: Context<Stake>, amount: u64, referrer: Option<Pubkey>) -> Re
         msg!("Player staked {} lamports", amount);
     }
     msg!("Start day: {}", start_day);
+    msg!("Deadline offset: {} seconds", deadline_offset);
+    if commitment_days > 0 {
+        msg!("Committed to a {} day streak", commitment_days);
+    }
 
     Ok(())
 }
@@ -7,7 +7,7 @@ use crate::errors::StreakError;
 pub struct StartBonusWindow<'info> {
     #[account(
         mut,
-        seeds = [GAME_STATE_SEED],
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
         bump = game_state.bump,
         constraint = game_state.authority == authority.key() @ StreakError::Unauthorized
     )]
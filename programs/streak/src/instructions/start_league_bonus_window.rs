@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameState, League};
+use crate::constants::{GAME_STATE_SEED, LEAGUE_SEED, BONUS_DURATION_SECONDS};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct StartLeagueBonusWindow<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump,
+        has_one = authority @ StreakError::Unauthorized
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        mut,
+        seeds = [LEAGUE_SEED, game_state.game_seed().as_ref(), &[league.league_id]],
+        bump = league.bump
+    )]
+    pub league: Account<'info, League>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<StartLeagueBonusWindow>, window_id: u64) -> Result<()> {
+    let league = &mut ctx.accounts.league;
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+
+    // Window IDs must move forward so earlier claims don't block this one
+    require!(window_id > league.current_bonus_window, StreakError::InvalidLeague);
+
+    league.current_bonus_window = window_id;
+    league.bonus_window_end = current_time + BONUS_DURATION_SECONDS;
+
+    msg!("League {} bonus window {} started!", league.league_id, window_id);
+    msg!("Ends at: {}", league.bonus_window_end);
+
+    Ok(())
+}
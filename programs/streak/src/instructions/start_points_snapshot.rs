@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+use crate::state::GameState;
+use crate::constants::GAME_STATE_SEED;
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct StartPointsSnapshot<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump,
+        has_one = authority @ StreakError::Unauthorized
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<StartPointsSnapshot>) -> Result<()> {
+    let game_state = &mut ctx.accounts.game_state;
+
+    game_state.points_snapshot_id = game_state.points_snapshot_id
+        .checked_add(1)
+        .ok_or(StreakError::Overflow)?;
+
+    msg!("Points snapshot {} started", game_state.points_snapshot_id);
+
+    Ok(())
+}
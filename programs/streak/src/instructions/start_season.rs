@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameState, Season};
+use crate::constants::{GAME_STATE_SEED, SEASON_SEED, BPS_DENOMINATOR};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct StartSeason<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump,
+        has_one = authority @ StreakError::Unauthorized
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Season::SIZE,
+        seeds = [SEASON_SEED, game_state.game_seed().as_ref(), &(game_state.current_season_id + 1).to_le_bytes()],
+        bump
+    )]
+    pub season: Account<'info, Season>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<StartSeason>,
+    start_period: i64,
+    end_period: i64,
+    entry_deadline_period: i64,
+    min_stake: u64,
+    payout_bps: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let game_state = &mut ctx.accounts.game_state;
+    let current_period = game_state.period_at(clock.unix_timestamp);
+
+    // Seasons renew themselves through finalize_season once the first one is started
+    require!(game_state.current_season_id == 0, StreakError::InvalidSeason);
+
+    require!(
+        end_period > start_period && end_period > current_period,
+        StreakError::InvalidSeason
+    );
+    require!(
+        entry_deadline_period > current_period && entry_deadline_period <= end_period,
+        StreakError::InvalidSeason
+    );
+    require!(payout_bps <= BPS_DENOMINATOR, StreakError::InvalidBps);
+
+    game_state.current_season_id = 1;
+
+    let season = &mut ctx.accounts.season;
+    season.game_id = game_state.game_id;
+    season.season_id = game_state.current_season_id;
+    season.start_period = start_period;
+    season.end_period = end_period;
+    season.entry_deadline_period = entry_deadline_period;
+    season.min_stake = min_stake;
+    season.payout_bps = payout_bps;
+    season.bump = ctx.bumps.season;
+
+    msg!("Season {} runs from period {} to {}", season.season_id, start_period, end_period);
+    msg!("Entry closes at period {}, minimum stake {} lamports", entry_deadline_period, min_stake);
+    msg!("Survivors share {} bps of the season's death pool", payout_bps);
+
+    Ok(())
+}
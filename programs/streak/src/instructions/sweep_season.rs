@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameState, Season};
+use crate::constants::{GAME_STATE_SEED, SEASON_SEED};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct SweepSeason<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        mut,
+        seeds = [SEASON_SEED, game_state.game_seed().as_ref(), &season.season_id.to_le_bytes()],
+        bump = season.bump
+    )]
+    pub season: Account<'info, Season>,
+}
+
+pub fn handler(ctx: Context<SweepSeason>) -> Result<()> {
+    let clock = Clock::get()?;
+    let game_state = &mut ctx.accounts.game_state;
+    let season = &mut ctx.accounts.season;
+    let current_period = game_state.period_at(clock.unix_timestamp);
+
+    require!(season.finalized, StreakError::SeasonNotEnded);
+    require!(!season.swept, StreakError::SeasonClaimsClosed);
+    require!(current_period >= season.claims_close_period(), StreakError::SeasonClaimsOpen);
+
+    // Shares nobody claimed (dead or absent survivors, rounding) go back to the pool
+    let unclaimed = season.payout_pool.saturating_sub(season.total_claimed);
+    season.swept = true;
+    game_state.total_pool = game_state.total_pool.checked_add(unclaimed).ok_or(StreakError::Overflow)?;
+
+    msg!("Season {} swept: {} lamports returned to the pool", season.season_id, unclaimed);
+
+    Ok(())
+}
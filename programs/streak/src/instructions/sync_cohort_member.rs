@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use crate::state::{Cohort, CohortMember, Player};
+use crate::constants::{PLAYER_SEED, COHORT_SEED, COHORT_MEMBER_SEED};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct SyncCohortMember<'info> {
+    #[account(
+        mut,
+        seeds = [COHORT_SEED, cohort.admin.as_ref(), &cohort.cohort_id.to_le_bytes()],
+        bump = cohort.bump
+    )]
+    pub cohort: Account<'info, Cohort>,
+
+    #[account(
+        mut,
+        seeds = [COHORT_MEMBER_SEED, cohort.key().as_ref(), cohort_member.wallet.as_ref()],
+        bump = cohort_member.bump
+    )]
+    pub cohort_member: Account<'info, CohortMember>,
+
+    #[account(
+        seeds = [PLAYER_SEED, player.game_seed().as_ref(), cohort_member.wallet.as_ref()],
+        bump = player.bump,
+        constraint = player.game_id == cohort.game_id @ StreakError::WrongGame
+    )]
+    pub player: Account<'info, Player>,
+}
+
+pub fn handler(ctx: Context<SyncCohortMember>) -> Result<()> {
+    let cohort_key = ctx.accounts.cohort.key();
+    let player = &ctx.accounts.player;
+    let cohort_member = &mut ctx.accounts.cohort_member;
+    let cohort = &mut ctx.accounts.cohort;
+
+    if !cohort_member.is_active {
+        msg!("Member {} already settled", cohort_member.wallet);
+        return Ok(());
+    }
+
+    // The funded stake is the one started at claim time with the cohort as sponsor
+    let still_running = player.is_active
+        && player.sponsor == Some(cohort_key)
+        && player.start_day == cohort_member.start_day;
+
+    if still_running {
+        // Replace the member's last counted growth with the current figure
+        let growth = player.harvestable_growth();
+        cohort.total_growth = cohort.total_growth
+            .checked_sub(cohort_member.last_growth)
+            .ok_or(StreakError::Overflow)?
+            .checked_add(growth)
+            .ok_or(StreakError::Overflow)?;
+        cohort_member.last_growth = growth;
+
+        msg!("Member {} active, growth: {} lamports", cohort_member.wallet, growth);
+        return Ok(());
+    }
+
+    // Funded stake ended - a death record since the claim means it was lost
+    let died = player.stake_at_death > 0 && player.died_at >= cohort_member.claimed_at;
+
+    cohort_member.is_active = false;
+    cohort.active_members = cohort.active_members.checked_sub(1).ok_or(StreakError::Overflow)?;
+
+    if died {
+        // Growth died with the stake
+        cohort.total_growth = cohort.total_growth
+            .checked_sub(cohort_member.last_growth)
+            .ok_or(StreakError::Overflow)?;
+        cohort_member.last_growth = 0;
+        cohort.deaths = cohort.deaths.checked_add(1).ok_or(StreakError::Overflow)?;
+
+        msg!("Member {} died", cohort_member.wallet);
+    } else {
+        msg!("Member {} left the cohort stake", cohort_member.wallet);
+    }
+    msg!("Active members: {}, deaths: {}", cohort.active_members, cohort.deaths);
+
+    Ok(())
+}
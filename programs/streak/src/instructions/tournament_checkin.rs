@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameState, Tournament, TournamentEntry};
+use crate::constants::{GAME_STATE_SEED, TOURNAMENT_SEED, TOURNAMENT_ENTRY_SEED};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct TournamentCheckin<'info> {
+    #[account(
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
+        bump = game_state.bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        seeds = [TOURNAMENT_SEED, game_state.game_seed().as_ref(), &tournament.tournament_id.to_le_bytes()],
+        bump = tournament.bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(
+        mut,
+        seeds = [TOURNAMENT_ENTRY_SEED, tournament.key().as_ref(), user.key().as_ref()],
+        bump = entry.bump
+    )]
+    pub entry: Account<'info, TournamentEntry>,
+
+    pub user: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<TournamentCheckin>) -> Result<()> {
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+    let game_state = &ctx.accounts.game_state;
+    let entry = &mut ctx.accounts.entry;
+    let current_period = game_state.period_at(current_time);
+
+    require!(!ctx.accounts.tournament.finished, StreakError::TournamentFinished);
+    require!(entry.is_alive, StreakError::PlayerDead);
+
+    // No late window or lifelines in a tournament - a missed period is fatal
+    require!(
+        !entry.has_missed_checkin(current_period, game_state),
+        StreakError::PlayerDead
+    );
+    require!(
+        game_state.period_at(entry.last_checkin) < current_period,
+        StreakError::AlreadyCheckedIn
+    );
+
+    entry.last_checkin = current_time;
+    entry.streak_days = entry.streak_days.checked_add(1).ok_or(StreakError::Overflow)?;
+
+    msg!("Tournament check-in! Streak: {}", entry.streak_days);
+
+    Ok(())
+}
@@ -1,20 +1,42 @@
 use anchor_lang::prelude::*;
-use crate::state::{GameState, Player};
-use crate::constants::{GAME_STATE_SEED, PLAYER_SEED};
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::{GameState, Player, WithdrawQueue, League, Season};
+use crate::constants::{
+    GAME_STATE_SEED, PLAYER_SEED, WITHDRAW_QUEUE_SEED, STAKE_VAULT_SEED, BPS_DENOMINATOR,
+    EARLY_EXIT_PENALTY_BPS,
+};
 use crate::errors::StreakError;
+use crate::events::ExitFeeCharged;
+use crate::vault::Vault;
 
 #[derive(Accounts)]
 pub struct Withdraw<'info> {
     #[account(
         mut,
-        seeds = [GAME_STATE_SEED],
+        seeds = [GAME_STATE_SEED, game_state.game_seed().as_ref()],
         bump = game_state.bump
     )]
     pub game_state: Account<'info, GameState>,
 
+    /// CHECK: Treasury receives its share of exit fees
     #[account(
         mut,
-        seeds = [PLAYER_SEED, user.key().as_ref()],
+        constraint = treasury.key() == game_state.treasury
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// Required while the queue is owed anything; without it (or once it is full)
+    /// the user's unpaid amount stays claimable as pending rewards
+    #[account(
+        mut,
+        seeds = [WITHDRAW_QUEUE_SEED, game_state.game_seed().as_ref()],
+        bump = withdraw_queue.bump
+    )]
+    pub withdraw_queue: Option<Account<'info, WithdrawQueue>>,
+
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, game_state.game_seed().as_ref(), user.key().as_ref()],
         bump = player.bump,
         constraint = player.wallet == user.key()
     )]
@@ -23,19 +45,104 @@ pub struct Withdraw<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
 
+    /// Sponsor of a sponsored stake (required when the stake returns to them)
+    /// CHECK: Validated against player.sponsor in handler
+    #[account(mut)]
+    pub sponsor: Option<UncheckedAccount<'info>>,
+
     pub system_program: Program<'info, System>,
+
+    /// Token-staked games only: the game's stake vault
+    #[account(
+        mut,
+        seeds = [STAKE_VAULT_SEED, game_state.game_seed().as_ref()],
+        bump
+    )]
+    pub stake_vault: Option<Account<'info, TokenAccount>>,
+
+    /// Token-staked games only: the user's token account for the stake mint
+    #[account(mut)]
+    pub user_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Token-staked games only: the treasury's token account for the stake mint
+    #[account(mut)]
+    pub treasury_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Token-staked games only: the sponsor's token account (when the stake returns to them)
+    #[account(mut)]
+    pub sponsor_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    #[account(
+        mut,
+        constraint = league.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub league: Option<Account<'info, League>>,
+
+    /// Game's current season (required once the game has seasons)
+    #[account(
+        mut,
+        constraint = season.game_id == game_state.game_id @ StreakError::WrongGame
+    )]
+    pub season: Option<Account<'info, Season>>,
 }
 
-pub fn handler(ctx: Context<Withdraw>) -> Result<()> {
+pub fn handler(ctx: Context<Withdraw>) -> Result<u64> {
     let player = &mut ctx.accounts.player;
+    let game_state = &ctx.accounts.game_state;
 
     // Validate player is active
     require!(player.is_active, StreakError::NotStaked);
+    require!(
+        ctx.accounts.withdraw_queue.is_some() || game_state.withdraw_queue_owed == 0,
+        StreakError::WithdrawQueuePending
+    );
+    require!(player.open_duels == 0, StreakError::OpenDuels);
+    require!(player.squad.is_none(), StreakError::InSquad);
+    League::check_member(ctx.accounts.league.as_ref(), player)?;
+    Season::check_current(ctx.accounts.season.as_ref(), game_state)?;
 
-    // Calculate total to withdraw (stake + pending rewards)
-    let total_withdraw = player.stake
-        .checked_add(player.pending_rewards)
+    // Exit fee on the stake, decaying with streak length
+    let fee = player.stake
+        .checked_mul(game_state.exit_fee_bps(player.streak_days))
+        .ok_or(StreakError::Overflow)?
+        .checked_div(BPS_DENOMINATOR)
         .ok_or(StreakError::Overflow)?;
+    let treasury_share = fee
+        .checked_mul(game_state.exit_fee_treasury_bps)
+        .ok_or(StreakError::Overflow)?
+        .checked_div(BPS_DENOMINATOR)
+        .ok_or(StreakError::Overflow)?;
+
+    // Leaving before an unfinished commitment target costs an extra penalty (kept by the pool)
+    let early_exit_penalty = if player.is_commitment_locked() {
+        player.stake
+            .checked_mul(EARLY_EXIT_PENALTY_BPS)
+            .ok_or(StreakError::Overflow)?
+            .checked_div(BPS_DENOMINATOR)
+            .ok_or(StreakError::Overflow)?
+    } else {
+        0
+    };
+
+    // Stake payout after exit fee and penalty; pending rewards always go to the player
+    let stake_payout = player.stake
+        .checked_sub(fee)
+        .ok_or(StreakError::Overflow)?
+        .checked_sub(early_exit_penalty)
+        .ok_or(StreakError::Overflow)?;
+    let rewards = player.pending_rewards;
+
+    // Sponsored stakes can be set up to return the stake to the sponsor
+    let to_sponsor = player.withdraw_to_sponsor;
+    if to_sponsor {
+        let sponsor_key = ctx.accounts.sponsor.as_ref().map(|s| s.key());
+        require!(
+            sponsor_key.is_some() && sponsor_key == player.sponsor,
+            StreakError::InvalidSponsor
+        );
+    }
 
     // Store values before modifying player
     let player_stake = player.stake;
@@ -47,27 +154,137 @@ pub fn handler(ctx: Context<Withdraw>) -> Result<()> {
     player.streak_days = 0;
     player.pending_rewards = 0;
 
-    // Transfer SOL from game state PDA to user
+    // Pay what the vault can cover after anything already queued;
+    // the remainder joins the back of the withdrawal queue
     let game_state_info = ctx.accounts.game_state.to_account_info();
     let user_info = ctx.accounts.user.to_account_info();
+    let vault = Vault::load(
+        &ctx.accounts.game_state,
+        ctx.accounts.stake_vault.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    )?;
+    let mut withdraw_queue = ctx.accounts.withdraw_queue.as_mut();
 
-    **game_state_info.try_borrow_mut_lamports()? = game_state_info
-        .lamports()
-        .checked_sub(total_withdraw)
-        .ok_or(StreakError::InsufficientFunds)?;
+    let mut available = vault.available(&game_state_info)?
+        .saturating_sub(ctx.accounts.game_state.withdraw_queue_owed);
+    let mut kept_pending: u64 = 0;
 
-    **user_info.try_borrow_mut_lamports()? = user_info
-        .lamports()
-        .checked_add(total_withdraw)
+    let user_recipient = vault.recipient(&user_info, ctx.accounts.user_token_account.as_ref())?;
+    let payouts = match ctx.accounts.sponsor.as_ref() {
+        Some(sponsor) if to_sponsor => vec![
+            (
+                vault.recipient(&sponsor.to_account_info(), ctx.accounts.sponsor_token_account.as_ref())?,
+                sponsor.key(),
+                stake_payout,
+            ),
+            (user_recipient, user_info.key(), rewards),
+        ],
+        _ => vec![(
+            user_recipient,
+            user_info.key(),
+            stake_payout.checked_add(rewards).ok_or(StreakError::Overflow)?,
+        )],
+    };
+
+    let mut paid: u64 = 0;
+    let mut queued: u64 = 0;
+    for (recipient, wallet, amount) in payouts.iter() {
+        let recipient_paid = (*amount).min(available);
+        let recipient_queued = amount - recipient_paid;
+        available -= recipient_paid;
+
+        if recipient_queued > 0 {
+            match withdraw_queue.as_deref_mut() {
+                Some(queue) if queue.has_room() => queue.enqueue(*wallet, recipient_queued)?,
+                // The user can claim their share directly once the vault refills;
+                // a sponsor has no player account to keep it on
+                _ if *wallet == user_info.key() => {
+                    kept_pending = kept_pending.checked_add(recipient_queued).ok_or(StreakError::Overflow)?;
+                }
+                Some(_) => return Err(StreakError::WithdrawQueueFull.into()),
+                None => return Err(StreakError::WithdrawQueuePending.into()),
+            }
+        }
+
+        vault.pay_out(&game_state_info, recipient, *wallet, recipient_paid)?;
+
+        paid = paid.checked_add(recipient_paid).ok_or(StreakError::Overflow)?;
+        queued = queued.checked_add(recipient_queued).ok_or(StreakError::Overflow)?;
+    }
+
+    // Treasury is paid last; any share the vault can't cover stays in the pool
+    let treasury_share = treasury_share.min(available);
+    let pool_share = fee
+        .checked_sub(treasury_share)
+        .ok_or(StreakError::Overflow)?
+        .checked_add(early_exit_penalty)
         .ok_or(StreakError::Overflow)?;
 
-    // Update game state
+    let treasury_recipient = vault.recipient(
+        &ctx.accounts.treasury.to_account_info(),
+        ctx.accounts.treasury_token_account.as_ref(),
+    )?;
+    vault.pay_out(
+        &game_state_info,
+        &treasury_recipient,
+        ctx.accounts.treasury.key(),
+        treasury_share,
+    )?;
+
+    // Leaving before the season ends drops the player from its survivors
+    if let Some(season) = ctx.accounts.season.as_mut() {
+        let clock = Clock::get()?;
+        let game_period = ctx.accounts.game_state.period_at(clock.unix_timestamp);
+        season.forfeit(&mut ctx.accounts.player, game_period)?;
+    }
+
+    // Leave the league, which keeps its share of the exit fee and any penalty
+    if let Some(league) = ctx.accounts.league.as_mut() {
+        league.leave(ctx.accounts.user.key(), player_stake.checked_sub(pool_share).ok_or(StreakError::Overflow)?)?;
+        ctx.accounts.player.league_id = 0;
+    }
+
+    // Update game state (pool keeps its share of the exit fee and any penalty)
+    if kept_pending > 0 {
+        ctx.accounts.player.pending_rewards = kept_pending;
+    }
+
     let game_state = &mut ctx.accounts.game_state;
     game_state.total_players = game_state.total_players.checked_sub(1).ok_or(StreakError::Overflow)?;
-    game_state.total_pool = game_state.total_pool.checked_sub(player_stake).ok_or(StreakError::Overflow)?;
+    game_state.total_pool = game_state.total_pool
+        .checked_sub(player_stake)
+        .ok_or(StreakError::Overflow)?
+        .checked_add(pool_share)
+        .ok_or(StreakError::Overflow)?;
+    if let Some(withdraw_queue) = ctx.accounts.withdraw_queue.as_ref() {
+        game_state.withdraw_queue_owed = withdraw_queue.total_owed;
+    }
+
+    emit!(ExitFeeCharged {
+        player: ctx.accounts.user.key(),
+        streak_days: streak_achieved,
+        amount_withdrawn: player_stake,
+        fee,
+        treasury_share,
+        pool_share,
+        early_exit_penalty,
+    });
 
-    msg!("Withdrew {} lamports", total_withdraw);
+    msg!("Withdrew {} lamports", paid);
+    if to_sponsor {
+        msg!("Stake of {} lamports returned to the sponsor", stake_payout);
+    }
+    if queued > kept_pending {
+        msg!("Vault short - {} lamports added to the withdrawal queue", queued - kept_pending);
+    }
+    if kept_pending > 0 {
+        msg!("Vault short - {} lamports kept as pending rewards", kept_pending);
+    }
+    msg!("Exit fee: {} lamports ({} to treasury, {} to pool)", fee, treasury_share, pool_share);
+    if early_exit_penalty > 0 {
+        msg!("Early exit penalty: {} lamports", early_exit_penalty);
+    }
     msg!("Final streak: {} days", streak_achieved);
 
-    Ok(())
+    Ok(fee)
 }
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::Cohort;
+use crate::constants::{COHORT_SEED, COHORT_VAULT_SEED};
+use crate::errors::StreakError;
+
+#[derive(Accounts)]
+pub struct WithdrawCohortBudget<'info> {
+    #[account(
+        mut,
+        seeds = [COHORT_SEED, admin.key().as_ref(), &cohort.cohort_id.to_le_bytes()],
+        bump = cohort.bump,
+        has_one = admin @ StreakError::Unauthorized
+    )]
+    pub cohort: Account<'info, Cohort>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Token-staked games only: the cohort's token budget
+    #[account(
+        mut,
+        seeds = [COHORT_VAULT_SEED, cohort.key().as_ref()],
+        bump
+    )]
+    pub cohort_vault: Option<Account<'info, TokenAccount>>,
+
+    /// Token-staked games only: the admin's token account for the stake mint
+    #[account(mut)]
+    pub admin_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+pub fn handler(ctx: Context<WithdrawCohortBudget>, amount: u64) -> Result<()> {
+    require!(amount > 0, StreakError::InvalidAmount);
+
+    let cohort_info = ctx.accounts.cohort.to_account_info();
+    let recipient = match ctx.accounts.cohort_vault.as_ref() {
+        Some(_) => ctx.accounts.admin_token_account
+            .as_ref()
+            .ok_or(StreakError::MissingTokenAccounts)?
+            .to_account_info(),
+        None => ctx.accounts.admin.to_account_info(),
+    };
+
+    // Unclaimed budget (including returned stakes and death refunds) goes back to the admin
+    require!(
+        Cohort::budget(&cohort_info, ctx.accounts.cohort_vault.as_ref())? >= amount,
+        StreakError::InsufficientFunds
+    );
+
+    Cohort::pay_out(
+        &ctx.accounts.cohort,
+        ctx.accounts.cohort_vault.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        &recipient,
+        amount,
+    )?;
+
+    msg!("Withdrew {} lamports from cohort {}", amount, ctx.accounts.cohort.cohort_id);
+
+    Ok(())
+}
@@ -2,10 +2,14 @@ use anchor_lang::prelude::*;
 
 pub mod constants;
 pub mod errors;
+pub mod events;
 pub mod instructions;
+pub mod randomness;
 pub mod state;
+pub mod vault;
 
 use instructions::*;
+use state::{GrowthTier, BoxReward};
 
 declare_id!("Eyz3yhxzGKemxF7JYT3Q9LCVCKLkim6unnzH4cMprkxW");
 
@@ -13,14 +17,85 @@ declare_id!("Eyz3yhxzGKemxF7JYT3Q9LCVCKLkim6unnzH4cMprkxW");
 pub mod streak {
     use super::*;
 
-    /// Initialize the game state (one-time setup)
+    /// Initialize the game state (one-time setup; pass a stake mint to stake an SPL token)
     pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
         instructions::initialize::handler(ctx)
     }
 
+    /// Create the withdrawal queue (one-time setup, authority only)
+    pub fn initialize_withdraw_queue(ctx: Context<InitializeWithdrawQueue>) -> Result<()> {
+        instructions::initialize_withdraw_queue::handler(ctx)
+    }
+
     /// Stake SOL to enter the game
-    pub fn stake(ctx: Context<Stake>, amount: u64, referrer: Option<Pubkey>) -> Result<()> {
-        instructions::stake::handler(ctx, amount, referrer)
+    pub fn stake(
+        ctx: Context<Stake>,
+        amount: u64,
+        referrer: Option<Pubkey>,
+        deadline_offset: i64,
+        commitment_days: u32,
+    ) -> Result<()> {
+        instructions::stake::handler(ctx, amount, referrer, deadline_offset, commitment_days)
+    }
+
+    /// Stake SOL on behalf of another wallet (sponsor pays, beneficiary plays)
+    pub fn sponsor_stake(
+        ctx: Context<SponsorStake>,
+        amount: u64,
+        deadline_offset: i64,
+        withdraw_to_sponsor: bool,
+        death_refund_bps: u64,
+    ) -> Result<()> {
+        instructions::sponsor_stake::handler(ctx, amount, deadline_offset, withdraw_to_sponsor, death_refund_bps)
+    }
+
+    /// Create a funded cohort whose members can claim sponsored stakes
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_cohort(
+        ctx: Context<CreateCohort>,
+        cohort_id: u64,
+        merkle_root: [u8; 32],
+        stake_per_member: u64,
+        deadline_offset: i64,
+        withdraw_to_sponsor: bool,
+        death_refund_bps: u64,
+        budget: u64,
+    ) -> Result<()> {
+        instructions::create_cohort::handler(
+            ctx,
+            cohort_id,
+            merkle_root,
+            stake_per_member,
+            deadline_offset,
+            withdraw_to_sponsor,
+            death_refund_bps,
+            budget,
+        )
+    }
+
+    /// Add SOL to a cohort's budget
+    pub fn fund_cohort(ctx: Context<FundCohort>, amount: u64) -> Result<()> {
+        instructions::fund_cohort::handler(ctx, amount)
+    }
+
+    /// Claim a cohort-funded stake (members only, once per cohort)
+    pub fn claim_cohort_stake(ctx: Context<ClaimCohortStake>, proof: Vec<[u8; 32]>) -> Result<()> {
+        instructions::claim_cohort_stake::handler(ctx, proof)
+    }
+
+    /// Refresh a member's contribution to the cohort stats (anyone can call)
+    pub fn sync_cohort_member(ctx: Context<SyncCohortMember>) -> Result<()> {
+        instructions::sync_cohort_member::handler(ctx)
+    }
+
+    /// Take unused budget back out of a cohort (cohort admin only)
+    pub fn withdraw_cohort_budget(ctx: Context<WithdrawCohortBudget>, amount: u64) -> Result<()> {
+        instructions::withdraw_cohort_budget::handler(ctx, amount)
+    }
+
+    /// Add SOL to an active stake without resetting the streak
+    pub fn add_stake(ctx: Context<AddStake>, amount: u64) -> Result<()> {
+        instructions::add_stake::handler(ctx, amount)
     }
 
     /// Daily check-in to keep streak alive
@@ -33,21 +108,63 @@ pub mod streak {
         instructions::claim_bonus::handler(ctx)
     }
 
+    /// Move growth above principal into pending rewards, protecting it from death
+    pub fn harvest(ctx: Context<Harvest>) -> Result<()> {
+        instructions::harvest::handler(ctx)
+    }
+
     /// Process a player's death (permissionless crank)
-    pub fn process_death(ctx: Context<ProcessDeath>) -> Result<()> {
+    pub fn process_death<'info>(ctx: Context<'_, '_, 'info, 'info, ProcessDeath<'info>>) -> Result<()> {
         instructions::process_death::handler(ctx)
     }
 
-    /// Withdraw stake and exit the game
-    pub fn withdraw(ctx: Context<Withdraw>) -> Result<()> {
+    /// Freeze the streak for upcoming periods (vacation mode)
+    pub fn schedule_freeze(
+        ctx: Context<ScheduleFreeze>,
+        start_period: i64,
+        n_periods: u32,
+        use_lifeline: bool,
+    ) -> Result<()> {
+        instructions::schedule_freeze::handler(ctx, start_period, n_periods, use_lifeline)
+    }
+
+    /// Revive a dead player within the recovery window
+    pub fn revive(ctx: Context<Revive>) -> Result<()> {
+        instructions::revive::handler(ctx)
+    }
+
+    /// Withdraw stake and exit the game (returns the exit fee charged)
+    pub fn withdraw(ctx: Context<Withdraw>) -> Result<u64> {
         instructions::withdraw::handler(ctx)
     }
 
+    /// Withdraw part of an active stake (must leave at least MIN_STAKE, returns the exit fee charged)
+    pub fn partial_withdraw(ctx: Context<PartialWithdraw>, amount: u64) -> Result<u64> {
+        instructions::partial_withdraw::handler(ctx, amount)
+    }
+
     /// Claim pending rewards from referral deaths
     pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
         instructions::claim_rewards::handler(ctx)
     }
 
+    /// Pay down the withdrawal queue from the vault (permissionless crank)
+    pub fn process_withdraw_queue<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ProcessWithdrawQueue<'info>>,
+    ) -> Result<()> {
+        instructions::process_withdraw_queue::handler(ctx)
+    }
+
+    /// Move pending rewards into an active stake
+    pub fn compound_rewards(ctx: Context<CompoundRewards>) -> Result<()> {
+        instructions::compound_rewards::handler(ctx)
+    }
+
+    /// Opt in or out of compounding referral rewards automatically
+    pub fn set_auto_compound(ctx: Context<SetAutoCompound>, enabled: bool) -> Result<()> {
+        instructions::set_auto_compound::handler(ctx, enabled)
+    }
+
     /// Start a new bonus window (authority only)
     pub fn start_bonus_window(ctx: Context<StartBonusWindow>, window_id: u64) -> Result<()> {
         instructions::start_bonus_window::handler(ctx, window_id)
@@ -57,4 +174,305 @@ pub mod streak {
     pub fn set_checkin_interval(ctx: Context<SetCheckinInterval>, interval_seconds: i64) -> Result<()> {
         instructions::set_checkin_interval::handler(ctx, interval_seconds)
     }
+
+    /// Configure the late check-in window and penalty (authority only)
+    pub fn set_late_checkin_config(
+        ctx: Context<SetLateCheckinConfig>,
+        window_seconds: i64,
+        penalty_bps: u64,
+    ) -> Result<()> {
+        instructions::set_late_checkin_config::handler(ctx, window_seconds, penalty_bps)
+    }
+
+    /// Configure the recovery window and revive pricing (authority only)
+    pub fn set_revive_config(
+        ctx: Context<SetReviveConfig>,
+        window_seconds: i64,
+        cost_bps: u64,
+        stake_bps: u64,
+    ) -> Result<()> {
+        instructions::set_revive_config::handler(ctx, window_seconds, cost_bps, stake_bps)
+    }
+
+    /// Configure the exit fee curve and treasury split (authority only)
+    pub fn set_exit_fee_config(
+        ctx: Context<SetExitFeeConfig>,
+        max_bps: u64,
+        zero_fee_streak_days: u32,
+        treasury_bps: u64,
+    ) -> Result<()> {
+        instructions::set_exit_fee_config::handler(ctx, max_bps, zero_fee_streak_days, treasury_bps)
+    }
+
+    /// Replace the streak-length growth tiers (authority only)
+    pub fn set_growth_tiers(ctx: Context<SetGrowthTiers>, tiers: Vec<GrowthTier>) -> Result<()> {
+        instructions::set_growth_tiers::handler(ctx, tiers)
+    }
+
+    /// Configure points credited per action and the campaign multiplier (authority only)
+    pub fn set_points_config(
+        ctx: Context<SetPointsConfig>,
+        per_checkin: u64,
+        per_bonus_claim: u64,
+        per_referral: u64,
+        per_milestone: u64,
+        multiplier_bps: u64,
+    ) -> Result<()> {
+        instructions::set_points_config::handler(
+            ctx,
+            per_checkin,
+            per_bonus_claim,
+            per_referral,
+            per_milestone,
+            multiplier_bps,
+        )
+    }
+
+    /// Start a new points snapshot (authority only)
+    pub fn start_points_snapshot(ctx: Context<StartPointsSnapshot>) -> Result<()> {
+        instructions::start_points_snapshot::handler(ctx)
+    }
+
+    /// Record a player's points in the current snapshot (permissionless crank)
+    pub fn snapshot_points(ctx: Context<SnapshotPoints>) -> Result<()> {
+        instructions::snapshot_points::handler(ctx)
+    }
+
+    /// Set the STREAK reward mint and emission schedule (authority only)
+    pub fn set_reward_mint(
+        ctx: Context<SetRewardMint>,
+        per_checkin: u64,
+        per_bonus_claim: u64,
+        per_milestone: u64,
+        halving_seconds: i64,
+    ) -> Result<()> {
+        instructions::set_reward_mint::handler(ctx, per_checkin, per_bonus_claim, per_milestone, halving_seconds)
+    }
+
+    /// Mint owed reward tokens to the player's associated token account
+    pub fn claim_token_rewards(ctx: Context<ClaimTokenRewards>) -> Result<()> {
+        instructions::claim_token_rewards::handler(ctx)
+    }
+
+    /// Create another game instance alongside the main game (caller becomes its authority)
+    pub fn create_arena(ctx: Context<CreateArena>, game_id: u64) -> Result<()> {
+        instructions::create_arena::handler(ctx, game_id)
+    }
+
+    /// Add the next stake-tier league to a game (authority only)
+    pub fn create_league(ctx: Context<CreateLeague>, min_stake: u64, max_stake: u64) -> Result<()> {
+        instructions::create_league::handler(ctx, min_stake, max_stake)
+    }
+
+    /// Start a bonus window for one league (authority only)
+    pub fn start_league_bonus_window(ctx: Context<StartLeagueBonusWindow>, window_id: u64) -> Result<()> {
+        instructions::start_league_bonus_window::handler(ctx, window_id)
+    }
+
+    /// Place an existing stake in the league matching it (for stakes made before the game had leagues)
+    pub fn join_league(ctx: Context<JoinLeague>) -> Result<()> {
+        instructions::join_league::handler(ctx)
+    }
+
+    /// Start the first season of a game (authority only; later seasons open on finalize)
+    pub fn start_season(
+        ctx: Context<StartSeason>,
+        start_period: i64,
+        end_period: i64,
+        entry_deadline_period: i64,
+        min_stake: u64,
+        payout_bps: u64,
+    ) -> Result<()> {
+        instructions::start_season::handler(ctx, start_period, end_period, entry_deadline_period, min_stake, payout_bps)
+    }
+
+    /// Enter the current season
+    pub fn join_season(ctx: Context<JoinSeason>) -> Result<()> {
+        instructions::join_season::handler(ctx)
+    }
+
+    /// Close an ended season, set aside survivor payouts and open the next season (permissionless crank)
+    pub fn finalize_season(ctx: Context<FinalizeSeason>) -> Result<()> {
+        instructions::finalize_season::handler(ctx)
+    }
+
+    /// Claim a survivor's share of a finalized season's death pool into pending rewards
+    pub fn claim_season_reward(ctx: Context<ClaimSeasonReward>) -> Result<()> {
+        instructions::claim_season_reward::handler(ctx)
+    }
+
+    /// Return a season's unclaimed payouts to the pool once its claim window closes (permissionless crank)
+    pub fn sweep_season(ctx: Context<SweepSeason>) -> Result<()> {
+        instructions::sweep_season::handler(ctx)
+    }
+
+    /// Create a last-one-standing tournament on the game's check-in schedule (authority only)
+    pub fn create_tournament(
+        ctx: Context<CreateTournament>,
+        tournament_id: u64,
+        entry_fee: u64,
+        registration_end_period: i64,
+        max_winners: u32,
+    ) -> Result<()> {
+        instructions::create_tournament::handler(ctx, tournament_id, entry_fee, registration_end_period, max_winners)
+    }
+
+    /// Pay the entry fee and join a tournament while registration is open
+    pub fn enter_tournament(ctx: Context<EnterTournament>) -> Result<()> {
+        instructions::enter_tournament::handler(ctx)
+    }
+
+    /// Check in to stay in a tournament
+    pub fn tournament_checkin(ctx: Context<TournamentCheckin>) -> Result<()> {
+        instructions::tournament_checkin::handler(ctx)
+    }
+
+    /// Knock out a tournament entry that missed a check-in (permissionless crank)
+    pub fn eliminate_tournament_entry(ctx: Context<EliminateTournamentEntry>) -> Result<()> {
+        instructions::eliminate_tournament_entry::handler(ctx)
+    }
+
+    /// Snapshot the winners once few enough are left standing (permissionless crank)
+    pub fn finish_tournament<'info>(ctx: Context<'_, '_, 'info, 'info, FinishTournament<'info>>) -> Result<()> {
+        instructions::finish_tournament::handler(ctx)
+    }
+
+    /// Claim a winner's share of the tournament's entry fees
+    pub fn claim_tournament_prize(ctx: Context<ClaimTournamentPrize>) -> Result<()> {
+        instructions::claim_tournament_prize::handler(ctx)
+    }
+
+    /// Close a tournament once every winner has claimed, returning the rounding dust (authority only)
+    pub fn close_tournament(ctx: Context<CloseTournament>) -> Result<()> {
+        instructions::close_tournament::handler(ctx)
+    }
+
+    /// Challenge another active player to a streak duel, putting up a wager
+    pub fn create_duel(ctx: Context<CreateDuel>, duel_id: u64, wager: u64, ends_at: i64) -> Result<()> {
+        instructions::create_duel::handler(ctx, duel_id, wager, ends_at)
+    }
+
+    /// Match the challenger's wager and start the duel
+    pub fn accept_duel(ctx: Context<AcceptDuel>) -> Result<()> {
+        instructions::accept_duel::handler(ctx)
+    }
+
+    /// Withdraw a duel challenge that hasn't been accepted
+    pub fn cancel_duel(ctx: Context<CancelDuel>) -> Result<()> {
+        instructions::cancel_duel::handler(ctx)
+    }
+
+    /// Claim a duel's winnings or refund, settling it first once it has ended
+    pub fn claim_duel(ctx: Context<ClaimDuel>) -> Result<()> {
+        instructions::claim_duel::handler(ctx)
+    }
+
+    /// Set the squad growth bonus and death penalty (authority only)
+    pub fn set_squad_config(ctx: Context<SetSquadConfig>, growth_bonus_bps: u64, penalty_bps: u64) -> Result<()> {
+        instructions::set_squad_config::handler(ctx, growth_bonus_bps, penalty_bps)
+    }
+
+    /// Start a squad with the caller as leader and first member
+    pub fn create_squad(ctx: Context<CreateSquad>, squad_id: u64) -> Result<()> {
+        instructions::create_squad::handler(ctx, squad_id)
+    }
+
+    /// Join a squad with the leader's approval
+    pub fn join_squad(ctx: Context<JoinSquad>) -> Result<()> {
+        instructions::join_squad::handler(ctx)
+    }
+
+    /// Leave a squad, paying any penalties still owed
+    pub fn leave_squad(ctx: Context<LeaveSquad>) -> Result<()> {
+        instructions::leave_squad::handler(ctx)
+    }
+
+    /// Move one of the caller's lifelines into their squad's shared pool
+    pub fn contribute_squad_lifeline(ctx: Context<ContributeSquadLifeline>) -> Result<()> {
+        instructions::contribute_squad_lifeline::handler(ctx)
+    }
+
+    /// Create the game's jackpot and route a share of each death into it (authority only)
+    pub fn initialize_jackpot(ctx: Context<InitializeJackpot>, death_share_bps: u64, weight_by_streak: bool) -> Result<()> {
+        instructions::initialize_jackpot::handler(ctx, death_share_bps, weight_by_streak)
+    }
+
+    /// Update the jackpot's death share and draw weighting (authority only)
+    pub fn set_jackpot_config(ctx: Context<SetJackpotConfig>, death_share_bps: u64, weight_by_streak: bool) -> Result<()> {
+        instructions::set_jackpot_config::handler(ctx, death_share_bps, weight_by_streak)
+    }
+
+    /// Close the oldest finished period's jackpot round, then draw it once its slot has passed (permissionless crank)
+    pub fn draw_jackpot(ctx: Context<DrawJackpot>) -> Result<()> {
+        instructions::draw_jackpot::handler(ctx)
+    }
+
+    /// Credit a jackpot winner's prizes to their pending rewards (permissionless crank)
+    pub fn claim_jackpot(ctx: Context<ClaimJackpot>) -> Result<()> {
+        instructions::claim_jackpot::handler(ctx)
+    }
+
+    /// Set how often mystery boxes are granted and their weighted rewards (authority only)
+    pub fn set_mystery_box_config(ctx: Context<SetMysteryBoxConfig>, interval_days: u32, rewards: Vec<BoxReward>) -> Result<()> {
+        instructions::set_mystery_box_config::handler(ctx, interval_days, rewards)
+    }
+
+    /// Open a mystery box using randomness from a slot after it was granted
+    pub fn open_box(ctx: Context<OpenBox>) -> Result<()> {
+        instructions::open_box::handler(ctx)
+    }
+
+    /// Create a sponsored prize pool with eligibility rules, an entry deadline and an expiry
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_sponsor_pool(
+        ctx: Context<CreateSponsorPool>,
+        pool_id: u64,
+        min_streak_days: u32,
+        min_stake: u64,
+        checkin_start_period: i64,
+        checkin_end_period: i64,
+        entries_close_at: i64,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::create_sponsor_pool::handler(
+            ctx,
+            pool_id,
+            min_streak_days,
+            min_stake,
+            checkin_start_period,
+            checkin_end_period,
+            entries_close_at,
+            expires_at,
+        )
+    }
+
+    /// Add SOL to a sponsor pool (anyone can fund)
+    pub fn fund_sponsor_pool(ctx: Context<FundSponsorPool>, amount: u64) -> Result<()> {
+        instructions::fund_sponsor_pool::handler(ctx, amount)
+    }
+
+    /// Enter a sponsor pool as an eligible player before entries close
+    pub fn enter_sponsor_pool(ctx: Context<EnterSponsorPool>) -> Result<()> {
+        instructions::enter_sponsor_pool::handler(ctx)
+    }
+
+    /// Claim an equal share of a sponsor pool once entries close
+    pub fn claim_sponsor_reward(ctx: Context<ClaimSponsorReward>) -> Result<()> {
+        instructions::claim_sponsor_reward::handler(ctx)
+    }
+
+    /// Return an expired sponsor pool's remainder to the sponsor (permissionless)
+    pub fn close_sponsor_pool(ctx: Context<CloseSponsorPool>) -> Result<()> {
+        instructions::close_sponsor_pool::handler(ctx)
+    }
+
+    /// Grow the game state account to the current layout (authority only)
+    pub fn migrate_game_state(ctx: Context<MigrateGameState>) -> Result<()> {
+        instructions::migrate_game_state::handler(ctx)
+    }
+
+    /// Grow a player account to the current layout (anyone can pay)
+    pub fn migrate_player(ctx: Context<MigratePlayer>) -> Result<()> {
+        instructions::migrate_player::handler(ctx)
+    }
 }
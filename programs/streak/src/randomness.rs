@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::solana_program::sysvar::slot_hashes;
+use crate::errors::StreakError;
+
+/// Where draws get their randomness from.
+/// Live games use an entry of the SlotHashes sysvar; builds with the
+/// `test-randomness` feature also accept any other account, whose address is the seed,
+/// so tests can pick the outcome.
+pub enum Randomness {
+    SlotHashes { hash: [u8; 32] },
+    #[cfg(feature = "test-randomness")]
+    Fixed([u8; 32]),
+}
+
+impl Randomness {
+    /// Pick the source for the passed randomness account, using the most recent slot hash
+    pub fn load(randomness_info: &AccountInfo) -> Result<Self> {
+        if randomness_info.key() == slot_hashes::ID {
+            // Layout: entry count (u64), then (slot, hash) entries, most recent first
+            let data = randomness_info.try_borrow_data()?;
+            require!(data.len() >= 48, StreakError::InvalidRandomness);
+            let hash: [u8; 32] = data[16..48].try_into().unwrap();
+            return Ok(Randomness::SlotHashes { hash });
+        }
+
+        #[cfg(feature = "test-randomness")]
+        return Ok(Randomness::Fixed(randomness_info.key().to_bytes()));
+
+        #[cfg(not(feature = "test-randomness"))]
+        Err(StreakError::InvalidRandomness.into())
+    }
+
+    /// Like `load`, but from the first recorded slot at or after `slot`, so the outcome
+    /// was fixed before anyone could see it. Returns None if no recorded slot is that
+    /// late yet, or once the slot has left the SlotHashes window.
+    pub fn load_at(randomness_info: &AccountInfo, slot: u64) -> Result<Option<Self>> {
+        if randomness_info.key() == slot_hashes::ID {
+            let data = randomness_info.try_borrow_data()?;
+            require!(data.len() >= 8, StreakError::InvalidRandomness);
+            let count = u64::from_le_bytes(data[..8].try_into().unwrap()) as usize;
+            require!(data.len() >= 8 + count * 40, StreakError::InvalidRandomness);
+
+            // Entries run newest to oldest: keep the last one still at or after `slot`,
+            // and only trust it if the window reaches back to `slot` itself
+            let mut found = None;
+            for entry in data[8..8 + count * 40].chunks_exact(40) {
+                let entry_slot = u64::from_le_bytes(entry[..8].try_into().unwrap());
+                if entry_slot < slot {
+                    return Ok(found);
+                }
+                found = Some(Randomness::SlotHashes { hash: entry[8..].try_into().unwrap() });
+                if entry_slot == slot {
+                    return Ok(found);
+                }
+            }
+            return Ok(None);
+        }
+
+        #[cfg(feature = "test-randomness")]
+        return Ok(Some(Randomness::Fixed(randomness_info.key().to_bytes())));
+
+        #[cfg(not(feature = "test-randomness"))]
+        Err(StreakError::InvalidRandomness.into())
+    }
+
+    /// Random u64 for a draw, separated by `domain` so different draws don't share values
+    pub fn value(&self, domain: &[&[u8]]) -> u64 {
+        let seed = match self {
+            Randomness::SlotHashes { hash } => hash,
+            #[cfg(feature = "test-randomness")]
+            Randomness::Fixed(seed) => seed,
+        };
+
+        let mut inputs: Vec<&[u8]> = vec![seed.as_ref()];
+        inputs.extend_from_slice(domain);
+        let digest = hashv(&inputs).to_bytes();
+        u64::from_le_bytes(digest[..8].try_into().unwrap())
+    }
+}
@@ -0,0 +1,214 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::constants::COHORT_SEED;
+use crate::errors::StreakError;
+
+/// A sponsor-funded group of players (e.g. an employer's staff) sharing the same stake rules
+#[account]
+#[derive(Default)]
+pub struct Cohort {
+    /// Wallet that created and manages the cohort
+    pub admin: Pubkey,
+
+    /// Admin-chosen ID (lets one admin run several cohorts)
+    pub cohort_id: u64,
+
+    /// Merkle root of keccak(member wallet) leaves
+    pub merkle_root: [u8; 32],
+
+    /// Stake funded for each member, in lamports
+    pub stake_per_member: u64,
+
+    /// Deadline offset applied to every member's stake
+    pub deadline_offset: i64,
+
+    /// Whether withdrawn stakes come back to the cohort budget
+    pub withdraw_to_sponsor: bool,
+
+    /// Share of a member's stake refunded to the cohort on death (basis points)
+    pub death_refund_bps: u64,
+
+    /// Total lamports ever moved from the budget into member stakes
+    pub total_funded: u64,
+
+    /// Members who have claimed their stake
+    pub members_claimed: u32,
+
+    /// Members whose funded stake is still running (as of their last sync)
+    pub active_members: u32,
+
+    /// Members who died on their funded stake
+    pub deaths: u32,
+
+    /// Growth above principal across members (as of their last sync)
+    pub total_growth: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Arena members stake into (MAIN_GAME_ID for the original game)
+    pub game_id: u64,
+}
+
+impl Cohort {
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // admin
+        8 + // cohort_id
+        32 + // merkle_root
+        8 + // stake_per_member
+        8 + // deadline_offset
+        1 + // withdraw_to_sponsor
+        8 + // death_refund_bps
+        8 + // total_funded
+        4 + // members_claimed
+        4 + // active_members
+        4 + // deaths
+        8 + // total_growth
+        1 + // bump
+        8 + // game_id
+        56; // padding
+
+    /// Budget left for new claims: the cohort's token balance in token-staked games,
+    /// otherwise its lamports above rent-exempt minimum
+    pub fn budget(cohort_info: &AccountInfo, cohort_vault: Option<&Account<TokenAccount>>) -> Result<u64> {
+        match cohort_vault {
+            Some(cohort_vault) => Ok(cohort_vault.amount),
+            None => Self::available_budget(cohort_info),
+        }
+    }
+
+    /// Add budget to the cohort: tokens into its vault, or lamports onto the cohort account
+    pub fn deposit<'info>(
+        cohort_info: &AccountInfo<'info>,
+        cohort_vault: Option<&Account<'info, TokenAccount>>,
+        token_program: Option<&Program<'info, Token>>,
+        funder: &AccountInfo<'info>,
+        funder_token_account: Option<&Account<'info, TokenAccount>>,
+        system_program: &AccountInfo<'info>,
+        amount: u64,
+    ) -> Result<()> {
+        let Some(cohort_vault) = cohort_vault else {
+            let cpi_context = CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: funder.clone(),
+                    to: cohort_info.clone(),
+                },
+            );
+            return system_program::transfer(cpi_context, amount);
+        };
+
+        let (Some(token_program), Some(funder_token_account)) = (token_program, funder_token_account) else {
+            return Err(StreakError::MissingTokenAccounts.into());
+        };
+        let cpi_context = CpiContext::new(
+            token_program.to_account_info(),
+            Transfer {
+                from: funder_token_account.to_account_info(),
+                to: cohort_vault.to_account_info(),
+                authority: funder.clone(),
+            },
+        );
+        token::transfer(cpi_context, amount)
+    }
+
+    /// Move budget out of the cohort: tokens from its vault (signed by the cohort PDA),
+    /// or lamports straight off the cohort account
+    pub fn pay_out<'info>(
+        cohort: &Account<'info, Cohort>,
+        cohort_vault: Option<&Account<'info, TokenAccount>>,
+        token_program: Option<&Program<'info, Token>>,
+        to: &AccountInfo<'info>,
+        amount: u64,
+    ) -> Result<()> {
+        let cohort_info = cohort.to_account_info();
+        let Some(cohort_vault) = cohort_vault else {
+            **cohort_info.try_borrow_mut_lamports()? = cohort_info
+                .lamports()
+                .checked_sub(amount)
+                .ok_or(StreakError::InsufficientFunds)?;
+
+            **to.try_borrow_mut_lamports()? = to
+                .lamports()
+                .checked_add(amount)
+                .ok_or(StreakError::Overflow)?;
+
+            return Ok(());
+        };
+
+        let token_program = token_program.ok_or(StreakError::MissingTokenAccounts)?;
+        let cohort_id = cohort.cohort_id.to_le_bytes();
+        let signer_seeds: &[&[&[u8]]] = &[&[COHORT_SEED, cohort.admin.as_ref(), &cohort_id, &[cohort.bump]]];
+        let cpi_context = CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            Transfer {
+                from: cohort_vault.to_account_info(),
+                to: to.clone(),
+                authority: cohort_info,
+            },
+            signer_seeds,
+        );
+        token::transfer(cpi_context, amount)
+    }
+
+    /// Budget left for new claims: the cohort's lamports above rent-exempt minimum
+    pub fn available_budget(cohort_info: &AccountInfo) -> Result<u64> {
+        let rent_exempt = Rent::get()?.minimum_balance(cohort_info.data_len());
+        Ok(cohort_info.lamports().saturating_sub(rent_exempt))
+    }
+
+    /// Check a wallet against the member merkle root (sorted-pair keccak tree)
+    pub fn is_member(&self, wallet: &Pubkey, proof: &[[u8; 32]]) -> bool {
+        let mut node = keccak::hashv(&[wallet.as_ref()]).to_bytes();
+
+        for sibling in proof.iter() {
+            node = if node <= *sibling {
+                keccak::hashv(&[&node, sibling]).to_bytes()
+            } else {
+                keccak::hashv(&[sibling, &node]).to_bytes()
+            };
+        }
+
+        node == self.merkle_root
+    }
+}
+
+/// A member's claim on a cohort, used to block double claims and keep cohort stats
+#[account]
+#[derive(Default)]
+pub struct CohortMember {
+    /// Cohort the stake was claimed from
+    pub cohort: Pubkey,
+
+    /// Member wallet
+    pub wallet: Pubkey,
+
+    /// Unix timestamp of the claim
+    pub claimed_at: i64,
+
+    /// Start of the funded stake (identifies it on the player account)
+    pub start_day: i64,
+
+    /// Whether the funded stake was still running at the last sync
+    pub is_active: bool,
+
+    /// Growth counted towards the cohort total at the last sync
+    pub last_growth: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl CohortMember {
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // cohort
+        32 + // wallet
+        8 + // claimed_at
+        8 + // start_day
+        1 + // is_active
+        8 + // last_growth
+        1 + // bump
+        32; // padding
+}
@@ -0,0 +1,163 @@
+use anchor_lang::prelude::*;
+use crate::constants::{PROTOCOL_FEE_BPS, BPS_DENOMINATOR};
+use crate::errors::StreakError;
+use crate::state::{GameState, Player};
+
+/// A head-to-head streak wager between two active players of the same game.
+/// Both wagers are held as lamports on this account until each side claims.
+#[account]
+#[derive(Default)]
+pub struct Duel {
+    /// Arena both players belong to
+    pub game_id: u64,
+
+    /// Challenger-chosen ID
+    pub duel_id: u64,
+
+    /// Player who opened the duel
+    pub challenger: Pubkey,
+
+    /// Player challenged
+    pub opponent: Pubkey,
+
+    /// Lamports each side puts up
+    pub wager: u64,
+
+    /// Unix timestamp the duel ends at; streaks kept past it count as survived
+    pub ends_at: i64,
+
+    /// Whether the opponent has matched the wager
+    pub accepted: bool,
+
+    /// Whether the outcome has been decided
+    pub settled: bool,
+
+    /// Player who kept their streak longer (None = draw, both wagers refunded)
+    pub winner: Option<Pubkey>,
+
+    /// Lamports owed to the winner (both wagers minus the protocol fee)
+    pub prize: u64,
+
+    /// Whether the challenger has claimed their payout
+    pub challenger_claimed: bool,
+
+    /// Whether the opponent has claimed their payout
+    pub opponent_claimed: bool,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Duel {
+    pub const SIZE: usize = 8 + // discriminator
+        8 + // game_id
+        8 + // duel_id
+        32 + // challenger
+        32 + // opponent
+        8 + // wager
+        8 + // ends_at
+        1 + // accepted
+        1 + // settled
+        33 + // winner (Option<Pubkey>)
+        8 + // prize
+        1 + // challenger_claimed
+        1 + // opponent_claimed
+        1 + // bump
+        32; // padding
+
+    /// Get the other side of the duel
+    pub fn counterpart(&self, wallet: &Pubkey) -> Result<Pubkey> {
+        if *wallet == self.challenger {
+            Ok(self.opponent)
+        } else if *wallet == self.opponent {
+            Ok(self.challenger)
+        } else {
+            Err(StreakError::NotDuelParty.into())
+        }
+    }
+
+    /// Decide the duel from both players' streaks as of `current_time`. The player whose
+    /// streak broke later wins; streaks that last to the end (or break in the same
+    /// period) draw. Returns the protocol fee taken from the pot.
+    pub fn settle(
+        &mut self,
+        challenger: &mut Player,
+        opponent: &mut Player,
+        game_state: &GameState,
+        current_time: i64,
+    ) -> Result<u64> {
+        require!(self.accepted, StreakError::DuelNotAccepted);
+        require!(!self.settled, StreakError::DuelSettled);
+
+        let interval = game_state.checkin_interval_seconds;
+        let late_window = game_state.late_window_seconds;
+        let streak_end = |player: &Player| {
+            player
+                .streak_broken_at(current_time, interval, late_window)
+                .unwrap_or(i64::MAX)
+                .min(self.ends_at)
+        };
+        let challenger_end = streak_end(challenger);
+        let opponent_end = streak_end(opponent);
+
+        self.settled = true;
+        challenger.open_duels = challenger.open_duels.saturating_sub(1);
+        opponent.open_duels = opponent.open_duels.saturating_sub(1);
+
+        let (winner, loser) = if challenger_end > opponent_end {
+            (challenger, opponent)
+        } else if opponent_end > challenger_end {
+            (opponent, challenger)
+        } else {
+            self.winner = None;
+            return Ok(0);
+        };
+
+        let pot = self.wager.checked_mul(2).ok_or(StreakError::Overflow)?;
+        let fee = pot
+            .checked_mul(PROTOCOL_FEE_BPS)
+            .ok_or(StreakError::Overflow)?
+            .checked_div(BPS_DENOMINATOR)
+            .ok_or(StreakError::Overflow)?;
+
+        self.winner = Some(winner.wallet);
+        self.prize = pot.checked_sub(fee).ok_or(StreakError::Overflow)?;
+        winner.duels_won = winner.duels_won.checked_add(1).ok_or(StreakError::Overflow)?;
+        loser.duels_lost = loser.duels_lost.checked_add(1).ok_or(StreakError::Overflow)?;
+
+        Ok(fee)
+    }
+
+    /// Get what a settled duel owes a player (0 once claimed)
+    pub fn payout_for(&self, wallet: &Pubkey) -> Result<u64> {
+        let claimed = if *wallet == self.challenger {
+            self.challenger_claimed
+        } else if *wallet == self.opponent {
+            self.opponent_claimed
+        } else {
+            return Err(StreakError::NotDuelParty.into());
+        };
+
+        Ok(match self.winner {
+            _ if claimed => 0,
+            Some(winner) if winner == *wallet => self.prize,
+            Some(_) => 0,
+            None => self.wager,
+        })
+    }
+
+    /// Move lamports out of the duel account
+    pub fn transfer_lamports(duel_info: &AccountInfo, to: &AccountInfo, amount: u64) -> Result<()> {
+        **duel_info.try_borrow_mut_lamports()? = duel_info
+            .lamports()
+            .checked_sub(amount)
+            .ok_or(StreakError::InsufficientFunds)?;
+
+        **to.try_borrow_mut_lamports()? = to
+            .lamports()
+            .checked_add(amount)
+            .ok_or(StreakError::Overflow)?;
+
+        Ok(())
+    }
+}
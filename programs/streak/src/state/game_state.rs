@@ -1,4 +1,39 @@
 use anchor_lang::prelude::*;
+use crate::constants::{MAX_GROWTH_TIERS, MAX_BOX_REWARDS, MAIN_GAME_ID};
+
+/// Streak-length tier that raises daily growth and pays a one-off milestone reward
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct GrowthTier {
+    /// Streak length at which the tier starts (0 = unused slot)
+    pub min_streak_days: u32,
+
+    /// Extra daily growth on top of DAILY_GROWTH_BPS (basis points)
+    pub extra_growth_bps: u64,
+
+    /// One-off reward for reaching the tier, paid from the pool (basis points of stake)
+    pub milestone_reward_bps: u64,
+}
+
+impl GrowthTier {
+    pub const SIZE: usize = 4 + 8 + 8;
+}
+
+/// Weighted mystery box outcome
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct BoxReward {
+    /// BOX_REWARD_* kind (0 = unused slot)
+    pub kind: u8,
+
+    /// Relative chance of this outcome
+    pub weight: u32,
+
+    /// Growth bps of stake, lifelines, points (before multipliers) or jackpot tickets
+    pub amount: u64,
+}
+
+impl BoxReward {
+    pub const SIZE: usize = 1 + 4 + 8;
+}
 
 #[account]
 #[derive(Default)]
@@ -33,6 +68,101 @@ pub struct GameState {
 
     /// PDA bump seed
     pub bump: u8,
+
+    /// Seconds after a period boundary during which a missed check-in
+    /// can still be made up with a penalty (0 = late check-ins disabled)
+    pub late_window_seconds: i64,
+
+    /// Share of stake moved to the pool on a late check-in (basis points)
+    pub late_penalty_bps: u64,
+
+    /// Seconds after death during which a player can revive (0 = revives disabled)
+    pub revive_window_seconds: i64,
+
+    /// Price of a revive as a share of the lost stake (basis points)
+    pub revive_cost_bps: u64,
+
+    /// Share of the lost stake restored on revive (basis points)
+    pub revive_stake_bps: u64,
+
+    /// Exit fee charged on a fresh streak (basis points of withdrawn stake)
+    pub exit_fee_max_bps: u64,
+
+    /// Streak length at which the exit fee has decayed to zero
+    pub exit_fee_zero_streak_days: u32,
+
+    /// Share of exit fees sent to the treasury, the rest stays in the pool (basis points)
+    pub exit_fee_treasury_bps: u64,
+
+    /// Lamports owed to the withdrawal queue (growth is paused while > 0)
+    pub withdraw_queue_owed: u64,
+
+    /// Growth tiers / milestones, ordered by min_streak_days
+    pub growth_tiers: [GrowthTier; MAX_GROWTH_TIERS],
+
+    /// Points credited per on-time or late check-in (before multipliers)
+    pub points_per_checkin: u64,
+
+    /// Points credited per bonus window claim (before multipliers)
+    pub points_per_bonus_claim: u64,
+
+    /// Points credited per referral reward from a death (before multipliers)
+    pub points_per_referral: u64,
+
+    /// Points credited per milestone reached (before multipliers)
+    pub points_per_milestone: u64,
+
+    /// Campaign multiplier applied to all points (basis points, 10_000 = 1x, 0 = points off)
+    pub points_multiplier_bps: u64,
+
+    /// Current points snapshot ID (0 = no snapshot taken yet)
+    pub points_snapshot_id: u64,
+
+    /// STREAK reward token mint, minted by this PDA (default = token rewards off)
+    pub reward_mint: Pubkey,
+
+    /// Tokens per check-in before halvings (base units)
+    pub reward_per_checkin: u64,
+
+    /// Tokens per bonus window claim before halvings (base units)
+    pub reward_per_bonus_claim: u64,
+
+    /// Tokens per milestone reached before halvings (base units)
+    pub reward_per_milestone: u64,
+
+    /// Unix timestamp emissions started (halvings count from here)
+    pub reward_emission_start: i64,
+
+    /// Seconds between emission halvings (0 = no halving)
+    pub reward_halving_seconds: i64,
+
+    /// SPL mint players stake (default = native SOL), fixed once the game has a token vault
+    pub stake_mint: Pubkey,
+
+    /// Arena ID this game state belongs to (MAIN_GAME_ID for the original game)
+    pub game_id: u64,
+
+    /// Number of stake-tier leagues (0 = leagues off)
+    pub league_count: u8,
+
+    /// Season currently open for entry or running (0 = no seasons)
+    pub current_season_id: u64,
+
+    /// Extra growth for members of squads where everyone checked in last period
+    /// (basis points of the member's growth, 0 = squad bonus off)
+    pub squad_growth_bonus_bps: u64,
+
+    /// Share of stake each remaining member loses when a squadmate dies (basis points)
+    pub squad_penalty_bps: u64,
+
+    /// Share of each dead stake routed to the jackpot (basis points, 0 = no jackpot)
+    pub jackpot_bps: u64,
+
+    /// Streak length between mystery boxes (every Nth day, 0 = boxes off)
+    pub box_interval_days: u32,
+
+    /// Weighted outcomes of opening a mystery box
+    pub box_rewards: [BoxReward; MAX_BOX_REWARDS],
 }
 
 impl GameState {
@@ -47,9 +177,107 @@ impl GameState {
         8 + // bonus_window_end
         8 + // checkin_interval_seconds
         1 + // bump
-        56; // padding (reduced from 64)
+        8 + // late_window_seconds
+        8 + // late_penalty_bps
+        8 + // revive_window_seconds
+        8 + // revive_cost_bps
+        8 + // revive_stake_bps
+        8 + // exit_fee_max_bps
+        4 + // exit_fee_zero_streak_days
+        8 + // exit_fee_treasury_bps
+        8 + // withdraw_queue_owed
+        GrowthTier::SIZE * MAX_GROWTH_TIERS + // growth_tiers
+        8 + // points_per_checkin
+        8 + // points_per_bonus_claim
+        8 + // points_per_referral
+        8 + // points_per_milestone
+        8 + // points_multiplier_bps
+        8 + // points_snapshot_id
+        32 + // reward_mint
+        8 + // reward_per_checkin
+        8 + // reward_per_bonus_claim
+        8 + // reward_per_milestone
+        8 + // reward_emission_start
+        8 + // reward_halving_seconds
+        32 + // stake_mint
+        8 + // game_id
+        1 + // league_count
+        8 + // current_season_id
+        8 + // squad_growth_bonus_bps
+        8 + // squad_penalty_bps
+        8 + // jackpot_bps
+        4 + // box_interval_days
+        BoxReward::SIZE * MAX_BOX_REWARDS + // box_rewards
+        119; // padding (extended by 256, see migrate_game_state)
 
     pub fn is_bonus_window_active(&self, current_time: i64) -> bool {
         self.current_bonus_window > 0 && current_time < self.bonus_window_end
     }
+
+    /// Seed that scopes PDAs to an arena. Empty for the main game, so the
+    /// singleton's accounts keep the addresses they had before arenas.
+    pub fn seed_for(game_id: u64) -> Vec<u8> {
+        if game_id == MAIN_GAME_ID {
+            Vec::new()
+        } else {
+            game_id.to_le_bytes().to_vec()
+        }
+    }
+
+    /// Seed that scopes PDAs to this game
+    pub fn game_seed(&self) -> Vec<u8> {
+        Self::seed_for(self.game_id)
+    }
+
+    /// Game-wide check-in period a timestamp falls in (UTC, no player offset)
+    pub fn period_at(&self, timestamp: i64) -> i64 {
+        timestamp.div_euclid(self.checkin_interval_seconds)
+    }
+
+    /// Whether players stake an SPL token instead of SOL
+    pub fn is_token_game(&self) -> bool {
+        self.stake_mint != Pubkey::default()
+    }
+
+    /// Lamports in the vault that can be paid out (everything above rent-exemption)
+    pub fn available_lamports(game_state_info: &AccountInfo) -> Result<u64> {
+        let rent_exempt = Rent::get()?.minimum_balance(game_state_info.data_len());
+        Ok(game_state_info.lamports().saturating_sub(rent_exempt))
+    }
+
+    /// Reward tokens for an action after halvings (0 when token rewards are off)
+    pub fn token_reward(&self, base_amount: u64, current_time: i64) -> u64 {
+        if self.reward_mint == Pubkey::default() {
+            return 0;
+        }
+        if self.reward_halving_seconds <= 0 {
+            return base_amount;
+        }
+
+        let halvings = (current_time - self.reward_emission_start).max(0) / self.reward_halving_seconds;
+        if halvings >= 64 {
+            0
+        } else {
+            base_amount >> halvings
+        }
+    }
+
+    /// Extra daily growth for a run of on-time check-ins (basis points)
+    pub fn growth_tier_bonus_bps(&self, growth_streak_days: u32) -> u64 {
+        self.growth_tiers
+            .iter()
+            .filter(|tier| tier.min_streak_days > 0 && growth_streak_days >= tier.min_streak_days)
+            .map(|tier| tier.extra_growth_bps)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Exit fee for a given streak length, decaying linearly to zero (basis points)
+    pub fn exit_fee_bps(&self, streak_days: u32) -> u64 {
+        if streak_days >= self.exit_fee_zero_streak_days {
+            return 0;
+        }
+        let remaining_days = (self.exit_fee_zero_streak_days - streak_days) as u64;
+        self.exit_fee_max_bps * remaining_days / self.exit_fee_zero_streak_days as u64
+    }
 }
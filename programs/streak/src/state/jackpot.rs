@@ -0,0 +1,180 @@
+use anchor_lang::prelude::*;
+use crate::constants::{MAX_JACKPOT_ENTRIES, MAX_JACKPOT_PERIODS, MAX_JACKPOT_PRIZES};
+use crate::errors::StreakError;
+use crate::state::{GameState, Player};
+
+/// A check-in entered into a period's draw
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct JackpotEntry {
+    /// Player wallet
+    pub wallet: Pubkey,
+
+    /// Period whose draw the entry is in
+    pub period: i64,
+
+    /// Draw weight (stake or streak at check-in)
+    pub weight: u64,
+}
+
+impl JackpotEntry {
+    pub const SIZE: usize = 32 + 8 + 8;
+}
+
+/// A drawn prize waiting to be credited to the winner's pending rewards
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct JackpotPrize {
+    /// Winning wallet
+    pub wallet: Pubkey,
+
+    /// Lamports won
+    pub amount: u64,
+}
+
+impl JackpotPrize {
+    pub const SIZE: usize = 32 + 8;
+}
+
+/// A game's jackpot, fed by a slice of every death and drawn once per period
+/// between the players who checked in. Its balance stays in the game's vault.
+#[account]
+#[derive(Default)]
+pub struct Jackpot {
+    /// Arena the jackpot belongs to
+    pub game_id: u64,
+
+    /// Lamports waiting for the next draw
+    pub balance: u64,
+
+    /// Weight entries by streak length instead of stake
+    pub weight_by_streak: bool,
+
+    /// Check-ins not yet drawn (oldest period first)
+    pub entries: Vec<JackpotEntry>,
+
+    /// Drawn prizes not yet credited
+    pub prizes: Vec<JackpotPrize>,
+
+    /// Latest period drawn
+    pub last_draw_period: i64,
+
+    /// Slot whose hash draws the oldest finished period (0 = round not closed yet)
+    pub draw_slot: u64,
+
+    /// Number of draws
+    pub draws: u64,
+
+    /// Total lamports ever won
+    pub total_won: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Jackpot {
+    pub const SIZE: usize = 8 + // discriminator
+        8 + // game_id
+        8 + // balance
+        1 + // weight_by_streak
+        4 + JackpotEntry::SIZE * MAX_JACKPOT_ENTRIES * MAX_JACKPOT_PERIODS + // entries
+        4 + JackpotPrize::SIZE * MAX_JACKPOT_PRIZES + // prizes
+        8 + // last_draw_period
+        8 + // draw_slot
+        8 + // draws
+        8 + // total_won
+        1 + // bump
+        24; // padding
+
+    /// Validate the jackpot was passed once the game has one
+    pub fn check_present(jackpot: Option<&Account<Jackpot>>, game_state: &GameState) -> Result<()> {
+        require!(
+            jackpot.is_some() || game_state.jackpot_bps == 0,
+            StreakError::MissingJackpot
+        );
+        Ok(())
+    }
+
+    /// Enter a check-in into its period's draw. If that draw is full the entry
+    /// rolls into the next period's, up to MAX_JACKPOT_PERIODS ahead. Returns
+    /// the period entered, or None if every draw in reach is full.
+    pub fn enter(&mut self, player: &Player, period: i64) -> Option<i64> {
+        if self.entries.len() >= MAX_JACKPOT_ENTRIES * MAX_JACKPOT_PERIODS {
+            return None;
+        }
+
+        let period = (period..period + MAX_JACKPOT_PERIODS as i64)
+            .find(|&draw_period| self.entries_for(draw_period) < MAX_JACKPOT_ENTRIES)?;
+        let weight = if self.weight_by_streak {
+            player.streak_days as u64
+        } else {
+            player.stake
+        };
+        self.entries.push(JackpotEntry { wallet: player.wallet, period, weight });
+        Some(period)
+    }
+
+    /// Number of entries in a period's draw
+    pub fn entries_for(&self, period: i64) -> usize {
+        self.entries.iter().filter(|entry| entry.period == period).count()
+    }
+
+    /// Oldest period with undrawn entries
+    pub fn next_draw_period(&self) -> Option<i64> {
+        self.entries.iter().map(|entry| entry.period).min()
+    }
+
+    /// Draw a winner from a period's entries using `random`, weighted by entry weight,
+    /// and award them the balance. The period's entries are cleared.
+    pub fn draw(&mut self, period: i64, random: u64) -> Result<Option<JackpotPrize>> {
+        let total_weight = self.entries
+            .iter()
+            .filter(|entry| entry.period == period)
+            .try_fold(0u64, |total, entry| total.checked_add(entry.weight))
+            .ok_or(StreakError::Overflow)?;
+
+        let mut winner = None;
+        if total_weight > 0 {
+            let mut ticket = random % total_weight;
+            for entry in self.entries.iter().filter(|entry| entry.period == period) {
+                if ticket < entry.weight {
+                    winner = Some(entry.wallet);
+                    break;
+                }
+                ticket -= entry.weight;
+            }
+        }
+
+        self.entries.retain(|entry| entry.period != period);
+        self.last_draw_period = period;
+        self.draw_slot = 0;
+        self.draws = self.draws.checked_add(1).ok_or(StreakError::Overflow)?;
+
+        let prize = match winner {
+            Some(wallet) if self.balance > 0 => JackpotPrize { wallet, amount: self.balance },
+            _ => return Ok(None),
+        };
+        require!(self.prizes.len() < MAX_JACKPOT_PRIZES, StreakError::JackpotPrizesFull);
+
+        self.prizes.push(prize);
+        self.balance = 0;
+        self.total_won = self.total_won.checked_add(prize.amount).ok_or(StreakError::Overflow)?;
+        Ok(Some(prize))
+    }
+
+    /// Clear a period's entries without a winner, leaving the balance for the next draw
+    pub fn void_draw(&mut self, period: i64) {
+        self.entries.retain(|entry| entry.period != period);
+        self.last_draw_period = period;
+        self.draw_slot = 0;
+    }
+
+    /// Remove and return everything a wallet has won
+    pub fn take_prizes(&mut self, wallet: &Pubkey) -> Result<u64> {
+        let won = self.prizes
+            .iter()
+            .filter(|prize| prize.wallet == *wallet)
+            .try_fold(0u64, |total, prize| total.checked_add(prize.amount))
+            .ok_or(StreakError::Overflow)?;
+        self.prizes.retain(|prize| prize.wallet != *wallet);
+        Ok(won)
+    }
+}
@@ -0,0 +1,180 @@
+use anchor_lang::prelude::*;
+use crate::constants::LEADERBOARD_SIZE;
+use crate::errors::StreakError;
+use crate::state::{GameState, Player};
+
+/// A player's position on a league leaderboard
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct LeaderboardEntry {
+    /// Player wallet (default = empty slot)
+    pub wallet: Pubkey,
+
+    /// Streak length at the player's latest check-in
+    pub streak_days: u32,
+}
+
+impl LeaderboardEntry {
+    pub const SIZE: usize = 32 + 4;
+}
+
+/// A stake band within a game. Players are placed in the league matching their
+/// stake, and deaths only feed the pool of the league the player was in.
+#[account]
+#[derive(Default)]
+pub struct League {
+    /// Arena the league belongs to
+    pub game_id: u64,
+
+    /// League number within the game (1-based)
+    pub league_id: u8,
+
+    /// Smallest stake in the band (inclusive)
+    pub min_stake: u64,
+
+    /// Largest stake in the band (exclusive, 0 = no upper bound)
+    pub max_stake: u64,
+
+    /// Stakes placed in this league plus what its deaths and penalties left in the pool
+    pub total_pool: u64,
+
+    /// Players currently staked in the league
+    pub active_players: u64,
+
+    /// Deaths in this league
+    pub total_deaths: u64,
+
+    /// Lamports this league's deaths left in its pool
+    pub total_death_pool: u64,
+
+    /// Current bonus window ID (0 = none yet)
+    pub current_bonus_window: u64,
+
+    /// Unix timestamp the bonus window ends
+    pub bonus_window_end: i64,
+
+    /// Longest active streaks, longest first
+    pub leaderboard: [LeaderboardEntry; LEADERBOARD_SIZE],
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl League {
+    pub const SIZE: usize = 8 + // discriminator
+        8 + // game_id
+        1 + // league_id
+        8 + // min_stake
+        8 + // max_stake
+        8 + // total_pool
+        8 + // active_players
+        8 + // total_deaths
+        8 + // total_death_pool
+        8 + // current_bonus_window
+        8 + // bonus_window_end
+        LeaderboardEntry::SIZE * LEADERBOARD_SIZE + // leaderboard
+        1 + // bump
+        64; // padding
+
+    /// Check whether a stake falls in this league's band
+    pub fn contains(&self, stake: u64) -> bool {
+        stake >= self.min_stake && (self.max_stake == 0 || stake < self.max_stake)
+    }
+
+    pub fn is_bonus_window_active(&self, current_time: i64) -> bool {
+        self.current_bonus_window > 0 && current_time < self.bonus_window_end
+    }
+
+    /// Check a league account is the one a player belongs to
+    pub fn check_member(league: Option<&Account<League>>, player: &Player) -> Result<()> {
+        match league {
+            Some(league) => require!(league.league_id == player.league_id, StreakError::WrongLeague),
+            None => require!(player.league_id == 0, StreakError::WrongLeague),
+        }
+        Ok(())
+    }
+
+    /// Place a new stake in its league. Games without leagues leave the player unplaced.
+    pub fn place(game_state: &GameState, league: Option<&mut Account<League>>, player: &mut Player) -> Result<()> {
+        player.league_id = 0;
+        if game_state.league_count == 0 {
+            return Ok(());
+        }
+
+        let league = league.ok_or(StreakError::WrongLeague)?;
+        require!(league.contains(player.stake), StreakError::OutsideLeagueBand);
+        league.join(player)?;
+        league.add_to_pool(player.stake)
+    }
+
+    /// Add a player to the league's members and leaderboard (pool changes are up to the caller)
+    pub fn join(&mut self, player: &mut Player) -> Result<()> {
+        player.league_id = self.league_id;
+        self.active_players = self.active_players.checked_add(1).ok_or(StreakError::Overflow)?;
+        self.record_streak(player.wallet, player.streak_days);
+
+        Ok(())
+    }
+
+    /// Take a player off the league's books. `pool_removed` is what left the pool with them.
+    pub fn leave(&mut self, wallet: Pubkey, pool_removed: u64) -> Result<()> {
+        self.active_players = self.active_players.checked_sub(1).ok_or(StreakError::Overflow)?;
+        self.remove_from_pool(pool_removed)?;
+        self.remove_from_leaderboard(wallet);
+
+        Ok(())
+    }
+
+    /// Move a player whose stake left this league's band into `new_league`
+    pub fn follow_stake(&mut self, new_league: Option<&mut Account<League>>, player: &mut Player) -> Result<()> {
+        if self.contains(player.stake) {
+            return Ok(());
+        }
+
+        let new_league = new_league.ok_or(StreakError::OutsideLeagueBand)?;
+        require!(new_league.league_id != self.league_id, StreakError::WrongLeague);
+
+        require!(new_league.contains(player.stake), StreakError::OutsideLeagueBand);
+
+        self.leave(player.wallet, player.stake)?;
+        new_league.join(player)?;
+        new_league.add_to_pool(player.stake)?;
+
+        msg!("Moved from league {} to league {}", self.league_id, new_league.league_id);
+
+        Ok(())
+    }
+
+    pub fn add_to_pool(&mut self, amount: u64) -> Result<()> {
+        self.total_pool = self.total_pool.checked_add(amount).ok_or(StreakError::Overflow)?;
+        Ok(())
+    }
+
+    pub fn remove_from_pool(&mut self, amount: u64) -> Result<()> {
+        self.total_pool = self.total_pool.checked_sub(amount).ok_or(StreakError::Overflow)?;
+        Ok(())
+    }
+
+    /// Update a player's leaderboard entry, keeping the board sorted longest first
+    pub fn record_streak(&mut self, wallet: Pubkey, streak_days: u32) {
+        self.remove_from_leaderboard(wallet);
+
+        let Some(position) = self
+            .leaderboard
+            .iter()
+            .position(|entry| entry.wallet == Pubkey::default() || entry.streak_days < streak_days)
+        else {
+            return;
+        };
+
+        self.leaderboard[position..].rotate_right(1);
+        self.leaderboard[position] = LeaderboardEntry { wallet, streak_days };
+    }
+
+    /// Drop a player from the leaderboard, closing the gap
+    pub fn remove_from_leaderboard(&mut self, wallet: Pubkey) {
+        if let Some(position) = self.leaderboard.iter().position(|entry| entry.wallet == wallet) {
+            self.leaderboard[position..].rotate_left(1);
+            self.leaderboard[LEADERBOARD_SIZE - 1] = LeaderboardEntry::default();
+        }
+    }
+}
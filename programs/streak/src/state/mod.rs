@@ -1,5 +1,23 @@
 pub mod game_state;
 pub mod player;
+pub mod withdraw_queue;
+pub mod cohort;
+pub mod league;
+pub mod season;
+pub mod tournament;
+pub mod duel;
+pub mod squad;
+pub mod jackpot;
+pub mod sponsor_pool;
 
 pub use game_state::*;
 pub use player::*;
+pub use withdraw_queue::*;
+pub use cohort::*;
+pub use league::*;
+pub use season::*;
+pub use tournament::*;
+pub use duel::*;
+pub use squad::*;
+pub use jackpot::*;
+pub use sponsor_pool::*;
@@ -1,5 +1,9 @@
 use anchor_lang::prelude::*;
-use crate::constants::SECONDS_PER_DAY;
+use crate::constants::{
+    SECONDS_PER_DAY, COMMITMENT_TIERS, GRACE_PERIOD_SECONDS, BPS_DENOMINATOR, POINTS_TIER_BONUS_BPS,
+};
+use crate::errors::StreakError;
+use crate::state::GameState;
 
 #[account]
 #[derive(Default)]
@@ -48,6 +52,127 @@ pub struct Player {
 
     /// PDA bump seed
     pub bump: u8,
+
+    /// Seconds added to the UTC period boundaries for this player's deadline.
+    /// Chosen at stake time and fixed for the rest of that stake.
+    pub deadline_offset: i64,
+
+    /// Consecutive check-ins counted towards growth (reset by late check-ins)
+    pub growth_streak_days: u32,
+
+    /// First frozen period of the latest scheduled freeze
+    pub freeze_start_period: i64,
+
+    /// Period after the last frozen period (exclusive)
+    pub freeze_end_period: i64,
+
+    /// Number of freezes scheduled during the current stake
+    pub freezes_used: u8,
+
+    /// Stake lost at the last death (0 = nothing to revive)
+    pub stake_at_death: u64,
+
+    /// Streak length at the last death
+    pub streak_at_death: u32,
+
+    /// Unix timestamp of the last death
+    pub died_at: i64,
+
+    /// Part of the lost stake that went to the pool (the most a revive can claw back)
+    pub death_pool_share: u64,
+
+    /// Target streak length committed to at stake time (0 = no commitment).
+    /// Withdrawals are penalised until the target is reached.
+    pub commitment_days: u32,
+
+    /// Whether the commitment target has been reached (bonus paid, stake unlocked)
+    pub commitment_completed: bool,
+
+    /// Bitmask of growth tier milestones reached during the current stake
+    pub milestones_reached: u8,
+
+    /// Lamports put into the current stake (growth is everything above this)
+    pub principal: u64,
+
+    /// Total growth ever harvested into pending_rewards
+    pub total_harvested: u64,
+
+    /// Total pending rewards ever compounded back into stake
+    pub total_compounded: u64,
+
+    /// Whether referral rewards go straight into stake while active
+    pub auto_compound: bool,
+
+    /// Wallet that funded the current stake (None = self-funded)
+    pub sponsor: Option<Pubkey>,
+
+    /// Whether the withdrawn stake goes back to the sponsor instead of the player
+    pub withdraw_to_sponsor: bool,
+
+    /// Share of the stake refunded to the sponsor on death (basis points)
+    pub sponsor_refund_bps: u64,
+
+    /// Lifetime engagement points (non-transferable, survive death and re-stakes)
+    pub points: u64,
+
+    /// Last points snapshot this player was recorded in
+    pub points_snapshot_id: u64,
+
+    /// Points held when the latest snapshot started (frozen before any later credit)
+    pub snapshot_points: u64,
+
+    /// Reward tokens earned but not yet minted to the player's token account
+    pub token_rewards_owed: u64,
+
+    /// Total reward tokens ever minted to the player
+    pub total_tokens_minted: u64,
+
+    /// Arena this player account belongs to (MAIN_GAME_ID for the original game)
+    pub game_id: u64,
+
+    /// League the current stake is placed in (0 = not in a league). Instructions
+    /// that touch the stake take this league's account while it is set; new stakes
+    /// in a game with leagues are placed in one, existing ones opt in with join_league.
+    pub league_id: u8,
+
+    /// Latest season the player joined
+    pub season_id: u64,
+
+    /// Stake at entry, the player's payout weight (0 = out of the season or already paid)
+    pub season_stake: u64,
+
+    /// Seasons ever joined
+    pub seasons_played: u32,
+
+    /// Seasons survived to the end
+    pub seasons_survived: u32,
+
+    /// Total season payouts ever received
+    pub season_rewards: u64,
+
+    /// Accepted duels not yet settled
+    pub open_duels: u8,
+
+    /// Duels ever won
+    pub duels_won: u32,
+
+    /// Duels ever lost
+    pub duels_lost: u32,
+
+    /// Squad the player belongs to (None = no squad)
+    pub squad: Option<Pubkey>,
+
+    /// Unopened mystery boxes
+    pub mystery_boxes: u8,
+
+    /// Slot whose hash opens the unopened boxes, fixed when the latest box was granted
+    pub box_reveal_slot: u64,
+
+    /// Mystery boxes ever opened
+    pub boxes_opened: u32,
+
+    /// Snapshot that snapshot_points was frozen for
+    pub points_frozen_id: u64,
 }
 
 impl Player {
@@ -67,7 +192,46 @@ impl Player {
         4 + // total_bonus_claims
         8 + // referral_earnings
         1 + // bump
-        64; // padding
+        8 + // deadline_offset
+        4 + // growth_streak_days
+        8 + // freeze_start_period
+        8 + // freeze_end_period
+        1 + // freezes_used
+        8 + // stake_at_death
+        4 + // streak_at_death
+        8 + // died_at
+        8 + // death_pool_share
+        4 + // commitment_days
+        1 + // commitment_completed
+        1 + // milestones_reached
+        8 + // principal
+        8 + // total_harvested
+        8 + // total_compounded
+        1 + // auto_compound
+        33 + // sponsor (Option<Pubkey>)
+        1 + // withdraw_to_sponsor
+        8 + // sponsor_refund_bps
+        8 + // points
+        8 + // points_snapshot_id
+        8 + // snapshot_points
+        8 + // token_rewards_owed
+        8 + // total_tokens_minted
+        8 + // game_id
+        1 + // league_id
+        8 + // season_id
+        8 + // season_stake
+        4 + // seasons_played
+        4 + // seasons_survived
+        8 + // season_rewards
+        1 + // open_duels
+        4 + // duels_won
+        4 + // duels_lost
+        33 + // squad (Option<Pubkey>)
+        1 + // mystery_boxes
+        8 + // box_reveal_slot
+        4 + // boxes_opened
+        8 + // points_frozen_id
+        46; // padding (extended by 128, see migrate_player)
 
     /// Get the UTC day number from a timestamp
     pub fn get_utc_day(timestamp: i64) -> i64 {
@@ -100,6 +264,210 @@ impl Player {
         (last_checkin_day < today && self.start_day < today)
     }
 
+    /// Get the start of the first period of a new stake (as a timestamp).
+    /// Staking in the last 5 minutes of a period starts the next one.
+    pub fn start_day_for(current_time: i64, interval: i64, deadline_offset: i64) -> i64 {
+        let local_time = current_time - deadline_offset;
+        let current_period = local_time.div_euclid(interval);
+        let seconds_until_next_period = interval - local_time.rem_euclid(interval);
+
+        if seconds_until_next_period <= GRACE_PERIOD_SECONDS {
+            (current_period + 1) * interval + deadline_offset
+        } else {
+            current_period * interval + deadline_offset
+        }
+    }
+
+    /// Reset per-stake state for a new stake. Lifetime stats (referrals,
+    /// lifelines, pending rewards and totals) are kept for re-stakers.
+    #[allow(clippy::too_many_arguments)]
+    pub fn begin_stake(
+        &mut self,
+        wallet: Pubkey,
+        game_id: u64,
+        amount: u64,
+        current_time: i64,
+        start_day: i64,
+        deadline_offset: i64,
+        commitment_days: u32,
+    ) {
+        self.wallet = wallet;
+        self.game_id = game_id;
+        self.stake = amount;
+        self.principal = amount;
+        self.streak_days = 1;
+        self.growth_streak_days = 1;
+        self.last_checkin = current_time;
+        self.start_day = start_day;
+        self.deadline_offset = deadline_offset;
+        self.is_active = true;
+        self.lifelines_used = 0;
+        self.last_bonus_claimed = 0;
+        self.total_bonus_claims = 0;
+        self.freeze_start_period = 0;
+        self.freeze_end_period = 0;
+        self.freezes_used = 0;
+        self.stake_at_death = 0; // Re-staking gives up any pending revive
+        self.season_stake = 0;
+        self.streak_at_death = 0;
+        self.died_at = 0;
+        self.death_pool_share = 0;
+        self.commitment_days = commitment_days;
+        self.commitment_completed = false;
+        self.milestones_reached = 0;
+        self.sponsor = None;
+        self.withdraw_to_sponsor = false;
+        self.sponsor_refund_bps = 0;
+    }
+
+    /// Check if the account still holds state a fresh stake would overwrite
+    /// (a pending revive, unclaimed rewards or an unclaimed season payout)
+    pub fn has_outstanding_claims(&self) -> bool {
+        self.stake_at_death > 0 || self.has_unclaimed_rewards()
+    }
+
+    /// Check for rewards or a season payout the player hasn't claimed yet
+    pub fn has_unclaimed_rewards(&self) -> bool {
+        self.pending_rewards > 0
+            || self.token_rewards_owed > 0
+            || self.season_stake > 0
+    }
+
+    /// Seed that scopes this player's PDA to its arena
+    pub fn game_seed(&self) -> Vec<u8> {
+        GameState::seed_for(self.game_id)
+    }
+
+    /// Get the check-in period a timestamp falls in, shifted by the player's deadline offset
+    pub fn period_at(&self, timestamp: i64, interval: i64) -> i64 {
+        (timestamp - self.deadline_offset).div_euclid(interval)
+    }
+
+    /// Check if a period is covered by the player's scheduled freeze
+    pub fn is_frozen(&self, period: i64) -> bool {
+        period >= self.freeze_start_period && period < self.freeze_end_period
+    }
+
+    /// Get the latest period covered by a check-in or a freeze following it
+    pub fn last_covered_period(&self, interval: i64) -> i64 {
+        let last_covered_period = self.period_at(self.last_checkin, interval);
+
+        // A freeze that picks up right after the last check-in counts as checked in
+        if self.freeze_start_period <= last_covered_period + 1 {
+            last_covered_period.max(self.freeze_end_period - 1)
+        } else {
+            last_covered_period
+        }
+    }
+
+    /// Check if player missed the check-in for the previous period
+    pub fn has_missed_checkin(&self, current_period: i64, interval: i64) -> bool {
+        let start_period = self.period_at(self.start_day, interval);
+
+        self.last_covered_period(interval) < current_period - 1 && start_period < current_period
+    }
+
+    /// Get the start of the first missed period (as a timestamp), or None while
+    /// the streak is unbroken or can still be saved in the late window
+    pub fn streak_broken_at(&self, current_time: i64, interval: i64, late_window_seconds: i64) -> Option<i64> {
+        let current_period = self.period_at(current_time, interval);
+        if !self.has_missed_checkin(current_period, interval)
+            || self.is_in_late_window(current_time, interval, late_window_seconds)
+        {
+            return None;
+        }
+
+        let first_missed_period = (self.last_covered_period(interval) + 1)
+            .max(self.period_at(self.start_day, interval));
+        Some(first_missed_period * interval + self.deadline_offset)
+    }
+
+    /// Check if player missed only the previous period and is still inside the late window
+    pub fn is_in_late_window(&self, current_time: i64, interval: i64, late_window_seconds: i64) -> bool {
+        let current_period = self.period_at(current_time, interval);
+        let seconds_into_period = (current_time - self.deadline_offset).rem_euclid(interval);
+
+        seconds_into_period < late_window_seconds
+            && self.has_missed_checkin(current_period, interval)
+            && !self.has_missed_checkin(current_period - 1, interval)
+    }
+
+    /// Get (extra daily growth bps, completion bonus bps) for the player's commitment
+    pub fn commitment_tier(&self) -> Option<(u64, u64)> {
+        COMMITMENT_TIERS
+            .iter()
+            .rev()
+            .find(|(min_days, _, _)| self.commitment_days >= *min_days)
+            .map(|(_, growth_bps, bonus_bps)| (*growth_bps, *bonus_bps))
+    }
+
+    /// Check if the player is still locked into an unfinished commitment
+    /// (reaching the target unlocks the stake even while its bonus is held)
+    pub fn is_commitment_locked(&self) -> bool {
+        self.commitment_days > 0 && !self.commitment_completed && self.streak_days < self.commitment_days
+    }
+
+    /// Growth accrued above the principal that can be harvested
+    pub fn harvestable_growth(&self) -> u64 {
+        self.stake.saturating_sub(self.principal)
+    }
+
+    /// Move rewards into stake (they become principal, not harvestable growth)
+    pub fn compound(&mut self, amount: u64) -> Result<()> {
+        self.stake = self.stake.checked_add(amount).ok_or(StreakError::Overflow)?;
+        self.principal = self.principal.checked_add(amount).ok_or(StreakError::Overflow)?;
+        self.total_compounded = self.total_compounded.checked_add(amount).ok_or(StreakError::Overflow)?;
+        Ok(())
+    }
+
+    /// Credit a referral reward, returning true if it was compounded into stake
+    pub fn credit_referral_reward(&mut self, amount: u64, dead_league_id: u8) -> Result<bool> {
+        self.referral_earnings = self.referral_earnings.checked_add(amount).ok_or(StreakError::Overflow)?;
+
+        // Compounding into a stake in another league would grow it outside that league's pool
+        let same_league = self.league_id == 0 || self.league_id == dead_league_id;
+        if self.auto_compound && self.is_active && same_league {
+            self.compound(amount)?;
+            return Ok(true);
+        }
+
+        self.pending_rewards = self.pending_rewards.checked_add(amount).ok_or(StreakError::Overflow)?;
+        Ok(false)
+    }
+
+    /// Record the points held when `snapshot_id` started, if not already recorded.
+    /// Called before points change, so the value is the one at the snapshot boundary.
+    pub fn freeze_snapshot_points(&mut self, snapshot_id: u64) {
+        if self.points_frozen_id < snapshot_id {
+            self.points_frozen_id = snapshot_id;
+            self.snapshot_points = self.points;
+        }
+    }
+
+    /// Credit engagement points. Each growth tier milestone reached this stake
+    /// adds POINTS_TIER_BONUS_BPS on top of the campaign multiplier.
+    pub fn credit_points(&mut self, base: u64, multiplier_bps: u64, snapshot_id: u64) -> Result<u64> {
+        // A zero campaign multiplier switches points off
+        if multiplier_bps == 0 {
+            return Ok(0);
+        }
+
+        // Points earned after a snapshot started don't count towards it
+        self.freeze_snapshot_points(snapshot_id);
+
+        let tier_bonus_bps = POINTS_TIER_BONUS_BPS
+            .checked_mul(self.milestones_reached.count_ones() as u64)
+            .ok_or(StreakError::Overflow)?;
+        let points = base
+            .checked_mul(multiplier_bps.checked_add(tier_bonus_bps).ok_or(StreakError::Overflow)?)
+            .ok_or(StreakError::Overflow)?
+            .checked_div(BPS_DENOMINATOR)
+            .ok_or(StreakError::Overflow)?;
+
+        self.points = self.points.checked_add(points).ok_or(StreakError::Overflow)?;
+        Ok(points)
+    }
+
     /// Calculate lifelines earned from referrals
     pub fn calculate_lifelines(&self) -> u8 {
         (self.direct_referrals / 3) as u8
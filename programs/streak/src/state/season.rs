@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use crate::errors::StreakError;
+use crate::state::{GameState, Player};
+
+/// A fixed run of game periods. Deaths during the season build its death pool,
+/// and a share of that pool is split between entrants who survive to the end.
+#[account]
+#[derive(Default)]
+pub struct Season {
+    /// Arena the season belongs to
+    pub game_id: u64,
+
+    /// Season number within the game (1-based)
+    pub season_id: u64,
+
+    /// First game period of the season
+    pub start_period: i64,
+
+    /// Period after the last period of the season (exclusive)
+    pub end_period: i64,
+
+    /// Entry rule: players can join until this period (exclusive)
+    pub entry_deadline_period: i64,
+
+    /// Entry rule: smallest stake that can join
+    pub min_stake: u64,
+
+    /// Share of the death pool paid to survivors (basis points)
+    pub payout_bps: u64,
+
+    /// Pool lamports left by deaths during the season
+    pub death_pool: u64,
+
+    /// Deaths during the season (all players, not just entrants)
+    pub deaths: u64,
+
+    /// Players who joined
+    pub entrants: u64,
+
+    /// Entrants still in the running (winners once finalized)
+    pub survivors: u64,
+
+    /// Sum of survivors' entry stakes (payout weights)
+    pub survivor_stake: u64,
+
+    /// Lamports set aside for survivors at finalization
+    pub payout_pool: u64,
+
+    /// Lamports survivors have claimed so far
+    pub total_claimed: u64,
+
+    /// Whether the season has been finalized
+    pub finalized: bool,
+
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Whether unclaimed payouts have been swept back into the pool
+    pub swept: bool,
+}
+
+impl Season {
+    pub const SIZE: usize = 8 + // discriminator
+        8 + // game_id
+        8 + // season_id
+        8 + // start_period
+        8 + // end_period
+        8 + // entry_deadline_period
+        8 + // min_stake
+        8 + // payout_bps
+        8 + // death_pool
+        8 + // deaths
+        8 + // entrants
+        8 + // survivors
+        8 + // survivor_stake
+        8 + // payout_pool
+        8 + // total_claimed
+        1 + // finalized
+        1 + // bump
+        1 + // swept
+        63; // padding
+
+    /// Whether a game period falls inside the season
+    pub fn is_running(&self, period: i64) -> bool {
+        !self.finalized && period >= self.start_period && period < self.end_period
+    }
+
+    /// Period survivors have to claim by: one season length after the end
+    pub fn claims_close_period(&self) -> i64 {
+        self.end_period + (self.end_period - self.start_period)
+    }
+
+    /// Check the season account passed matches the game's current season
+    /// (required once the game has one)
+    pub fn check_current(season: Option<&Account<Season>>, game_state: &GameState) -> Result<()> {
+        match season {
+            Some(season) => require!(season.season_id == game_state.current_season_id, StreakError::WrongSeason),
+            None => require!(game_state.current_season_id == 0, StreakError::WrongSeason),
+        }
+        Ok(())
+    }
+
+    /// Lower a player's payout weight to their stake after it shrinks, and the
+    /// survivors' total weight with it, until the season they entered is finalized
+    pub fn cap_stake(season: Option<&mut Account<Season>>, player: &mut Player, current_season_id: u64) -> Result<()> {
+        if player.season_id != current_season_id || player.season_stake <= player.stake {
+            return Ok(());
+        }
+
+        let season = season.ok_or(StreakError::WrongSeason)?;
+        require!(season.season_id == player.season_id, StreakError::WrongSeason);
+        if season.finalized {
+            return Ok(());
+        }
+
+        season.survivor_stake = season.survivor_stake
+            .checked_sub(player.season_stake - player.stake)
+            .ok_or(StreakError::Overflow)?;
+        player.season_stake = player.stake;
+        Ok(())
+    }
+
+    /// Drop a player whose streak ended (death or exit) in `period`, before the
+    /// season ended, from its survivors
+    pub fn forfeit(&mut self, player: &mut Player, period: i64) -> Result<()> {
+        if player.season_id != self.season_id || player.season_stake == 0 || period >= self.end_period {
+            return Ok(());
+        }
+
+        self.survivors = self.survivors.checked_sub(1).ok_or(StreakError::Overflow)?;
+        self.survivor_stake = self.survivor_stake
+            .checked_sub(player.season_stake)
+            .ok_or(StreakError::Overflow)?;
+        player.season_stake = 0;
+
+        msg!("Out of season {}", self.season_id);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameState, Player};
+use crate::errors::StreakError;
+
+/// A third-party prize pool split evenly between the eligible players who enter it
+/// before entries close. Entrants claim their share until it expires. Funds are held
+/// as lamports on this account; anything left after expiry goes back to the sponsor.
+#[account]
+#[derive(Default)]
+pub struct SponsorPool {
+    /// Arena whose players can claim
+    pub game_id: u64,
+
+    /// Sponsor-chosen ID
+    pub pool_id: u64,
+
+    /// Wallet that created the pool and receives the remainder
+    pub sponsor: Pubkey,
+
+    /// Lamports paid to each entrant (fixed at the first claim)
+    pub reward_per_player: u64,
+
+    /// Minimum current streak (0 = any)
+    pub min_streak_days: u32,
+
+    /// Minimum current stake (0 = any)
+    pub min_stake: u64,
+
+    /// First game period the streak must cover (with checkin_end_period, 0 = no period rule)
+    pub checkin_start_period: i64,
+
+    /// Period after the last one the streak must cover (exclusive)
+    pub checkin_end_period: i64,
+
+    /// Unix timestamp entries close and claims open at
+    pub entries_close_at: i64,
+
+    /// Unix timestamp claims close at
+    pub expires_at: i64,
+
+    /// Total lamports ever funded
+    pub total_funded: u64,
+
+    /// Total lamports ever claimed
+    pub total_claimed: u64,
+
+    /// Number of eligible players who entered
+    pub entries: u32,
+
+    /// Number of players who claimed
+    pub claims: u32,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl SponsorPool {
+    pub const SIZE: usize = 8 + // discriminator
+        8 + // game_id
+        8 + // pool_id
+        32 + // sponsor
+        8 + // reward_per_player
+        4 + // min_streak_days
+        8 + // min_stake
+        8 + // checkin_start_period
+        8 + // checkin_end_period
+        8 + // entries_close_at
+        8 + // expires_at
+        8 + // total_funded
+        8 + // total_claimed
+        4 + // entries
+        4 + // claims
+        1 + // bump
+        20; // padding
+
+    /// Get the lamports available for rewards (balance above rent-exemption)
+    pub fn available_budget(pool_info: &AccountInfo) -> Result<u64> {
+        let rent_exempt = Rent::get()?.minimum_balance(pool_info.data_len());
+        Ok(pool_info.lamports().saturating_sub(rent_exempt))
+    }
+
+    /// Split the budget between the entrants on the first claim. Funding stops when
+    /// entries close, so every entrant gets the same share.
+    pub fn share(&mut self, pool_info: &AccountInfo) -> Result<u64> {
+        if self.reward_per_player == 0 {
+            self.reward_per_player = Self::available_budget(pool_info)?
+                .checked_div(self.entries as u64)
+                .ok_or(StreakError::Overflow)?;
+        }
+        Ok(self.reward_per_player)
+    }
+
+    /// Check a player against the pool's rules. The streak must be intact right now;
+    /// with a period rule it must also have started by checkin_start_period and
+    /// checked in through the last required period.
+    pub fn is_eligible(&self, player: &Player, game_state: &GameState, current_time: i64) -> bool {
+        let interval = game_state.checkin_interval_seconds;
+        let streak_intact = player.is_active
+            && player.streak_broken_at(current_time, interval, game_state.late_window_seconds).is_none();
+
+        let covers_periods = self.checkin_end_period == 0
+            || (game_state.period_at(player.start_day) <= self.checkin_start_period
+                && game_state.period_at(player.last_checkin) >= self.checkin_end_period - 1);
+
+        streak_intact
+            && covers_periods
+            && player.streak_days >= self.min_streak_days
+            && player.stake >= self.min_stake
+    }
+}
+
+/// Record of a player's entry in a sponsor pool and its claim (one per wallet)
+#[account]
+#[derive(Default)]
+pub struct SponsorClaim {
+    /// Pool entered
+    pub pool: Pubkey,
+
+    /// Entrant wallet
+    pub wallet: Pubkey,
+
+    /// Lamports received (0 until claimed)
+    pub amount: u64,
+
+    /// Unix timestamp of the claim (0 until claimed)
+    pub claimed_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl SponsorClaim {
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // pool
+        32 + // wallet
+        8 + // amount
+        8 + // claimed_at
+        1 + // bump
+        16; // padding
+}
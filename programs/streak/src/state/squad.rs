@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use crate::constants::{MAX_SQUAD_SIZE, MIN_SQUAD_SIZE, BPS_DENOMINATOR};
+use crate::errors::StreakError;
+use crate::state::{GameState, Player};
+
+/// A squad member's check-in and penalty progress
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct SquadMember {
+    /// Member wallet
+    pub wallet: Pubkey,
+
+    /// Latest game period the member checked in (or joined) in
+    pub last_period: i64,
+
+    /// Squad penalties this member has paid (or that predate joining)
+    pub penalties_paid: u32,
+}
+
+impl SquadMember {
+    pub const SIZE: usize = 32 + 8 + 4;
+}
+
+/// A team of 2-10 players sharing a growth bonus while everyone checks in,
+/// and a penalty (or a lifeline from the shared pool) when someone misses
+#[account]
+#[derive(Default)]
+pub struct Squad {
+    /// Arena the squad plays in
+    pub game_id: u64,
+
+    /// Creator-chosen ID, unique within the game
+    pub squad_id: u64,
+
+    /// Member who approves new joiners
+    pub leader: Pubkey,
+
+    /// Current members
+    pub members: Vec<SquadMember>,
+
+    /// Lifelines contributed by members, used before anyone dies
+    pub lifelines: u8,
+
+    /// Deaths that cost the remaining members a penalty
+    pub penalty_count: u32,
+
+    /// Total lamports ever paid in squad penalties
+    pub total_penalties: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Squad {
+    pub const SIZE: usize = 8 + // discriminator
+        8 + // game_id
+        8 + // squad_id
+        32 + // leader
+        4 + SquadMember::SIZE * MAX_SQUAD_SIZE + // members
+        1 + // lifelines
+        4 + // penalty_count
+        8 + // total_penalties
+        1 + // bump
+        32; // padding
+
+    /// Validate that the passed squad is the player's squad (or that they have none)
+    pub fn check_member(squad: Option<&Account<Squad>>, player: &Player) -> Result<()> {
+        match squad {
+            Some(squad) => require!(player.squad == Some(squad.key()), StreakError::WrongSquad),
+            None => require!(player.squad.is_none(), StreakError::WrongSquad),
+        }
+        Ok(())
+    }
+
+    fn member_mut(&mut self, wallet: &Pubkey) -> Result<&mut SquadMember> {
+        self.members
+            .iter_mut()
+            .find(|member| member.wallet == *wallet)
+            .ok_or(StreakError::WrongSquad.into())
+    }
+
+    /// Add a player to the squad, counting as checked in for the current period
+    pub fn join(&mut self, squad_key: Pubkey, player: &mut Player, current_period: i64) -> Result<()> {
+        require!(player.squad.is_none(), StreakError::AlreadyInSquad);
+        require!(self.members.len() < MAX_SQUAD_SIZE, StreakError::SquadFull);
+
+        self.members.push(SquadMember {
+            wallet: player.wallet,
+            last_period: current_period,
+            penalties_paid: self.penalty_count,
+        });
+        player.squad = Some(squad_key);
+        Ok(())
+    }
+
+    /// Remove a player from the squad, handing leadership on if needed
+    pub fn leave(&mut self, player: &mut Player) {
+        self.members.retain(|member| member.wallet != player.wallet);
+        if self.leader == player.wallet {
+            self.leader = self.members.first().map_or(Pubkey::default(), |member| member.wallet);
+        }
+        player.squad = None;
+    }
+
+    /// Check if every member checked in during the previous period
+    pub fn is_intact(&self, current_period: i64) -> bool {
+        self.members.len() >= MIN_SQUAD_SIZE
+            && self.members.iter().all(|member| member.last_period >= current_period - 1)
+    }
+
+    /// Record a member's check-in for the current period
+    pub fn record_checkin(&mut self, wallet: &Pubkey, current_period: i64) -> Result<()> {
+        self.member_mut(wallet)?.last_period = current_period;
+        Ok(())
+    }
+
+    /// Take any squad penalties the member hasn't paid yet out of their stake.
+    /// Returns the lamports moved to the pool.
+    pub fn collect_penalties(&mut self, player: &mut Player, game_state: &GameState) -> Result<u64> {
+        let penalty_count = self.penalty_count;
+        let member = self.member_mut(&player.wallet)?;
+        let owed = penalty_count.saturating_sub(member.penalties_paid);
+        member.penalties_paid = penalty_count;
+
+        let mut penalty: u64 = 0;
+        for _ in 0..owed {
+            penalty = penalty
+                .checked_add(
+                    player.stake
+                        .checked_sub(penalty)
+                        .ok_or(StreakError::Overflow)?
+                        .checked_mul(game_state.squad_penalty_bps)
+                        .ok_or(StreakError::Overflow)?
+                        .checked_div(BPS_DENOMINATOR)
+                        .ok_or(StreakError::Overflow)?,
+                )
+                .ok_or(StreakError::Overflow)?;
+        }
+
+        player.stake = player.stake.checked_sub(penalty).ok_or(StreakError::Overflow)?;
+        self.total_penalties = self.total_penalties.checked_add(penalty).ok_or(StreakError::Overflow)?;
+        Ok(penalty)
+    }
+
+    /// Record a member's death: they leave and everyone left owes a penalty
+    pub fn record_death(&mut self, player: &mut Player) -> Result<()> {
+        self.leave(player);
+        self.penalty_count = self.penalty_count.checked_add(1).ok_or(StreakError::Overflow)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,163 @@
+use anchor_lang::prelude::*;
+use crate::errors::StreakError;
+use crate::state::GameState;
+
+/// A last-one-standing tournament on a game's check-in schedule.
+/// Entry fees are held as lamports on this account and split between the winners.
+#[account]
+#[derive(Default)]
+pub struct Tournament {
+    /// Arena whose check-in interval the tournament follows
+    pub game_id: u64,
+
+    /// Authority-chosen ID
+    pub tournament_id: u64,
+
+    /// Fixed entry fee in lamports
+    pub entry_fee: u64,
+
+    /// Registration is open until this game period (exclusive)
+    pub registration_end_period: i64,
+
+    /// Number of survivors that split the pot (the tournament ends at or below this)
+    pub max_winners: u32,
+
+    /// Players who entered
+    pub entrants: u32,
+
+    /// Entries not yet eliminated
+    pub alive: u32,
+
+    /// Entry fees of eliminated players
+    pub pot: u64,
+
+    /// Latest period anyone was eliminated in
+    pub last_death_period: i64,
+
+    /// Eliminations in that period (they share the pot if nobody survives)
+    pub last_period_deaths: u32,
+
+    /// Whether winners have been snapshotted
+    pub finished: bool,
+
+    /// Game period the tournament finished in
+    pub finish_period: i64,
+
+    /// Entries alive at the finish (0 = the last period's eliminations tied)
+    pub survivors_at_finish: u32,
+
+    /// Number of winners splitting the pot
+    pub winner_count: u32,
+
+    /// Lamports paid to each winner
+    pub prize_per_winner: u64,
+
+    /// Winners who have claimed
+    pub prizes_claimed: u32,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Tournament {
+    pub const SIZE: usize = 8 + // discriminator
+        8 + // game_id
+        8 + // tournament_id
+        8 + // entry_fee
+        8 + // registration_end_period
+        4 + // max_winners
+        4 + // entrants
+        4 + // alive
+        8 + // pot
+        8 + // last_death_period
+        4 + // last_period_deaths
+        1 + // finished
+        8 + // finish_period
+        4 + // survivors_at_finish
+        4 + // winner_count
+        8 + // prize_per_winner
+        4 + // prizes_claimed
+        1 + // bump
+        64; // padding
+
+    /// Knock out an entry that missed a check-in; its entry fee joins the pot
+    pub fn eliminate(&mut self, entry: &mut TournamentEntry, game_state: &GameState) -> Result<()> {
+        entry.is_alive = false;
+        entry.death_period = entry.first_missed_period(game_state);
+
+        self.alive = self.alive.checked_sub(1).ok_or(StreakError::Overflow)?;
+        self.pot = self.pot.checked_add(self.entry_fee).ok_or(StreakError::Overflow)?;
+        self.record_death(entry.death_period)
+    }
+
+    /// Record an elimination, tracking the latest elimination period for tie-breaks
+    pub fn record_death(&mut self, death_period: i64) -> Result<()> {
+        if death_period > self.last_death_period {
+            self.last_death_period = death_period;
+            self.last_period_deaths = 1;
+        } else if death_period == self.last_death_period {
+            self.last_period_deaths = self.last_period_deaths.checked_add(1).ok_or(StreakError::Overflow)?;
+        }
+        Ok(())
+    }
+}
+
+/// A player's single entry in a tournament (no re-entry after elimination)
+#[account]
+#[derive(Default)]
+pub struct TournamentEntry {
+    /// Tournament entered
+    pub tournament: Pubkey,
+
+    /// Entrant wallet
+    pub wallet: Pubkey,
+
+    /// Start of the entry's first period (as a timestamp)
+    pub start_day: i64,
+
+    /// Unix timestamp of last check-in
+    pub last_checkin: i64,
+
+    /// Current streak in periods
+    pub streak_days: u32,
+
+    /// Whether the entry is still in the tournament
+    pub is_alive: bool,
+
+    /// First period the entry missed (set on elimination)
+    pub death_period: i64,
+
+    /// Whether the entry's prize has been claimed
+    pub claimed: bool,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl TournamentEntry {
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // tournament
+        32 + // wallet
+        8 + // start_day
+        8 + // last_checkin
+        4 + // streak_days
+        1 + // is_alive
+        8 + // death_period
+        1 + // claimed
+        1 + // bump
+        32; // padding
+
+    /// Check if the entry missed the check-in for the previous period
+    /// (the same rule players follow, on UTC periods)
+    pub fn has_missed_checkin(&self, current_period: i64, game_state: &GameState) -> bool {
+        let last_checkin_period = game_state.period_at(self.last_checkin);
+        let start_period = game_state.period_at(self.start_day);
+
+        last_checkin_period < current_period - 1 && start_period < current_period
+    }
+
+    /// First period the entry failed to check in
+    pub fn first_missed_period(&self, game_state: &GameState) -> i64 {
+        (game_state.period_at(self.last_checkin) + 1).max(game_state.period_at(self.start_day))
+    }
+}
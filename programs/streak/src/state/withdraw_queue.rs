@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::constants::MAX_QUEUE_ENTRIES;
+use crate::errors::StreakError;
+
+/// A withdrawal the vault could not fully cover
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct QueueEntry {
+    /// Wallet owed the remainder
+    pub wallet: Pubkey,
+
+    /// Lamports still owed
+    pub amount_owed: u64,
+}
+
+#[account]
+#[derive(Default)]
+pub struct WithdrawQueue {
+    /// Unpaid withdrawals, oldest first
+    pub entries: Vec<QueueEntry>,
+
+    /// Total lamports owed across all entries
+    pub total_owed: u64,
+
+    /// Total lamports ever paid out through the queue
+    pub total_paid: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl WithdrawQueue {
+    pub const SIZE: usize = 8 + // discriminator
+        4 + MAX_QUEUE_ENTRIES * (32 + 8) + // entries
+        8 + // total_owed
+        8 + // total_paid
+        1 + // bump
+        64; // padding
+
+    /// Check if another entry fits in the queue
+    pub fn has_room(&self) -> bool {
+        self.entries.len() < MAX_QUEUE_ENTRIES
+    }
+
+    /// Add an unpaid remainder to the back of the queue
+    pub fn enqueue(&mut self, wallet: Pubkey, amount: u64) -> Result<()> {
+        require!(self.entries.len() < MAX_QUEUE_ENTRIES, StreakError::WithdrawQueueFull);
+
+        self.entries.push(QueueEntry { wallet, amount_owed: amount });
+        self.total_owed = self.total_owed.checked_add(amount).ok_or(StreakError::Overflow)?;
+
+        Ok(())
+    }
+}
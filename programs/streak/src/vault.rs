@@ -0,0 +1,158 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::constants::GAME_STATE_SEED;
+use crate::errors::StreakError;
+use crate::state::GameState;
+
+/// Where a game keeps its staked funds.
+/// SOL games hold lamports on the game state PDA; token games hold tokens
+/// in a vault token account owned by the game state PDA.
+pub enum Vault<'a, 'info> {
+    Sol,
+    Token {
+        stake_vault: &'a Account<'info, TokenAccount>,
+        token_program: &'a Program<'info, Token>,
+        game_seed: Vec<u8>,
+        game_state_bump: u8,
+    },
+}
+
+impl<'a, 'info> Vault<'a, 'info> {
+    /// Pick the vault for a game, checking token games were passed their token accounts
+    pub fn load(
+        game_state: &GameState,
+        stake_vault: Option<&'a Account<'info, TokenAccount>>,
+        token_program: Option<&'a Program<'info, Token>>,
+    ) -> Result<Self> {
+        if !game_state.is_token_game() {
+            return Ok(Vault::Sol);
+        }
+
+        match (stake_vault, token_program) {
+            (Some(stake_vault), Some(token_program)) => {
+                require!(stake_vault.mint == game_state.stake_mint, StreakError::InvalidTokenAccount);
+                Ok(Vault::Token {
+                    stake_vault,
+                    token_program,
+                    game_seed: game_state.game_seed(),
+                    game_state_bump: game_state.bump,
+                })
+            }
+            _ => Err(StreakError::MissingTokenAccounts.into()),
+        }
+    }
+
+    /// Funds that can be paid out (SOL above rent-exemption, or the vault's token balance)
+    pub fn available(&self, game_state_info: &AccountInfo<'info>) -> Result<u64> {
+        match self {
+            Vault::Sol => GameState::available_lamports(game_state_info),
+            Vault::Token { stake_vault, .. } => Ok(stake_vault.amount),
+        }
+    }
+
+    /// Account a payout to `wallet` goes to: the wallet itself, or its token account
+    pub fn recipient(
+        &self,
+        wallet: &AccountInfo<'info>,
+        token_account: Option<&Account<'info, TokenAccount>>,
+    ) -> Result<AccountInfo<'info>> {
+        match self {
+            Vault::Sol => Ok(wallet.clone()),
+            Vault::Token { .. } => Ok(token_account
+                .ok_or(StreakError::MissingTokenAccounts)?
+                .to_account_info()),
+        }
+    }
+
+    /// Move a deposit from the user into the vault
+    pub fn deposit(
+        &self,
+        game_state_info: &AccountInfo<'info>,
+        user: &AccountInfo<'info>,
+        user_token_account: Option<&Account<'info, TokenAccount>>,
+        system_program: &AccountInfo<'info>,
+        amount: u64,
+    ) -> Result<()> {
+        match self {
+            Vault::Sol => {
+                require!(user.lamports() >= amount, StreakError::InsufficientFunds);
+
+                let cpi_context = CpiContext::new(
+                    system_program.clone(),
+                    system_program::Transfer {
+                        from: user.clone(),
+                        to: game_state_info.clone(),
+                    },
+                );
+                system_program::transfer(cpi_context, amount)
+            }
+            Vault::Token { stake_vault, token_program, .. } => {
+                let user_token_account = user_token_account.ok_or(StreakError::MissingTokenAccounts)?;
+                require!(user_token_account.owner == user.key(), StreakError::InvalidTokenAccount);
+                require!(user_token_account.amount >= amount, StreakError::InsufficientFunds);
+
+                let cpi_context = CpiContext::new(
+                    token_program.to_account_info(),
+                    Transfer {
+                        from: user_token_account.to_account_info(),
+                        to: stake_vault.to_account_info(),
+                        authority: user.clone(),
+                    },
+                );
+                token::transfer(cpi_context, amount)
+            }
+        }
+    }
+
+    /// Pay out of the vault. For SOL games `recipient` is the wallet itself
+    /// (checked by the caller); for token games it is a token account owned by `wallet`.
+    pub fn pay_out(
+        &self,
+        game_state_info: &AccountInfo<'info>,
+        recipient: &AccountInfo<'info>,
+        wallet: Pubkey,
+        amount: u64,
+    ) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        match self {
+            Vault::Sol => {
+                **game_state_info.try_borrow_mut_lamports()? = game_state_info
+                    .lamports()
+                    .checked_sub(amount)
+                    .ok_or(StreakError::InsufficientFunds)?;
+
+                **recipient.try_borrow_mut_lamports()? = recipient
+                    .lamports()
+                    .checked_add(amount)
+                    .ok_or(StreakError::Overflow)?;
+
+                Ok(())
+            }
+            Vault::Token { stake_vault, token_program, game_seed, game_state_bump } => {
+                require!(recipient.owner == &token::ID, StreakError::InvalidTokenAccount);
+                let recipient_account = TokenAccount::try_deserialize(&mut &recipient.try_borrow_data()?[..])
+                    .map_err(|_| StreakError::InvalidTokenAccount)?;
+                require!(
+                    recipient_account.owner == wallet && recipient_account.mint == stake_vault.mint,
+                    StreakError::InvalidTokenAccount
+                );
+
+                let signer_seeds: &[&[&[u8]]] = &[&[GAME_STATE_SEED, game_seed, &[*game_state_bump]]];
+                let cpi_context = CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    Transfer {
+                        from: stake_vault.to_account_info(),
+                        to: recipient.clone(),
+                        authority: game_state_info.clone(),
+                    },
+                    signer_seeds,
+                );
+                token::transfer(cpi_context, amount)
+            }
+        }
+    }
+}
@@ -1,7 +1,8 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
-import { PublicKey, SystemProgram, LAMPORTS_PER_SOL, Keypair } from "@solana/web3.js";
+import { PublicKey, SystemProgram, LAMPORTS_PER_SOL, Keypair, SYSVAR_SLOT_HASHES_PUBKEY } from "@solana/web3.js";
 import { assert } from "chai";
+import { createMint, getAccount, getOrCreateAssociatedTokenAccount, TOKEN_PROGRAM_ID } from "@solana/spl-token";
 
 // IDL types
 interface GameState {
@@ -15,6 +16,11 @@ interface GameState {
   bonusWindowEnd: anchor.BN;
   checkinIntervalSeconds: anchor.BN;
   bump: number;
+  lateWindowSeconds: anchor.BN;
+  latePenaltyBps: anchor.BN;
+  pointsPerCheckin: anchor.BN;
+  pointsMultiplierBps: anchor.BN;
+  pointsSnapshotId: anchor.BN;
 }
 
 interface Player {
@@ -33,10 +39,23 @@ interface Player {
   totalBonusClaims: number;
   referralEarnings: anchor.BN;
   bump: number;
+  deadlineOffset: anchor.BN;
+  growthStreakDays: number;
+  commitmentDays: number;
+  commitmentCompleted: boolean;
+  sponsor: PublicKey | null;
+  withdrawToSponsor: boolean;
+  sponsorRefundBps: anchor.BN;
+  points: anchor.BN;
+  pointsSnapshotId: anchor.BN;
+  snapshotPoints: anchor.BN;
 }
 
 const GAME_STATE_SEED = Buffer.from("game_state");
 const PLAYER_SEED = Buffer.from("player");
+const WITHDRAW_QUEUE_SEED = Buffer.from("withdraw_queue");
+const COHORT_SEED = Buffer.from("cohort");
+const COHORT_MEMBER_SEED = Buffer.from("cohort_member");
 const MIN_STAKE = 0.05 * LAMPORTS_PER_SOL; // 0.05 SOL
 
 describe("streak", () => {
@@ -56,6 +75,7 @@ describe("streak", () => {
   // PDAs
   let gameStatePDA: PublicKey;
   let gameStateBump: number;
+  let withdrawQueuePDA: PublicKey;
 
   before(async () => {
     // Create test keypairs
@@ -68,6 +88,10 @@ describe("streak", () => {
       [GAME_STATE_SEED],
       program.programId
     );
+    [withdrawQueuePDA] = PublicKey.findProgramAddressSync(
+      [WITHDRAW_QUEUE_SEED],
+      program.programId
+    );
 
     // Airdrop SOL to test accounts
     const airdropAmount = 10 * LAMPORTS_PER_SOL;
@@ -95,7 +119,9 @@ describe("streak", () => {
   async function buildStakeInstruction(
     user: Keypair,
     amount: anchor.BN,
-    referrer?: PublicKey
+    referrer?: PublicKey,
+    deadlineOffset: number = 0,
+    commitmentDays: number = 0
   ): Promise<anchor.web3.TransactionInstruction> {
     const [playerPDA] = getPlayerPDA(user.publicKey);
 
@@ -118,7 +144,15 @@ describe("streak", () => {
       referrerBuffer = Buffer.from([0]);
     }
 
-    const data = Buffer.concat([discriminator, amountBuffer, referrerBuffer]);
+    // Encode deadline offset (i64 LE)
+    const offsetBuffer = Buffer.alloc(8);
+    offsetBuffer.writeBigInt64LE(BigInt(deadlineOffset));
+
+    // Encode commitment target (u32 LE)
+    const commitmentBuffer = Buffer.alloc(4);
+    commitmentBuffer.writeUInt32LE(commitmentDays);
+
+    const data = Buffer.concat([discriminator, amountBuffer, referrerBuffer, offsetBuffer, commitmentBuffer]);
 
     const keys = [
       { pubkey: gameStatePDA, isSigner: false, isWritable: true },
@@ -136,9 +170,15 @@ describe("streak", () => {
   }
 
   // Helper to stake SOL
-  async function stake(user: Keypair, amount: number, referrer?: PublicKey): Promise<string> {
+  async function stake(
+    user: Keypair,
+    amount: number,
+    referrer?: PublicKey,
+    deadlineOffset: number = 0,
+    commitmentDays: number = 0
+  ): Promise<string> {
     const amountLamports = new anchor.BN(Math.floor(amount * LAMPORTS_PER_SOL));
-    const ix = await buildStakeInstruction(user, amountLamports, referrer);
+    const ix = await buildStakeInstruction(user, amountLamports, referrer, deadlineOffset, commitmentDays);
 
     const tx = new anchor.web3.Transaction().add(ix);
     tx.feePayer = user.publicKey;
@@ -148,6 +188,150 @@ describe("streak", () => {
     return await provider.connection.sendRawTransaction(tx.serialize());
   }
 
+  // Flow tests run in their own arenas with a short interval, so periods pass
+  // in seconds and each flow only needs the accounts of the features it enables
+  const FLOW_INTERVAL = 10;
+
+  interface FlowArena {
+    id: anchor.BN;
+    gameState: PublicKey;
+    treasury: PublicKey;
+  }
+
+  function getArenaPlayerPDA(arena: FlowArena, wallet: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [PLAYER_SEED, arena.id.toArrayLike(Buffer, "le", 8), wallet.toBuffer()],
+      program.programId
+    )[0];
+  }
+
+  function getArenaPDA(arena: FlowArena, seed: string, ...extra: Buffer[]): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from(seed), arena.id.toArrayLike(Buffer, "le", 8), ...extra],
+      program.programId
+    )[0];
+  }
+
+  // Create an arena run by the provider wallet with a FLOW_INTERVAL check-in interval
+  async function createFlowArena(): Promise<FlowArena> {
+    const id = new anchor.BN(Date.now());
+    const treasury = Keypair.generate().publicKey;
+    const [gameState] = PublicKey.findProgramAddressSync(
+      [GAME_STATE_SEED, id.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+
+    await program.methods
+      .createArena(id)
+      .accounts({
+        gameState,
+        treasury,
+        authority: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    await program.methods
+      .setCheckinInterval(new anchor.BN(FLOW_INTERVAL))
+      .accounts({ gameState, authority: authority.publicKey })
+      .rpc();
+
+    return { id, gameState, treasury };
+  }
+
+  async function fundedPlayers(count: number): Promise<Keypair[]> {
+    const players = Array.from({ length: count }, () => Keypair.generate());
+    for (const player of players) {
+      const sig = await provider.connection.requestAirdrop(player.publicKey, 2 * LAMPORTS_PER_SOL);
+      await provider.connection.confirmTransaction(sig, "confirmed");
+    }
+    return players;
+  }
+
+  // Cluster time, which is what the program compares periods against
+  async function chainTime(): Promise<number> {
+    const slot = await provider.connection.getSlot();
+    return (await provider.connection.getBlockTime(slot)) ?? Math.floor(Date.now() / 1000);
+  }
+
+  // Wait until the cluster clock enters the next flow period
+  async function waitForNextPeriod(): Promise<void> {
+    const period = Math.floor((await chainTime()) / FLOW_INTERVAL);
+    while (Math.floor((await chainTime()) / FLOW_INTERVAL) <= period) {
+      await new Promise(resolve => setTimeout(resolve, 1000));
+    }
+  }
+
+  async function stakeInArena(arena: FlowArena, user: Keypair, lamports: number, league?: PublicKey): Promise<void> {
+    await program.methods
+      .stake(new anchor.BN(lamports), null, new anchor.BN(0), 0)
+      .accounts({
+        gameState: arena.gameState,
+        player: getArenaPlayerPDA(arena, user.publicKey),
+        user: user.publicKey,
+        systemProgram: SystemProgram.programId,
+        league: league ?? null,
+      })
+      .signers([user])
+      .rpc();
+  }
+
+  async function checkinInArena(
+    arena: FlowArena,
+    user: Keypair,
+    extra: { league?: PublicKey; squad?: PublicKey; jackpot?: PublicKey } = {}
+  ): Promise<void> {
+    await program.methods
+      .checkin()
+      .accounts({
+        gameState: arena.gameState,
+        player: getArenaPlayerPDA(arena, user.publicKey),
+        user: user.publicKey,
+        league: extra.league ?? null,
+        squad: extra.squad ?? null,
+        jackpot: extra.jackpot ?? null,
+      })
+      .signers([user])
+      .rpc();
+  }
+
+  async function processDeathInArena(
+    arena: FlowArena,
+    wallet: PublicKey,
+    extra: { league?: PublicKey; season?: PublicKey; squad?: PublicKey; jackpot?: PublicKey } = {},
+    remainingAccounts: PublicKey[] = []
+  ): Promise<void> {
+    await program.methods
+      .processDeath()
+      .accounts({
+        gameState: arena.gameState,
+        treasury: arena.treasury,
+        deadPlayer: getArenaPlayerPDA(arena, wallet),
+        systemProgram: SystemProgram.programId,
+        league: extra.league ?? null,
+        season: extra.season ?? null,
+        squad: extra.squad ?? null,
+        jackpot: extra.jackpot ?? null,
+      })
+      .remainingAccounts(remainingAccounts.map(pubkey => ({ pubkey, isSigner: false, isWritable: true })))
+      .rpc();
+  }
+
+  async function createLeague(flowArena: FlowArena, leagueId: number, min: number, max: number): Promise<PublicKey> {
+    const league = getArenaPDA(flowArena, "league", Buffer.from([leagueId]));
+    const previousLeague = leagueId > 1 ? getArenaPDA(flowArena, "league", Buffer.from([leagueId - 1])) : null;
+    await program.methods
+      .createLeague(new anchor.BN(min), new anchor.BN(max))
+      .accounts({
+        gameState: flowArena.gameState,
+        league,
+        previousLeague,
+        authority: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    return league;
+  }
+
   describe("Initialize", () => {
     it("Initializes the game state", async () => {
       try {
@@ -172,6 +356,26 @@ describe("streak", () => {
       assert.isTrue(gameState.treasury.equals(treasury.publicKey));
     });
 
+    it("Initializes the withdrawal queue", async () => {
+      try {
+        await program.account.withdrawQueue.fetch(withdrawQueuePDA);
+        console.log("Withdrawal queue already initialized, skipping...");
+      } catch {
+        await program.methods
+          .initializeWithdrawQueue()
+          .accounts({
+            gameState: gameStatePDA,
+            withdrawQueue: withdrawQueuePDA,
+            authority: authority.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+      }
+
+      const queue = await program.account.withdrawQueue.fetch(withdrawQueuePDA);
+      assert.isArray(queue.entries);
+    });
+
     it("Sets a short check-in interval for testing (60 seconds)", async () => {
       try {
         await program.methods
@@ -191,6 +395,65 @@ describe("streak", () => {
     });
   });
 
+  describe("Late check-in config", () => {
+    it("Authority can configure the late window and penalty", async () => {
+      await program.methods
+        .setLateCheckinConfig(new anchor.BN(20), new anchor.BN(500))
+        .accounts({
+          gameState: gameStatePDA,
+          authority: authority.publicKey,
+        })
+        .rpc();
+
+      const gameState = await program.account.gameState.fetch(gameStatePDA) as unknown as GameState;
+      assert.equal(gameState.lateWindowSeconds.toNumber(), 20);
+      assert.equal(gameState.latePenaltyBps.toNumber(), 500);
+    });
+
+    it("Rejects a late window longer than the check-in interval", async () => {
+      try {
+        await program.methods
+          .setLateCheckinConfig(new anchor.BN(3600), new anchor.BN(500))
+          .accounts({
+            gameState: gameStatePDA,
+            authority: authority.publicKey,
+          })
+          .rpc();
+        assert.fail("Should have thrown InvalidLateWindow error");
+      } catch (e: unknown) {
+        assert.include((e as Error).message, "InvalidLateWindow");
+      }
+    });
+
+    it("A late check-in charges the penalty and resets the growth multiplier", async () => {
+      const arena = await createFlowArena();
+      const [erin] = await fundedPlayers(1);
+      const erinPDA = getArenaPlayerPDA(arena, erin.publicKey);
+      await program.methods
+        .setLateCheckinConfig(new anchor.BN(8), new anchor.BN(500))
+        .accounts({ gameState: arena.gameState, authority: authority.publicKey })
+        .rpc();
+
+      await stakeInArena(arena, erin, 0.1 * LAMPORTS_PER_SOL);
+      await waitForNextPeriod();
+      await checkinInArena(arena, erin);
+      let player = await program.account.player.fetch(erinPDA);
+      assert.equal(player.growthStreakDays as number, 2);
+
+      // Erin misses a period and checks in inside the late window
+      await waitForNextPeriod();
+      await waitForNextPeriod();
+      const stakeBefore = (player.stake as anchor.BN).toNumber();
+      await checkinInArena(arena, erin);
+
+      player = await program.account.player.fetch(erinPDA);
+      assert.isTrue(player.isActive as boolean);
+      assert.equal(player.streakDays as number, 3);
+      assert.equal(player.growthStreakDays as number, 1);
+      assert.equal((player.stake as anchor.BN).toNumber(), stakeBefore - Math.floor(stakeBefore * 500 / 10_000));
+    });
+  });
+
   describe("Stake", () => {
     it("Player 1 can stake SOL", async () => {
       const sig = await stake(player1, 0.1);
@@ -204,6 +467,7 @@ describe("streak", () => {
       assert.equal(player.streakDays, 1);
       assert.isTrue(player.isActive);
       assert.isNull(player.referrer);
+      assert.equal(player.deadlineOffset.toNumber(), 0);
     });
 
     it("Player 2 can stake with Player 1 as referrer", async () => {
@@ -234,6 +498,60 @@ describe("streak", () => {
       }
     });
 
+    it("Player 3 can stake with a deadline offset", async () => {
+      const offset = 9 * 3600; // Deadline 9 hours after UTC midnight
+      const sig = await stake(player3, 0.1, undefined, offset);
+      await provider.connection.confirmTransaction(sig, "confirmed");
+
+      const [playerPDA] = getPlayerPDA(player3.publicKey);
+      const player = await program.account.player.fetch(playerPDA) as unknown as Player;
+
+      assert.isTrue(player.isActive);
+      assert.equal(player.deadlineOffset.toNumber(), offset);
+    });
+
+    it("Cannot stake with an out-of-range deadline offset", async () => {
+      const newPlayer = Keypair.generate();
+      await provider.connection.requestAirdrop(newPlayer.publicKey, 2 * LAMPORTS_PER_SOL);
+      await new Promise(resolve => setTimeout(resolve, 2000));
+
+      try {
+        await stake(newPlayer, 0.1, undefined, 86_400);
+        assert.fail("Should have thrown InvalidDeadlineOffset error");
+      } catch (e: unknown) {
+        const error = e as Error;
+        assert.include(error.message, "InvalidDeadlineOffset");
+      }
+    });
+
+    it("Player can stake with a 30 day commitment", async () => {
+      const committed = Keypair.generate();
+      await provider.connection.requestAirdrop(committed.publicKey, 2 * LAMPORTS_PER_SOL);
+      await new Promise(resolve => setTimeout(resolve, 2000));
+
+      const sig = await stake(committed, 0.1, undefined, 0, 30);
+      await provider.connection.confirmTransaction(sig, "confirmed");
+
+      const [playerPDA] = getPlayerPDA(committed.publicKey);
+      const player = await program.account.player.fetch(playerPDA) as unknown as Player;
+
+      assert.equal(player.commitmentDays, 30);
+      assert.isFalse(player.commitmentCompleted);
+    });
+
+    it("Cannot stake with a commitment shorter than a week", async () => {
+      const newPlayer = Keypair.generate();
+      await provider.connection.requestAirdrop(newPlayer.publicKey, 2 * LAMPORTS_PER_SOL);
+      await new Promise(resolve => setTimeout(resolve, 2000));
+
+      try {
+        await stake(newPlayer, 0.1, undefined, 0, 3);
+        assert.fail("Should have thrown InvalidCommitment error");
+      } catch (e: unknown) {
+        assert.include((e as Error).message, "InvalidCommitment");
+      }
+    });
+
     it("Cannot stake twice while active", async () => {
       try {
         await stake(player1, 0.1);
@@ -266,141 +584,2365 @@ describe("streak", () => {
       assert.isTrue(player.isActive);
       // Streak may increase depending on timing
     });
-  });
-
-  describe("Re-stake after death (BUG FIX TEST)", () => {
-    let deadPlayer: Keypair;
-
-    before(async () => {
-      // Create a new player that we will let die
-      deadPlayer = Keypair.generate();
-      await provider.connection.requestAirdrop(deadPlayer.publicKey, 5 * LAMPORTS_PER_SOL);
-      await new Promise(resolve => setTimeout(resolve, 2000));
-    });
 
-    it("Dead player stakes initially", async () => {
-      const sig = await stake(deadPlayer, 0.1);
-      await provider.connection.confirmTransaction(sig, "confirmed");
+    it("A player only dies after a whole period without a check-in", async () => {
+      const arena = await createFlowArena();
+      const [dave] = await fundedPlayers(1);
+      await stakeInArena(arena, dave, 0.1 * LAMPORTS_PER_SOL);
+      await waitForNextPeriod();
+      await checkinInArena(arena, dave);
 
-      const [playerPDA] = getPlayerPDA(deadPlayer.publicKey);
-      const player = await program.account.player.fetch(playerPDA) as unknown as Player;
+      // Dave hasn't checked in this period yet, but it's still open to them
+      await waitForNextPeriod();
+      try {
+        await processDeathInArena(arena, dave.publicKey);
+        assert.fail("Should have thrown error");
+      } catch (err: any) {
+        assert.include(err.toString(), "PlayerNotDead");
+      }
 
-      assert.isTrue(player.isActive);
-      assert.equal(player.stake.toNumber(), 0.1 * LAMPORTS_PER_SOL);
+      // Once that period has passed without a check-in, Dave can be processed
+      await waitForNextPeriod();
+      await processDeathInArena(arena, dave.publicKey);
+      const player = await program.account.player.fetch(getArenaPlayerPDA(arena, dave.publicKey));
+      assert.isFalse(player.isActive as boolean);
     });
 
-    it("Wait for check-in period to pass (simulating death)", async () => {
-      // Note: In a real test environment, we would use a local validator
-      // and warp time forward. For devnet testing, we wait.
-      console.log("In a real test, the player would miss the check-in period...");
-      // For now, we'll manually process death or mark as inactive
+    it("Growth tiers raise growth from their streak length and pay the milestone once", async () => {
+      const arena = await createFlowArena();
+      // From day 3: +1.1% a day instead of 0.1%, and 10% of stake on reaching it
+      await program.methods
+        .setGrowthTiers([{ minStreakDays: 3, extraGrowthBps: new anchor.BN(100), milestoneRewardBps: new anchor.BN(1_000) }])
+        .accounts({ gameState: arena.gameState, authority: authority.publicKey })
+        .rpc();
+      const [olga] = await fundedPlayers(1);
+      const olgaPDA = getArenaPlayerPDA(arena, olga.publicKey);
+      await stakeInArena(arena, olga, 0.1 * LAMPORTS_PER_SOL);
+
+      const grow = (stake: number, bps: number) => stake + Math.floor(stake * bps / 10_000);
+      let expected = 0.1 * LAMPORTS_PER_SOL;
+      let milestoneReward = 0;
+      for (const bps of [10, 10, 110]) {
+        await waitForNextPeriod();
+        await checkinInArena(arena, olga);
+        expected = grow(expected, bps);
+
+        const player = await program.account.player.fetch(olgaPDA);
+        assert.equal((player.stake as anchor.BN).toNumber(), expected);
+
+        // The milestone pays when the streak reaches day 3, and only then
+        if (player.streakDays as number === 3) {
+          milestoneReward = Math.floor(expected * 1_000 / 10_000);
+        }
+        assert.equal((player.pendingRewards as anchor.BN).toNumber(), milestoneReward);
+      }
+      assert.isAbove(milestoneReward, 0);
     });
+  });
 
-    it("Process death on the dead player", async () => {
-      // For testing purposes, let's manually manipulate or wait
-      // In a real scenario with short intervals, we would wait
-
-      // First, let's check if we can process death
-      const [playerPDA] = getPlayerPDA(deadPlayer.publicKey);
-
-      try {
-        // Build process_death instruction manually
-        const discriminator = Buffer.from([114, 251, 43, 80, 207, 177, 198, 62]);
-
-        const keys = [
-          { pubkey: gameStatePDA, isSigner: false, isWritable: true },
-          { pubkey: treasury.publicKey, isSigner: false, isWritable: true },
-          { pubkey: playerPDA, isSigner: false, isWritable: true },
-          { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
-        ];
+  describe("Freezes", () => {
+    function scheduleFreeze(arena: FlowArena, user: Keypair, startPeriod: number, periods: number) {
+      return program.methods
+        .scheduleFreeze(new anchor.BN(startPeriod), periods, false)
+        .accounts({
+          gameState: arena.gameState,
+          player: getArenaPlayerPDA(arena, user.publicKey),
+          user: user.publicKey,
+        })
+        .signers([user])
+        .rpc();
+    }
 
-        const ix = new anchor.web3.TransactionInstruction({
-          keys,
-          programId: program.programId,
-          data: discriminator,
-        });
+    it("Charges the fee upfront and covers the frozen periods", async () => {
+      const arena = await createFlowArena();
+      const [erin] = await fundedPlayers(1);
+      const erinPDA = getArenaPlayerPDA(arena, erin.publicKey);
+      await stakeInArena(arena, erin, 0.1 * LAMPORTS_PER_SOL);
 
-        const tx = new anchor.web3.Transaction().add(ix);
-        tx.feePayer = authority.publicKey;
-        tx.recentBlockhash = (await provider.connection.getLatestBlockhash()).blockhash;
+      const period = Math.floor((await chainTime()) / FLOW_INTERVAL);
+      await scheduleFreeze(arena, erin, period + 1, 2);
 
-        await provider.sendAndConfirm(tx);
+      // 0.2% of stake per frozen period
+      let player = await program.account.player.fetch(erinPDA);
+      assert.equal((player.stake as anchor.BN).toNumber(), 0.1 * LAMPORTS_PER_SOL * (10_000 - 40) / 10_000);
+      assert.equal((player.freezeEndPeriod as anchor.BN).toNumber(), period + 3);
 
-        const player = await program.account.player.fetch(playerPDA) as unknown as Player;
-        assert.isFalse(player.isActive, "Player should be inactive after death");
-      } catch (e: unknown) {
-        // Player might not be dead yet (depends on timing)
-        console.log("Process death not applicable yet:", (e as Error).message);
+      // Two periods go by without a check-in, and Erin is still alive after them
+      await waitForNextPeriod();
+      await waitForNextPeriod();
+      await waitForNextPeriod();
+      try {
+        await processDeathInArena(arena, erin.publicKey);
+        assert.fail("Should have thrown error");
+      } catch (err: any) {
+        assert.include(err.toString(), "PlayerNotDead");
       }
+      await checkinInArena(arena, erin);
+
+      player = await program.account.player.fetch(erinPDA);
+      assert.isTrue(player.isActive as boolean);
+      assert.equal(player.streakDays as number, 2);
     });
 
-    it("Dead player (or inactive player) CAN RE-STAKE - THIS IS THE BUG FIX", async () => {
-      const [playerPDA] = getPlayerPDA(deadPlayer.publicKey);
+    it("Rejects freezes scheduled too far ahead", async () => {
+      const arena = await createFlowArena();
+      const [frank] = await fundedPlayers(1);
+      await stakeInArena(arena, frank, 0.1 * LAMPORTS_PER_SOL);
 
-      // First, let's check current state
-      let playerBefore: Player;
+      const period = Math.floor((await chainTime()) / FLOW_INTERVAL);
       try {
-        playerBefore = await program.account.player.fetch(playerPDA) as unknown as Player;
-        console.log("Player state before re-stake:", {
-          isActive: playerBefore.isActive,
-          stake: playerBefore.stake.toNumber() / LAMPORTS_PER_SOL,
-          wallet: playerBefore.wallet.toBase58().slice(0, 8) + "...",
-        });
-      } catch {
-        console.log("Player account doesn't exist yet");
+        await scheduleFreeze(arena, frank, period + 31, 1);
+        assert.fail("Should have thrown error");
+      } catch (err: any) {
+        assert.include(err.toString(), "InvalidFreeze");
       }
+    });
+  });
 
-      // If player is active, we need to make them inactive first
-      // For this test, we'll modify the test to use a fresh player or skip if active
-      if (playerBefore! && playerBefore.isActive) {
-        console.log("Player is still active - cannot test re-stake scenario");
-        console.log("To properly test this, run against a local validator with time warp");
-        return;
-      }
+  describe("Revives", () => {
+    async function reviveArena(): Promise<FlowArena> {
+      const arena = await createFlowArena();
+      // 10-minute recovery window, 10% cost, up to 50% of the stake restored
+      await program.methods
+        .setReviveConfig(new anchor.BN(600), new anchor.BN(1_000), new anchor.BN(5_000))
+        .accounts({ gameState: arena.gameState, authority: authority.publicKey })
+        .rpc();
+      return arena;
+    }
 
-      // THE CRITICAL TEST: Re-stake should work without passing referrer_player account
-      // This is what was failing before the fix
-      const newStakeAmount = 0.2;
+    function revive(arena: FlowArena, user: Keypair, extra: { league?: PublicKey; newLeague?: PublicKey; season?: PublicKey } = {}) {
+      return program.methods
+        .revive()
+        .accounts({
+          gameState: arena.gameState,
+          player: getArenaPlayerPDA(arena, user.publicKey),
+          user: user.publicKey,
+          systemProgram: SystemProgram.programId,
+          league: extra.league ?? null,
+          newLeague: extra.newLeague ?? null,
+          season: extra.season ?? null,
+        })
+        .signers([user])
+        .rpc();
+    }
 
-      // Build stake instruction WITHOUT referrer (since this is a re-stake)
-      const amountLamports = new anchor.BN(Math.floor(newStakeAmount * LAMPORTS_PER_SOL));
-      const discriminator = Buffer.from([206, 176, 202, 18, 200, 209, 179, 108]);
-      const amountBuffer = Buffer.alloc(8);
-      amountLamports.toArrayLike(Buffer, "le", 8).copy(amountBuffer);
-      const referrerBuffer = Buffer.from([0]); // No referrer for re-stake
+    it("Restores part of the stake out of the pool's share of the death", async () => {
+      const arena = await reviveArena();
+      const [gina] = await fundedPlayers(1);
+      const ginaPDA = getArenaPlayerPDA(arena, gina.publicKey);
+      await stakeInArena(arena, gina, 0.2 * LAMPORTS_PER_SOL);
+      await waitForNextPeriod();
+      await checkinInArena(arena, gina);
+      await waitForNextPeriod();
+      await waitForNextPeriod();
+      await processDeathInArena(arena, gina.publicKey);
+
+      const dead = await program.account.player.fetch(ginaPDA);
+      const stakeAtDeath = (dead.stakeAtDeath as anchor.BN).toNumber();
+      const poolBefore = ((await program.account.gameState.fetch(arena.gameState)).totalPool as anchor.BN).toNumber();
+      await revive(arena, gina);
+
+      const player = await program.account.player.fetch(ginaPDA);
+      assert.isTrue(player.isActive as boolean);
+      assert.equal((player.stake as anchor.BN).toNumber(), Math.floor(stakeAtDeath * 5_000 / 10_000));
+      assert.equal(player.streakDays as number, dead.streakAtDeath as number);
+      assert.equal((player.stakeAtDeath as anchor.BN).toNumber(), 0);
+      assert.equal((player.deathPoolShare as anchor.BN).toNumber(), 0);
+
+      // The cost goes into the pool; the restored stake was already counted in it
+      const cost = Math.floor(stakeAtDeath * 1_000 / 10_000);
+      assert.equal(((await program.account.gameState.fetch(arena.gameState)).totalPool as anchor.BN).toNumber(), poolBefore + cost);
+    });
 
-      const data = Buffer.concat([discriminator, amountBuffer, referrerBuffer]);
+    it("Takes the restored stake back out of the season's death pool and moves leagues with it", async () => {
+      const arena = await reviveArena();
+      const lowLeague = await createLeague(arena, 1, 0.05 * LAMPORTS_PER_SOL, LAMPORTS_PER_SOL);
+      const highLeague = await createLeague(arena, 2, LAMPORTS_PER_SOL, 0);
+      const season = getArenaPDA(arena, "season", new anchor.BN(1).toArrayLike(Buffer, "le", 8));
+      const [hank] = await fundedPlayers(1);
+      const hankPDA = getArenaPlayerPDA(arena, hank.publicKey);
 
-      const keys = [
-        { pubkey: gameStatePDA, isSigner: false, isWritable: true },
-        { pubkey: playerPDA, isSigner: false, isWritable: true },
-        // NO referrer_player account - this is what caused the bug!
-        { pubkey: deadPlayer.publicKey, isSigner: true, isWritable: true },
-        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
-      ];
+      const period = Math.floor((await chainTime()) / FLOW_INTERVAL);
+      await program.methods
+        .startSeason(
+          new anchor.BN(period + 1),
+          new anchor.BN(period + 20),
+          new anchor.BN(period + 2),
+          new anchor.BN(0.05 * LAMPORTS_PER_SOL),
+          new anchor.BN(5_000)
+        )
+        .accounts({
+          gameState: arena.gameState,
+          season,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
 
-      const ix = new anchor.web3.TransactionInstruction({
-        keys,
-        programId: program.programId,
-        data,
-      });
+      // Hank dies in the high league during the season
+      await stakeInArena(arena, hank, 1.2 * LAMPORTS_PER_SOL, highLeague);
+      await waitForNextPeriod();
+      await waitForNextPeriod();
+      await processDeathInArena(arena, hank.publicKey, { league: highLeague, season });
+      const deathPool = ((await program.account.season.fetch(season)).deathPool as anchor.BN).toNumber();
+
+      // Half the stake is back, which belongs in the low league's band
+      await revive(arena, hank, { league: highLeague, newLeague: lowLeague, season });
+
+      const player = await program.account.player.fetch(hankPDA);
+      const restored = (player.stake as anchor.BN).toNumber();
+      assert.equal(restored, 0.6 * LAMPORTS_PER_SOL);
+      assert.equal(player.leagueId as number, 1);
+      assert.equal(((await program.account.season.fetch(season)).deathPool as anchor.BN).toNumber(), deathPool - restored);
+
+      const low = await program.account.league.fetch(lowLeague);
+      assert.equal((low.activePlayers as anchor.BN).toNumber(), 1);
+      assert.equal((low.totalPool as anchor.BN).toNumber(), restored);
+    });
+  });
 
-      const tx = new anchor.web3.Transaction().add(ix);
-      tx.feePayer = deadPlayer.publicKey;
-      tx.recentBlockhash = (await provider.connection.getLatestBlockhash()).blockhash;
-      tx.sign(deadPlayer);
+  describe("Points", () => {
+    it("Authority can configure points", async () => {
+      await program.methods
+        .setPointsConfig(new anchor.BN(10), new anchor.BN(5), new anchor.BN(20), new anchor.BN(50), new anchor.BN(10_000))
+        .accounts({
+          gameState: gameStatePDA,
+          authority: authority.publicKey,
+        })
+        .rpc();
 
-      try {
-        const sig = await provider.connection.sendRawTransaction(tx.serialize());
-        await provider.connection.confirmTransaction(sig, "confirmed");
+      const gameState = await program.account.gameState.fetch(gameStatePDA) as unknown as GameState;
+      assert.equal(gameState.pointsPerCheckin.toNumber(), 10);
+      assert.equal(gameState.pointsMultiplierBps.toNumber(), 10_000);
+    });
 
-        const playerAfter = await program.account.player.fetch(playerPDA) as unknown as Player;
+    it("Anyone can record a player's points in a snapshot once", async () => {
+      const [playerPDA] = getPlayerPDA(player1.publicKey);
 
-        console.log("RE-STAKE SUCCESSFUL!");
-        console.log("Player state after re-stake:", {
-          isActive: playerAfter.isActive,
-          stake: playerAfter.stake.toNumber() / LAMPORTS_PER_SOL,
-          streakDays: playerAfter.streakDays,
+      await program.methods
+        .startPointsSnapshot()
+        .accounts({
+          gameState: gameStatePDA,
+          authority: authority.publicKey,
+        })
+        .rpc();
+
+      await program.methods
+        .snapshotPoints()
+        .accounts({ gameState: gameStatePDA, player: playerPDA })
+        .rpc();
+
+      const gameState = await program.account.gameState.fetch(gameStatePDA) as unknown as GameState;
+      const player = await program.account.player.fetch(playerPDA) as unknown as Player;
+      assert.isTrue(player.pointsSnapshotId.eq(gameState.pointsSnapshotId));
+      assert.isTrue(player.snapshotPoints.eq(player.points));
+
+      try {
+        await program.methods
+          .snapshotPoints()
+          .accounts({ gameState: gameStatePDA, player: playerPDA })
+          .rpc();
+        assert.fail("Should have thrown AlreadySnapshotted error");
+      } catch (e: unknown) {
+        assert.include((e as Error).message, "AlreadySnapshotted");
+      }
+    });
+  });
+
+  describe("Reward token", () => {
+    let rewardMint: PublicKey;
+
+    it("Rejects a mint the game state PDA can't mint", async () => {
+      const foreignMint = await createMint(
+        provider.connection,
+        authority.payer,
+        authority.publicKey,
+        null,
+        6
+      );
+
+      try {
+        await program.methods
+          .setRewardMint(new anchor.BN(1_000_000), new anchor.BN(500_000), new anchor.BN(5_000_000), new anchor.BN(0))
+          .accounts({
+            gameState: gameStatePDA,
+            rewardMint: foreignMint,
+            authority: authority.publicKey,
+          })
+          .rpc();
+        assert.fail("Should have thrown InvalidRewardMint error");
+      } catch (e: unknown) {
+        assert.include((e as Error).message, "InvalidRewardMint");
+      }
+    });
+
+    it("Authority can set a locally created reward mint", async () => {
+      rewardMint = await createMint(
+        provider.connection,
+        authority.payer,
+        gameStatePDA,
+        null,
+        6
+      );
+
+      await program.methods
+        .setRewardMint(new anchor.BN(1_000_000), new anchor.BN(500_000), new anchor.BN(5_000_000), new anchor.BN(30 * 86_400))
+        .accounts({
+          gameState: gameStatePDA,
+          rewardMint,
+          authority: authority.publicKey,
+        })
+        .rpc();
+
+      const gameState = await program.account.gameState.fetch(gameStatePDA);
+      assert.isTrue((gameState.rewardMint as PublicKey).equals(rewardMint));
+    });
+
+    it("Cannot claim reward tokens with nothing owed", async () => {
+      const [playerPDA] = getPlayerPDA(player2.publicKey);
+      const tokenAccount = await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        authority.payer,
+        rewardMint,
+        player2.publicKey
+      );
+
+      try {
+        await program.methods
+          .claimTokenRewards()
+          .accounts({
+            gameState: gameStatePDA,
+            player: playerPDA,
+            rewardMint,
+            playerTokenAccount: tokenAccount.address,
+            user: player2.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([player2])
+          .rpc();
+        assert.fail("Should have thrown NoTokenRewards error");
+      } catch (e: unknown) {
+        assert.include((e as Error).message, "NoTokenRewards");
+      }
+    });
+  });
+
+  describe("Token stakes", () => {
+    it("Arena fixes its stake mint and vault at creation", async () => {
+      const tokenArenaId = new anchor.BN(Date.now() + 1);
+      const [tokenArenaPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("game_state"), tokenArenaId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      const [stakeVaultPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake_vault"), tokenArenaId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      const stakeMint = await createMint(
+        provider.connection,
+        authority.payer,
+        authority.publicKey,
+        null,
+        6
+      );
+
+      await program.methods
+        .createArena(tokenArenaId)
+        .accounts({
+          gameState: tokenArenaPDA,
+          treasury: treasury.publicKey,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+          stakeMint,
+          stakeVault: stakeVaultPDA,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+
+      const arena = await program.account.gameState.fetch(tokenArenaPDA);
+      assert.isTrue((arena.stakeMint as PublicKey).equals(stakeMint));
+
+      const vault = await getAccount(provider.connection, stakeVaultPDA);
+      assert.isTrue(vault.mint.equals(stakeMint));
+      assert.isTrue(vault.owner.equals(tokenArenaPDA));
+    });
+
+    it("Main game stakes SOL", async () => {
+      const gameState = await program.account.gameState.fetch(gameStatePDA);
+      assert.isTrue((gameState.stakeMint as PublicKey).equals(PublicKey.default));
+    });
+  });
+
+  describe("Arenas", () => {
+    const arenaId = new anchor.BN(Date.now());
+    let arenaPDA: PublicKey;
+
+    before(() => {
+      [arenaPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("game_state"), arenaId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+    });
+
+    it("Main game keeps the singleton address as arena 0", async () => {
+      const gameState = await program.account.gameState.fetch(gameStatePDA);
+      assert.equal((gameState.gameId as anchor.BN).toNumber(), 0);
+    });
+
+    it("Anyone can create an arena next to the main game", async () => {
+      const arenaTreasury = Keypair.generate();
+
+      await program.methods
+        .createArena(arenaId)
+        .accounts({
+          gameState: arenaPDA,
+          treasury: arenaTreasury.publicKey,
+          authority: player3.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player3])
+        .rpc();
+
+      const arena = await program.account.gameState.fetch(arenaPDA);
+      assert.isTrue((arena.gameId as anchor.BN).eq(arenaId));
+      assert.isTrue((arena.authority as PublicKey).equals(player3.publicKey));
+      assert.equal((arena.totalPlayers as anchor.BN).toNumber(), 0);
+    });
+
+    it("Arena authority can configure it independently", async () => {
+      await program.methods
+        .setCheckinInterval(new anchor.BN(3600))
+        .accounts({
+          gameState: arenaPDA,
+          authority: player3.publicKey,
+        })
+        .signers([player3])
+        .rpc();
+
+      const arena = await program.account.gameState.fetch(arenaPDA);
+      const gameState = await program.account.gameState.fetch(gameStatePDA);
+      assert.equal((arena.checkinIntervalSeconds as anchor.BN).toNumber(), 3600);
+      assert.notEqual((gameState.checkinIntervalSeconds as anchor.BN).toNumber(), 3600);
+    });
+
+    it("Arena authority can add stake-tier leagues", async () => {
+      const bands: [number, number][] = [[0.05, 1], [1, 0]];
+
+      let previousLeague: PublicKey | null = null;
+      for (const [index, [min, max]] of bands.entries()) {
+        const [leaguePDA] = PublicKey.findProgramAddressSync(
+          [Buffer.from("league"), arenaId.toArrayLike(Buffer, "le", 8), Buffer.from([index + 1])],
+          program.programId
+        );
+
+        await program.methods
+          .createLeague(new anchor.BN(min * LAMPORTS_PER_SOL), new anchor.BN(max * LAMPORTS_PER_SOL))
+          .accounts({
+            gameState: arenaPDA,
+            league: leaguePDA,
+            previousLeague,
+            authority: player3.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player3])
+          .rpc();
+
+        const league = await program.account.league.fetch(leaguePDA);
+        assert.equal(league.leagueId as number, index + 1);
+        assert.equal((league.minStake as anchor.BN).toNumber(), min * LAMPORTS_PER_SOL);
+        previousLeague = leaguePDA;
+      }
+
+      const arena = await program.account.gameState.fetch(arenaPDA);
+      assert.equal(arena.leagueCount as number, 2);
+    });
+
+    it("Arena authority can start the first season", async () => {
+      const [seasonPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("season"), arenaId.toArrayLike(Buffer, "le", 8), new anchor.BN(1).toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      const currentPeriod = Math.floor(Date.now() / 1000 / 3600);
+
+      await program.methods
+        .startSeason(
+          new anchor.BN(currentPeriod + 1),
+          new anchor.BN(currentPeriod + 1 + 24 * 7),
+          new anchor.BN(currentPeriod + 1),
+          new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+          new anchor.BN(5_000)
+        )
+        .accounts({
+          gameState: arenaPDA,
+          season: seasonPDA,
+          authority: player3.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player3])
+        .rpc();
+
+      const season = await program.account.season.fetch(seasonPDA);
+      const arena = await program.account.gameState.fetch(arenaPDA);
+      assert.equal((arena.currentSeasonId as anchor.BN).toNumber(), 1);
+      assert.equal((season.endPeriod as anchor.BN).toNumber() - (season.startPeriod as anchor.BN).toNumber(), 24 * 7);
+      assert.isFalse(season.finalized as boolean);
+    });
+
+    it("Arena authority can add a jackpot fed by deaths", async () => {
+      const [jackpotPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("jackpot"), arenaId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+
+      await program.methods
+        .initializeJackpot(new anchor.BN(1_000), false)
+        .accounts({
+          gameState: arenaPDA,
+          jackpot: jackpotPDA,
+          authority: player3.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player3])
+        .rpc();
+
+      const arena = await program.account.gameState.fetch(arenaPDA);
+      assert.equal((arena.jackpotBps as anchor.BN).toNumber(), 1_000);
+
+      // Nobody has checked in yet, so there is nothing to draw
+      try {
+        await program.methods
+          .drawJackpot()
+          .accounts({
+            gameState: arenaPDA,
+            jackpot: jackpotPDA,
+            randomness: SYSVAR_SLOT_HASHES_PUBKEY,
+          })
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err: any) {
+        assert.include(err.toString(), "JackpotNotReady");
+      }
+    });
+
+    it("Players can enter an arena tournament while registration is open", async () => {
+      const tournamentId = new anchor.BN(1);
+      const [tournamentPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("tournament"), arenaId.toArrayLike(Buffer, "le", 8), tournamentId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      const [entryPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("tournament_entry"), tournamentPDA.toBuffer(), player3.publicKey.toBuffer()],
+        program.programId
+      );
+      const currentPeriod = Math.floor(Date.now() / 1000 / 3600);
+
+      await program.methods
+        .createTournament(tournamentId, new anchor.BN(0.05 * LAMPORTS_PER_SOL), new anchor.BN(currentPeriod + 24), 1)
+        .accounts({
+          gameState: arenaPDA,
+          tournament: tournamentPDA,
+          authority: player3.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player3])
+        .rpc();
+
+      await program.methods
+        .enterTournament()
+        .accounts({
+          gameState: arenaPDA,
+          tournament: tournamentPDA,
+          entry: entryPDA,
+          user: player3.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player3])
+        .rpc();
+
+      const tournament = await program.account.tournament.fetch(tournamentPDA);
+      const entry = await program.account.tournamentEntry.fetch(entryPDA);
+      assert.equal(tournament.entrants as number, 1);
+      assert.equal(tournament.alive as number, 1);
+      assert.isTrue(entry.isAlive as boolean);
+
+      // Registration is still open, so the tournament can't finish yet
+      try {
+        await program.methods
+          .finishTournament()
+          .accounts({
+            gameState: arenaPDA,
+            tournament: tournamentPDA,
+          })
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err: any) {
+        assert.include(err.toString(), "TournamentRunning");
+      }
+    });
+
+    it("Stakes and deaths in an arena only touch that arena's books", async () => {
+      const arena = await createFlowArena();
+      const [alice, bob] = await fundedPlayers(2);
+      const mainBefore = await program.account.gameState.fetch(gameStatePDA);
+      const vaultBefore = await provider.connection.getBalance(arena.gameState);
+
+      await stakeInArena(arena, alice, 0.1 * LAMPORTS_PER_SOL);
+      await stakeInArena(arena, bob, 0.1 * LAMPORTS_PER_SOL);
+
+      let state = await program.account.gameState.fetch(arena.gameState);
+      assert.equal((state.totalPlayers as anchor.BN).toNumber(), 2);
+      assert.equal((state.totalPool as anchor.BN).toNumber(), 0.2 * LAMPORTS_PER_SOL);
+      assert.equal(await provider.connection.getBalance(arena.gameState) - vaultBefore, 0.2 * LAMPORTS_PER_SOL);
+
+      // Alice keeps checking in, Bob misses a period and dies
+      await waitForNextPeriod();
+      await checkinInArena(arena, alice);
+      await waitForNextPeriod();
+      await checkinInArena(arena, alice);
+      await processDeathInArena(arena, bob.publicKey);
+
+      state = await program.account.gameState.fetch(arena.gameState);
+      const player = await program.account.player.fetch(getArenaPlayerPDA(arena, alice.publicKey));
+      const fee = 0.1 * LAMPORTS_PER_SOL * 300 / 10_000;
+      assert.equal((state.totalPlayers as anchor.BN).toNumber(), 1);
+      assert.equal((state.totalDeaths as anchor.BN).toNumber(), 1);
+      assert.equal((state.totalPool as anchor.BN).toNumber(), 0.2 * LAMPORTS_PER_SOL - fee);
+      assert.isTrue((player.gameId as anchor.BN).eq(arena.id));
+
+      // The main game's books are untouched
+      const mainAfter = await program.account.gameState.fetch(gameStatePDA);
+      assert.isTrue((mainAfter.totalPool as anchor.BN).eq(mainBefore.totalPool as anchor.BN));
+      assert.isTrue((mainAfter.totalDeaths as anchor.BN).eq(mainBefore.totalDeaths as anchor.BN));
+    });
+  });
+
+  describe("Leagues", () => {
+    let arena: FlowArena;
+    let lowLeague: PublicKey;
+    let highLeague: PublicKey;
+    let alice: Keypair;
+    let bob: Keypair;
+    let carol: Keypair;
+    let alicePDA: PublicKey;
+
+    before(async () => {
+      arena = await createFlowArena();
+      lowLeague = await createLeague(arena, 1, 0.05 * LAMPORTS_PER_SOL, LAMPORTS_PER_SOL);
+      highLeague = await createLeague(arena, 2, LAMPORTS_PER_SOL, 0);
+      [alice, bob, carol] = await fundedPlayers(3);
+      alicePDA = getArenaPlayerPDA(arena, alice.publicKey);
+    });
+
+    it("Places each stake in the league whose band holds it", async () => {
+      await stakeInArena(arena, alice, 0.1 * LAMPORTS_PER_SOL, lowLeague);
+      await stakeInArena(arena, bob, 0.1 * LAMPORTS_PER_SOL, lowLeague);
+      await stakeInArena(arena, carol, LAMPORTS_PER_SOL, highLeague);
+
+      const low = await program.account.league.fetch(lowLeague);
+      assert.equal((low.activePlayers as anchor.BN).toNumber(), 2);
+      assert.equal((low.totalPool as anchor.BN).toNumber(), 0.2 * LAMPORTS_PER_SOL);
+      const player = await program.account.player.fetch(alicePDA);
+      assert.equal(player.leagueId as number, 1);
+    });
+
+    it("Adds check-in growth to the league's pool", async () => {
+      await waitForNextPeriod();
+      await checkinInArena(arena, alice, { league: lowLeague });
+      await checkinInArena(arena, carol, { league: highLeague });
+
+      const growth = ((await program.account.player.fetch(alicePDA)).stake as anchor.BN).toNumber() - 0.1 * LAMPORTS_PER_SOL;
+      assert.isAbove(growth, 0);
+      const low = await program.account.league.fetch(lowLeague);
+      assert.equal((low.totalPool as anchor.BN).toNumber(), 0.2 * LAMPORTS_PER_SOL + growth);
+    });
+
+    it("Moves a player topping up past the band to the next league with their whole stake", async () => {
+      await program.methods
+        .addStake(new anchor.BN(LAMPORTS_PER_SOL))
+        .accounts({
+          gameState: arena.gameState,
+          player: alicePDA,
+          user: alice.publicKey,
+          systemProgram: SystemProgram.programId,
+          league: lowLeague,
+          newLeague: highLeague,
+        })
+        .signers([alice])
+        .rpc();
+
+      const aliceStake = ((await program.account.player.fetch(alicePDA)).stake as anchor.BN).toNumber();
+      const carolStake = ((await program.account.player.fetch(getArenaPlayerPDA(arena, carol.publicKey))).stake as anchor.BN).toNumber();
+      const low = await program.account.league.fetch(lowLeague);
+      const high = await program.account.league.fetch(highLeague);
+      assert.equal(((await program.account.player.fetch(alicePDA)).leagueId) as number, 2);
+      assert.equal((low.activePlayers as anchor.BN).toNumber(), 1);
+      assert.equal((low.totalPool as anchor.BN).toNumber(), 0.1 * LAMPORTS_PER_SOL);
+      assert.equal((high.activePlayers as anchor.BN).toNumber(), 2);
+      assert.equal((high.totalPool as anchor.BN).toNumber(), aliceStake + carolStake);
+    });
+
+    it("Keeps a death's pool addition in the dead player's league", async () => {
+      // Bob misses a period while the others check in
+      await waitForNextPeriod();
+      await checkinInArena(arena, alice, { league: highLeague });
+      await checkinInArena(arena, carol, { league: highLeague });
+      const highBefore = ((await program.account.league.fetch(highLeague)).totalPool as anchor.BN).toNumber();
+      await processDeathInArena(arena, bob.publicKey, { league: lowLeague });
+
+      const poolAddition = 0.1 * LAMPORTS_PER_SOL * (10_000 - 300) / 10_000;
+      const low = await program.account.league.fetch(lowLeague);
+      const high = await program.account.league.fetch(highLeague);
+      assert.equal((low.activePlayers as anchor.BN).toNumber(), 0);
+      assert.equal((low.totalDeaths as anchor.BN).toNumber(), 1);
+      assert.equal((low.totalDeathPool as anchor.BN).toNumber(), poolAddition);
+      assert.equal((low.totalPool as anchor.BN).toNumber(), poolAddition);
+      assert.equal((high.totalPool as anchor.BN).toNumber(), highBefore);
+    });
+
+    it("Rejects league bands that leave a gap or overlap", async () => {
+      const bandArena = await createFlowArena();
+      const expectInvalid = async (leagueId: number, min: number, max: number) => {
+        try {
+          await createLeague(bandArena, leagueId, min, max);
+          assert.fail("Should have thrown InvalidLeague error");
+        } catch (err: any) {
+          assert.include(err.toString(), "InvalidLeague");
+        }
+      };
+
+      // The first band starts at the minimum stake
+      await expectInvalid(1, 0.1 * LAMPORTS_PER_SOL, LAMPORTS_PER_SOL);
+      await createLeague(bandArena, 1, 0.05 * LAMPORTS_PER_SOL, LAMPORTS_PER_SOL);
+
+      // Later bands start exactly where the previous one ends
+      await expectInvalid(2, 0.5 * LAMPORTS_PER_SOL, 0);
+      await expectInvalid(2, 2 * LAMPORTS_PER_SOL, 0);
+      await createLeague(bandArena, 2, LAMPORTS_PER_SOL, 0);
+
+      // Nothing fits above an unbounded band
+      await expectInvalid(3, 2 * LAMPORTS_PER_SOL, 0);
+    });
+
+    it("Harvested growth leaves the league's pool", async () => {
+      const [erin] = await fundedPlayers(1);
+      const erinPDA = getArenaPlayerPDA(arena, erin.publicKey);
+      await stakeInArena(arena, erin, 0.1 * LAMPORTS_PER_SOL, lowLeague);
+      await waitForNextPeriod();
+      await checkinInArena(arena, erin, { league: lowLeague });
+
+      const poolBefore = ((await program.account.league.fetch(lowLeague)).totalPool as anchor.BN).toNumber();
+      const stakeBefore = ((await program.account.player.fetch(erinPDA)).stake as anchor.BN).toNumber();
+      await program.methods
+        .harvest()
+        .accounts({
+          gameState: arena.gameState,
+          player: erinPDA,
+          user: erin.publicKey,
+          league: lowLeague,
+        })
+        .signers([erin])
+        .rpc();
+
+      const harvested = stakeBefore - 0.1 * LAMPORTS_PER_SOL;
+      assert.isAbove(harvested, 0);
+      const low = await program.account.league.fetch(lowLeague);
+      assert.equal((low.totalPool as anchor.BN).toNumber(), poolBefore - harvested);
+    });
+
+    it("Players staked before leagues existed can opt in with join_league", async () => {
+      const liveArena = await createFlowArena();
+      const [dave] = await fundedPlayers(1);
+      await stakeInArena(liveArena, dave, 0.2 * LAMPORTS_PER_SOL);
+
+      const league = await createLeague(liveArena, 1, 0.05 * LAMPORTS_PER_SOL, 0);
+      await program.methods
+        .joinLeague()
+        .accounts({
+          gameState: liveArena.gameState,
+          player: getArenaPlayerPDA(liveArena, dave.publicKey),
+          league,
+          user: dave.publicKey,
+        })
+        .signers([dave])
+        .rpc();
+
+      const joined = await program.account.league.fetch(league);
+      const player = await program.account.player.fetch(getArenaPlayerPDA(liveArena, dave.publicKey));
+      assert.equal(player.leagueId as number, 1);
+      assert.equal((joined.activePlayers as anchor.BN).toNumber(), 1);
+      assert.equal((joined.totalPool as anchor.BN).toNumber(), 0.2 * LAMPORTS_PER_SOL);
+    });
+  });
+
+  describe("Seasons", () => {
+    const poolAddition = 0.1 * LAMPORTS_PER_SOL * (10_000 - 300) / 10_000;
+    const payout = poolAddition * 5_000 / 10_000;
+    let arena: FlowArena;
+    let alice: Keypair;
+    let bob: Keypair;
+    let season: PublicKey;
+    let alicePDA: PublicKey;
+    let bobPDA: PublicKey;
+
+    before(async () => {
+      arena = await createFlowArena();
+      [alice, bob] = await fundedPlayers(2);
+      season = getArenaPDA(arena, "season", new anchor.BN(1).toArrayLike(Buffer, "le", 8));
+      alicePDA = getArenaPlayerPDA(arena, alice.publicKey);
+      bobPDA = getArenaPlayerPDA(arena, bob.publicKey);
+    });
+
+    it("Counts the stake of every player who joins before entry closes", async () => {
+      // Runs for periods p+1..p+3, entry closes at p+2
+      await waitForNextPeriod();
+      const period = Math.floor((await chainTime()) / FLOW_INTERVAL);
+      await program.methods
+        .startSeason(
+          new anchor.BN(period + 1),
+          new anchor.BN(period + 4),
+          new anchor.BN(period + 2),
+          new anchor.BN(0.05 * LAMPORTS_PER_SOL),
+          new anchor.BN(5_000)
+        )
+        .accounts({
+          gameState: arena.gameState,
+          season,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      for (const user of [alice, bob]) {
+        await stakeInArena(arena, user, 0.1 * LAMPORTS_PER_SOL);
+        await program.methods
+          .joinSeason()
+          .accounts({
+            gameState: arena.gameState,
+            season,
+            player: getArenaPlayerPDA(arena, user.publicKey),
+            user: user.publicKey,
+          })
+          .signers([user])
+          .rpc();
+      }
+
+      const state = await program.account.season.fetch(season);
+      assert.equal(state.survivors as number, 2);
+      assert.equal((state.survivorStake as anchor.BN).toNumber(), 0.2 * LAMPORTS_PER_SOL);
+    });
+
+    it("A death during the season drops the survivor and feeds the death pool", async () => {
+      // Bob misses p+1
+      await waitForNextPeriod();
+      await checkinInArena(arena, alice);
+      await waitForNextPeriod();
+      await checkinInArena(arena, alice);
+      await processDeathInArena(arena, bob.publicKey, { season });
+
+      const state = await program.account.season.fetch(season);
+      assert.equal(state.survivors as number, 1);
+      assert.equal((state.survivorStake as anchor.BN).toNumber(), 0.1 * LAMPORTS_PER_SOL);
+      assert.equal((state.deathPool as anchor.BN).toNumber(), poolAddition);
+      assert.equal(((await program.account.player.fetch(bobPDA)).seasonStake as anchor.BN).toNumber(), 0);
+    });
+
+    it("Finalizing sets the payout share of the death pool aside", async () => {
+      await waitForNextPeriod();
+      await checkinInArena(arena, alice);
+      await waitForNextPeriod();
+
+      const poolBefore = ((await program.account.gameState.fetch(arena.gameState)).totalPool as anchor.BN).toNumber();
+      await program.methods
+        .finalizeSeason()
+        .accounts({
+          gameState: arena.gameState,
+          season,
+          nextSeason: getArenaPDA(arena, "season", new anchor.BN(2).toArrayLike(Buffer, "le", 8)),
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const state = await program.account.season.fetch(season);
+      assert.isTrue(state.finalized as boolean);
+      assert.equal((state.payoutPool as anchor.BN).toNumber(), payout);
+      assert.equal(((await program.account.gameState.fetch(arena.gameState)).totalPool as anchor.BN).toNumber(), poolBefore - payout);
+    });
+
+    it("The only survivor takes the whole payout", async () => {
+      await program.methods
+        .claimSeasonReward()
+        .accounts({ gameState: arena.gameState, season, player: alicePDA, user: alice.publicKey })
+        .signers([alice])
+        .rpc();
+
+      const player = await program.account.player.fetch(alicePDA);
+      assert.equal((player.pendingRewards as anchor.BN).toNumber(), payout);
+      assert.equal((player.seasonStake as anchor.BN).toNumber(), 0);
+      assert.equal(player.seasonsSurvived as number, 1);
+      assert.equal(((await program.account.season.fetch(season)).totalClaimed as anchor.BN).toNumber(), payout);
+    });
+
+    it("The dead forfeit, and unclaimed payouts can't be swept before the deadline", async () => {
+      try {
+        await program.methods
+          .claimSeasonReward()
+          .accounts({ gameState: arena.gameState, season, player: bobPDA, user: bob.publicKey })
+          .signers([bob])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err: any) {
+        assert.include(err.toString(), "NoSeasonReward");
+      }
+      try {
+        await program.methods
+          .sweepSeason()
+          .accounts({ gameState: arena.gameState, season })
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err: any) {
+        assert.include(err.toString(), "SeasonClaimsOpen");
+      }
+    });
+
+    it("A shrinking stake lowers the survivors' total weight with the player's", async () => {
+      const carolArena = await createFlowArena();
+      const [carol] = await fundedPlayers(1);
+      const carolSeason = getArenaPDA(carolArena, "season", new anchor.BN(1).toArrayLike(Buffer, "le", 8));
+      const carolPDA = getArenaPlayerPDA(carolArena, carol.publicKey);
+
+      const period = Math.floor((await chainTime()) / FLOW_INTERVAL);
+      await program.methods
+        .startSeason(
+          new anchor.BN(period + 1),
+          new anchor.BN(period + 4),
+          new anchor.BN(period + 2),
+          new anchor.BN(0.05 * LAMPORTS_PER_SOL),
+          new anchor.BN(5_000)
+        )
+        .accounts({
+          gameState: carolArena.gameState,
+          season: carolSeason,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      await stakeInArena(carolArena, carol, 0.2 * LAMPORTS_PER_SOL);
+      await program.methods
+        .joinSeason()
+        .accounts({ gameState: carolArena.gameState, season: carolSeason, player: carolPDA, user: carol.publicKey })
+        .signers([carol])
+        .rpc();
+
+      const partialWithdraw = (withSeason: boolean) =>
+        program.methods
+          .partialWithdraw(new anchor.BN(0.1 * LAMPORTS_PER_SOL))
+          .accounts({
+            gameState: carolArena.gameState,
+            treasury: carolArena.treasury,
+            player: carolPDA,
+            user: carol.publicKey,
+            sponsor: null,
+            systemProgram: SystemProgram.programId,
+            season: withSeason ? carolSeason : null,
+          })
+          .signers([carol])
+          .rpc();
+
+      // The season has to come along while the withdrawal lowers Carol's weight
+      try {
+        await partialWithdraw(false);
+        assert.fail("Should have thrown error");
+      } catch (err: any) {
+        assert.include(err.toString(), "WrongSeason");
+      }
+      await partialWithdraw(true);
+
+      const player = await program.account.player.fetch(carolPDA);
+      const state = await program.account.season.fetch(carolSeason);
+      assert.equal((player.seasonStake as anchor.BN).toNumber(), (player.stake as anchor.BN).toNumber());
+      assert.equal((state.survivorStake as anchor.BN).toNumber(), (player.stake as anchor.BN).toNumber());
+    });
+  });
+
+  describe("Tournaments", () => {
+    const fee = 0.05 * LAMPORTS_PER_SOL;
+    let arena: FlowArena;
+    let alice: Keypair;
+    let bob: Keypair;
+    let carol: Keypair;
+    let dave: Keypair;
+    let tied: PublicKey;
+    let survived: PublicKey;
+    let tiedEntries: PublicKey[];
+
+    function entryPDA(tournament: PublicKey, wallet: PublicKey): PublicKey {
+      return PublicKey.findProgramAddressSync(
+        [Buffer.from("tournament_entry"), tournament.toBuffer(), wallet.toBuffer()],
+        program.programId
+      )[0];
+    }
+
+    function sortedKeys(keys: PublicKey[]): PublicKey[] {
+      return [...keys].sort((a, b) => Buffer.compare(a.toBuffer(), b.toBuffer()));
+    }
+
+    async function finish(tournament: PublicKey, entries: PublicKey[]): Promise<void> {
+      await program.methods
+        .finishTournament()
+        .accounts({ gameState: arena.gameState, tournament })
+        .remainingAccounts(sortedKeys(entries).map(pubkey => ({ pubkey, isSigner: false, isWritable: true })))
+        .rpc();
+    }
+
+    async function claimPrize(tournament: PublicKey, user: Keypair): Promise<number> {
+      const before = await provider.connection.getBalance(user.publicKey);
+      await program.methods
+        .claimTournamentPrize()
+        .accounts({
+          gameState: arena.gameState,
+          tournament,
+          entry: entryPDA(tournament, user.publicKey),
+          user: user.publicKey,
+        })
+        .signers([user])
+        .rpc();
+      return await provider.connection.getBalance(user.publicKey) - before;
+    }
+
+    async function closeTournament(tournament: PublicKey): Promise<void> {
+      await program.methods
+        .closeTournament()
+        .accounts({ gameState: arena.gameState, tournament, authority: authority.publicKey })
+        .rpc();
+    }
+
+    before(async () => {
+      arena = await createFlowArena();
+      [alice, bob, carol, dave] = await fundedPlayers(4);
+      tied = getArenaPDA(arena, "tournament", new anchor.BN(1).toArrayLike(Buffer, "le", 8));
+      survived = getArenaPDA(arena, "tournament", new anchor.BN(2).toArrayLike(Buffer, "le", 8));
+      tiedEntries = [alice, bob, carol].map(user => entryPDA(tied, user.publicKey));
+
+      await waitForNextPeriod();
+      const period = Math.floor((await chainTime()) / FLOW_INTERVAL);
+      for (const [id, tournament, entrants] of [
+        [1, tied, [alice, bob, carol]],
+        [2, survived, [alice, dave]],
+      ] as [number, PublicKey, Keypair[]][]) {
+        await program.methods
+          .createTournament(new anchor.BN(id), new anchor.BN(fee), new anchor.BN(period + 1), 1)
+          .accounts({
+            gameState: arena.gameState,
+            tournament,
+            authority: authority.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+
+        for (const user of entrants) {
+          await program.methods
+            .enterTournament()
+            .accounts({
+              gameState: arena.gameState,
+              tournament,
+              entry: entryPDA(tournament, user.publicKey),
+              user: user.publicKey,
+              systemProgram: SystemProgram.programId,
+            })
+            .signers([user])
+            .rpc();
+        }
+      }
+
+      // Only Alice checks in, and only in the second tournament
+      await waitForNextPeriod();
+      await program.methods
+        .tournamentCheckin()
+        .accounts({
+          gameState: arena.gameState,
+          tournament: survived,
+          entry: entryPDA(survived, alice.publicKey),
+          user: alice.publicKey,
+        })
+        .signers([alice])
+        .rpc();
+      await waitForNextPeriod();
+    });
+
+    it("Finishing needs every entry still marked alive", async () => {
+      try {
+        await finish(tied, tiedEntries.slice(0, 2));
+        assert.fail("Should have thrown error");
+      } catch (err: any) {
+        assert.include(err.toString(), "MissingTournamentEntries");
+      }
+    });
+
+    it("Splits the pot between everyone who fell in the last period when nobody survives", async () => {
+      await finish(tied, tiedEntries);
+      const tournament = await program.account.tournament.fetch(tied);
+      assert.isTrue(tournament.finished as boolean);
+      assert.equal(tournament.alive as number, 0);
+      assert.equal(tournament.winnerCount as number, 3);
+      assert.equal((tournament.prizePerWinner as anchor.BN).toNumber(), fee);
+      for (const user of [alice, bob, carol]) {
+        assert.equal(await claimPrize(tied, user), fee);
+      }
+    });
+
+    it("Eliminates stale entries at the finish and pays only the survivors", async () => {
+      await finish(survived, [alice, dave].map(user => entryPDA(survived, user.publicKey)));
+      const tournament = await program.account.tournament.fetch(survived);
+      assert.equal(tournament.winnerCount as number, 1);
+      assert.equal((tournament.prizePerWinner as anchor.BN).toNumber(), 2 * fee);
+      assert.isFalse((await program.account.tournamentEntry.fetch(entryPDA(survived, dave.publicKey))).isAlive as boolean);
+      try {
+        await claimPrize(survived, dave);
+        assert.fail("Should have thrown error");
+      } catch (err: any) {
+        assert.include(err.toString(), "NotTournamentWinner");
+      }
+    });
+
+    it("Closes a tournament only once every winner has claimed", async () => {
+      try {
+        await closeTournament(survived);
+        assert.fail("Should have thrown error");
+      } catch (err: any) {
+        assert.include(err.toString(), "TournamentPrizesUnclaimed");
+      }
+      assert.equal(await claimPrize(survived, alice), 2 * fee);
+
+      for (const closed of [tied, survived]) {
+        await closeTournament(closed);
+        assert.isNull(await provider.connection.getAccountInfo(closed));
+      }
+    });
+
+    it("Caps the number of winners", async () => {
+      const capped = getArenaPDA(arena, "tournament", new anchor.BN(3).toArrayLike(Buffer, "le", 8));
+      const period = Math.floor((await chainTime()) / FLOW_INTERVAL);
+      try {
+        await program.methods
+          .createTournament(new anchor.BN(3), new anchor.BN(fee), new anchor.BN(period + 1), 21)
+          .accounts({
+            gameState: arena.gameState,
+            tournament: capped,
+            authority: authority.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err: any) {
+        assert.include(err.toString(), "InvalidTournament");
+      }
+    });
+  });
+
+  describe("Jackpot", () => {
+    const prize = 0.1 * LAMPORTS_PER_SOL * 1_000 / 10_000;
+    let arena: FlowArena;
+    let alice: Keypair;
+    let bob: Keypair;
+    let carol: Keypair;
+    let jackpot: PublicKey;
+    let drawPeriod: number;
+    let drawSlot: number;
+
+    function draw(): Promise<string> {
+      return program.methods
+        .drawJackpot()
+        .accounts({ gameState: arena.gameState, jackpot, randomness: SYSVAR_SLOT_HASHES_PUBKEY })
+        .rpc();
+    }
+
+    before(async () => {
+      arena = await createFlowArena();
+      [alice, bob, carol] = await fundedPlayers(3);
+      jackpot = getArenaPDA(arena, "jackpot");
+
+      await program.methods
+        .initializeJackpot(new anchor.BN(1_000), false)
+        .accounts({
+          gameState: arena.gameState,
+          jackpot,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      await waitForNextPeriod();
+      for (const user of [alice, bob, carol]) {
+        await stakeInArena(arena, user, 0.1 * LAMPORTS_PER_SOL);
+      }
+    });
+
+    it("Enters every check-in in its period's draw and feeds deaths into the balance", async () => {
+      await waitForNextPeriod();
+      drawPeriod = Math.floor((await chainTime()) / FLOW_INTERVAL);
+      await checkinInArena(arena, alice, { jackpot });
+      await checkinInArena(arena, bob, { jackpot });
+
+      // Carol's death feeds 10% of Carol's stake into the jackpot
+      await waitForNextPeriod();
+      await processDeathInArena(arena, carol.publicKey, { jackpot });
+      await checkinInArena(arena, alice, { jackpot });
+      await checkinInArena(arena, bob, { jackpot });
+
+      const state = await program.account.jackpot.fetch(jackpot);
+      assert.equal((state.balance as anchor.BN).toNumber(), prize);
+      assert.equal((state.entries as any[]).length, 4);
+    });
+
+    it("The first crank closes the finished round and commits its draw slot", async () => {
+      await draw();
+      const state = await program.account.jackpot.fetch(jackpot);
+      drawSlot = (state.drawSlot as anchor.BN).toNumber();
+      assert.isAbove(drawSlot, 0);
+      assert.equal((state.entries as any[]).length, 4);
+      assert.equal((state.balance as anchor.BN).toNumber(), prize);
+    });
+
+    it("Draws the round from its slot's hash and keeps later periods' entries", async () => {
+      while ((await provider.connection.getSlot("confirmed")) <= drawSlot + 1) {
+        await new Promise(resolve => setTimeout(resolve, 400));
+      }
+      await draw();
+
+      const state = await program.account.jackpot.fetch(jackpot);
+      const entries = state.entries as { wallet: PublicKey; period: anchor.BN }[];
+      const prizes = state.prizes as { wallet: PublicKey; amount: anchor.BN }[];
+      assert.equal((state.lastDrawPeriod as anchor.BN).toNumber(), drawPeriod);
+      assert.equal((state.drawSlot as anchor.BN).toNumber(), 0);
+      assert.equal((state.balance as anchor.BN).toNumber(), 0);
+      assert.equal((state.totalWon as anchor.BN).toNumber(), prize);
+      assert.equal(entries.length, 2);
+      assert.isTrue(entries.every(entry => entry.period.toNumber() === drawPeriod + 1));
+      assert.equal(prizes.length, 1);
+      assert.equal(prizes[0].amount.toNumber(), prize);
+      const winner = prizes[0].wallet;
+      assert.isTrue(winner.equals(alice.publicKey) || winner.equals(bob.publicKey));
+    });
+
+    it("Credits the prize to the winner's pending rewards", async () => {
+      const prizes = (await program.account.jackpot.fetch(jackpot)).prizes as { wallet: PublicKey }[];
+      const winnerPDA = getArenaPlayerPDA(arena, prizes[0].wallet);
+      await program.methods
+        .claimJackpot()
+        .accounts({ gameState: arena.gameState, jackpot, player: winnerPDA })
+        .rpc();
+
+      const player = await program.account.player.fetch(winnerPDA);
+      assert.equal((player.pendingRewards as anchor.BN).toNumber(), prize);
+      assert.equal(((await program.account.jackpot.fetch(jackpot)).prizes as any[]).length, 0);
+    });
+  });
+
+  describe("Top-up and partial withdrawal", () => {
+    it("Player 1 can add to an active stake", async () => {
+      const [playerPDA] = getPlayerPDA(player1.publicKey);
+      const before = await program.account.player.fetch(playerPDA) as unknown as Player;
+
+      await program.methods
+        .addStake(new anchor.BN(0.05 * LAMPORTS_PER_SOL))
+        .accounts({
+          gameState: gameStatePDA,
+          player: playerPDA,
+          user: player1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player1])
+        .rpc();
+
+      const after = await program.account.player.fetch(playerPDA) as unknown as Player;
+      assert.equal(after.stake.sub(before.stake).toNumber(), 0.05 * LAMPORTS_PER_SOL);
+      assert.equal(after.streakDays, before.streakDays, "Streak should be preserved");
+    });
+
+    it("Player 1 can withdraw part of the stake", async () => {
+      const [playerPDA] = getPlayerPDA(player1.publicKey);
+      const before = await program.account.player.fetch(playerPDA) as unknown as Player;
+
+      await program.methods
+        .partialWithdraw(new anchor.BN(0.05 * LAMPORTS_PER_SOL))
+        .accounts({
+          gameState: gameStatePDA,
+          treasury: treasury.publicKey,
+          player: playerPDA,
+          user: player1.publicKey,
+          sponsor: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player1])
+        .rpc();
+
+      const after = await program.account.player.fetch(playerPDA) as unknown as Player;
+      assert.equal(before.stake.sub(after.stake).toNumber(), 0.05 * LAMPORTS_PER_SOL);
+      assert.isTrue(after.isActive);
+    });
+
+    it("Cannot withdraw below the minimum stake", async () => {
+      const [playerPDA] = getPlayerPDA(player1.publicKey);
+      const player = await program.account.player.fetch(playerPDA) as unknown as Player;
+
+      try {
+        await program.methods
+          .partialWithdraw(player.stake.sub(new anchor.BN(MIN_STAKE - 1)))
+          .accounts({
+            gameState: gameStatePDA,
+            treasury: treasury.publicKey,
+            player: playerPDA,
+            user: player1.publicKey,
+            sponsor: null,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player1])
+          .rpc();
+        assert.fail("Should have thrown BelowMinimumStake error");
+      } catch (e: unknown) {
+        assert.include((e as Error).message, "BelowMinimumStake");
+      }
+    });
+  });
+
+  describe("Harvest", () => {
+    it("Moves growth out of the stake and the pool into pending rewards that survive death", async () => {
+      const arena = await createFlowArena();
+      const [pia] = await fundedPlayers(1);
+      const piaPDA = getArenaPlayerPDA(arena, pia.publicKey);
+      await stakeInArena(arena, pia, 0.1 * LAMPORTS_PER_SOL);
+      await waitForNextPeriod();
+      await checkinInArena(arena, pia);
+
+      const grown = ((await program.account.player.fetch(piaPDA)).stake as anchor.BN).toNumber();
+      const growth = grown - 0.1 * LAMPORTS_PER_SOL;
+      const poolBefore = ((await program.account.gameState.fetch(arena.gameState)).totalPool as anchor.BN).toNumber();
+      await program.methods
+        .harvest()
+        .accounts({ gameState: arena.gameState, player: piaPDA, user: pia.publicKey })
+        .signers([pia])
+        .rpc();
+
+      let player = await program.account.player.fetch(piaPDA);
+      assert.isAbove(growth, 0);
+      assert.equal((player.stake as anchor.BN).toNumber(), 0.1 * LAMPORTS_PER_SOL);
+      assert.equal((player.pendingRewards as anchor.BN).toNumber(), growth);
+      assert.equal((player.totalHarvested as anchor.BN).toNumber(), growth);
+      assert.equal(((await program.account.gameState.fetch(arena.gameState)).totalPool as anchor.BN).toNumber(), poolBefore - growth);
+
+      // Nothing left to harvest until the stake grows again
+      try {
+        await program.methods
+          .harvest()
+          .accounts({ gameState: arena.gameState, player: piaPDA, user: pia.publicKey })
+          .signers([pia])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err: any) {
+        assert.include(err.toString(), "NothingToHarvest");
+      }
+
+      await waitForNextPeriod();
+      await waitForNextPeriod();
+      await processDeathInArena(arena, pia.publicKey);
+      player = await program.account.player.fetch(piaPDA);
+      assert.isFalse(player.isActive as boolean);
+      assert.equal((player.pendingRewards as anchor.BN).toNumber(), growth);
+    });
+  });
+
+  describe("Compound rewards", () => {
+    it("Moves pending rewards into the stake as principal and back into the pool", async () => {
+      const arena = await createFlowArena();
+      const [quinn] = await fundedPlayers(1);
+      const quinnPDA = getArenaPlayerPDA(arena, quinn.publicKey);
+      await stakeInArena(arena, quinn, 0.1 * LAMPORTS_PER_SOL);
+      await waitForNextPeriod();
+      await checkinInArena(arena, quinn);
+      await program.methods
+        .harvest()
+        .accounts({ gameState: arena.gameState, player: quinnPDA, user: quinn.publicKey })
+        .signers([quinn])
+        .rpc();
+
+      const rewards = ((await program.account.player.fetch(quinnPDA)).pendingRewards as anchor.BN).toNumber();
+      const poolBefore = ((await program.account.gameState.fetch(arena.gameState)).totalPool as anchor.BN).toNumber();
+      await program.methods
+        .compoundRewards()
+        .accounts({ gameState: arena.gameState, player: quinnPDA, user: quinn.publicKey })
+        .signers([quinn])
+        .rpc();
+
+      const player = await program.account.player.fetch(quinnPDA);
+      assert.isAbove(rewards, 0);
+      assert.equal((player.pendingRewards as anchor.BN).toNumber(), 0);
+      assert.equal((player.stake as anchor.BN).toNumber(), 0.1 * LAMPORTS_PER_SOL + rewards);
+      assert.equal((player.principal as anchor.BN).toNumber(), 0.1 * LAMPORTS_PER_SOL + rewards);
+      assert.equal((player.totalCompounded as anchor.BN).toNumber(), rewards);
+      assert.equal(((await program.account.gameState.fetch(arena.gameState)).totalPool as anchor.BN).toNumber(), poolBefore + rewards);
+    });
+  });
+
+  describe("Withdrawals", () => {
+    function withdrawFromArena(arena: FlowArena, user: Keypair, withdrawQueue: PublicKey | null = null) {
+      return program.methods
+        .withdraw()
+        .accounts({
+          gameState: arena.gameState,
+          treasury: arena.treasury,
+          withdrawQueue,
+          player: getArenaPlayerPDA(arena, user.publicKey),
+          user: user.publicKey,
+          sponsor: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+    }
+
+    function setExitFee(arena: FlowArena, maxBps: number, zeroFeeStreakDays: number, treasuryBps: number) {
+      return program.methods
+        .setExitFeeConfig(new anchor.BN(maxBps), zeroFeeStreakDays, new anchor.BN(treasuryBps))
+        .accounts({ gameState: arena.gameState, authority: authority.publicKey })
+        .rpc();
+    }
+
+    it("Splits the exit fee between the treasury and the pool", async () => {
+      const arena = await createFlowArena();
+      // 10% on day 0, decaying to nothing by day 10, half of it to the treasury
+      await setExitFee(arena, 1_000, 10, 5_000);
+      const [ivy, jack] = await fundedPlayers(2);
+      await stakeInArena(arena, ivy, 0.1 * LAMPORTS_PER_SOL);
+      await stakeInArena(arena, jack, 0.1 * LAMPORTS_PER_SOL);
+
+      const poolBefore = ((await program.account.gameState.fetch(arena.gameState)).totalPool as anchor.BN).toNumber();
+      const ivyBefore = await provider.connection.getBalance(ivy.publicKey);
+      await withdrawFromArena(arena, ivy);
+
+      // Day 1 of 10: 9% of the stake
+      const fee = 0.1 * LAMPORTS_PER_SOL * 900 / 10_000;
+      const poolShare = fee / 2;
+      const state = await program.account.gameState.fetch(arena.gameState);
+      assert.equal((state.totalPool as anchor.BN).toNumber(), poolBefore - 0.1 * LAMPORTS_PER_SOL + poolShare);
+      assert.equal(await provider.connection.getBalance(arena.treasury), fee - poolShare);
+      assert.approximately(
+        (await provider.connection.getBalance(ivy.publicKey)) - ivyBefore,
+        0.1 * LAMPORTS_PER_SOL - fee,
+        10_000
+      );
+    });
+
+    it("Queues what the vault can't cover and pays the queue down in order", async () => {
+      const arena = await createFlowArena();
+      await setExitFee(arena, 0, 0, 0);
+      // Doubling growth on the first check-in leaves the vault short of the stakes
+      await program.methods
+        .setGrowthTiers([{ minStreakDays: 1, extraGrowthBps: new anchor.BN(10_000), milestoneRewardBps: new anchor.BN(0) }])
+        .accounts({ gameState: arena.gameState, authority: authority.publicKey })
+        .rpc();
+      const withdrawQueue = getArenaPDA(arena, "withdraw_queue");
+      await program.methods
+        .initializeWithdrawQueue()
+        .accounts({
+          gameState: arena.gameState,
+          withdrawQueue,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const [kim, leo, mia, ned] = await fundedPlayers(4);
+      for (const user of [kim, leo, mia]) {
+        await stakeInArena(arena, user, 0.1 * LAMPORTS_PER_SOL);
+      }
+      await waitForNextPeriod();
+      for (const user of [kim, leo, mia]) {
+        await checkinInArena(arena, user);
+      }
+
+      // Kim is paid in full, Leo in part, and Mia's whole withdrawal waits
+      for (const user of [kim, leo, mia]) {
+        await withdrawFromArena(arena, user, withdrawQueue);
+      }
+      let queue = await program.account.withdrawQueue.fetch(withdrawQueue);
+      const entries = queue.entries as any[];
+      assert.equal(entries.length, 2);
+      assert.isTrue(entries[0].wallet.equals(leo.publicKey));
+      assert.isTrue(entries[1].wallet.equals(mia.publicKey));
+      const leoOwed = (entries[0].amountOwed as anchor.BN).toNumber();
+      const miaOwed = (entries[1].amountOwed as anchor.BN).toNumber();
+
+      // A new stake refills the vault; the crank pays Leo off before Mia gets anything
+      await stakeInArena(arena, ned, 0.15 * LAMPORTS_PER_SOL);
+      const leoBefore = await provider.connection.getBalance(leo.publicKey);
+      const miaBefore = await provider.connection.getBalance(mia.publicKey);
+      await program.methods
+        .processWithdrawQueue()
+        .accounts({ gameState: arena.gameState, withdrawQueue })
+        .remainingAccounts([leo, mia].map(user => ({ pubkey: user.publicKey, isSigner: false, isWritable: true })))
+        .rpc();
+
+      queue = await program.account.withdrawQueue.fetch(withdrawQueue);
+      const miaPaid = (await provider.connection.getBalance(mia.publicKey)) - miaBefore;
+      assert.equal((await provider.connection.getBalance(leo.publicKey)) - leoBefore, leoOwed);
+      assert.isAbove(miaPaid, 0);
+      assert.equal((queue.entries as any[]).length, 1);
+      assert.isTrue((queue.entries as any[])[0].wallet.equals(mia.publicKey));
+      assert.equal(((queue.entries as any[])[0].amountOwed as anchor.BN).toNumber(), miaOwed - miaPaid);
+      assert.equal((queue.totalOwed as anchor.BN).toNumber(), miaOwed - miaPaid);
+    });
+  });
+
+  describe("Sponsored stakes", () => {
+    it("Sponsor can fund a stake for another wallet", async () => {
+      const beneficiary = Keypair.generate();
+      const [playerPDA] = getPlayerPDA(beneficiary.publicKey);
+
+      await program.methods
+        .sponsorStake(new anchor.BN(0.1 * LAMPORTS_PER_SOL), new anchor.BN(0), true, new anchor.BN(5_000))
+        .accounts({
+          gameState: gameStatePDA,
+          player: playerPDA,
+          beneficiary: beneficiary.publicKey,
+          sponsor: player2.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player2, beneficiary])
+        .rpc();
+
+      const player = await program.account.player.fetch(playerPDA) as unknown as Player;
+      assert.isTrue(player.isActive);
+      assert.isTrue(player.wallet.equals(beneficiary.publicKey));
+      assert.isTrue(player.sponsor!.equals(player2.publicKey));
+      assert.isTrue(player.withdrawToSponsor);
+      assert.equal(player.sponsorRefundBps.toNumber(), 5_000);
+    });
+
+    it("Sponsor cannot sponsor themselves", async () => {
+      const [playerPDA] = getPlayerPDA(player3.publicKey);
+
+      try {
+        await program.methods
+          .sponsorStake(new anchor.BN(0.1 * LAMPORTS_PER_SOL), new anchor.BN(0), false, new anchor.BN(0))
+          .accounts({
+            gameState: gameStatePDA,
+            player: playerPDA,
+            beneficiary: player3.publicKey,
+            sponsor: player3.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player3])
+          .rpc();
+        assert.fail("Should have thrown InvalidBeneficiary error");
+      } catch (e: unknown) {
+        assert.include((e as Error).message, "InvalidBeneficiary");
+      }
+    });
+  });
+
+  describe("Duels", () => {
+    const duelId = new anchor.BN(1);
+    let duelPDA: PublicKey;
+    const wager = 0.01 * LAMPORTS_PER_SOL;
+    let arena: FlowArena;
+    let alice: Keypair;
+    let bob: Keypair;
+    let carol: Keypair;
+    let dave: Keypair;
+    let deathDuel: PublicKey;
+    let timedDuel: PublicKey;
+    let startedAt: number;
+
+    before(() => {
+      [duelPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("duel"), player1.publicKey.toBuffer(), duelId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+    });
+
+    function arenaDuelPDA(challenger: PublicKey, id: number): PublicKey {
+      return getArenaPDA(arena, "duel", challenger.toBuffer(), new anchor.BN(id).toArrayLike(Buffer, "le", 8));
+    }
+
+    async function startDuel(challenger: Keypair, opponent: Keypair, id: number, endsAt: number): Promise<PublicKey> {
+      const duel = arenaDuelPDA(challenger.publicKey, id);
+      await program.methods
+        .createDuel(new anchor.BN(id), new anchor.BN(wager), new anchor.BN(endsAt))
+        .accounts({
+          gameState: arena.gameState,
+          duel,
+          challengerPlayer: getArenaPlayerPDA(arena, challenger.publicKey),
+          opponentPlayer: getArenaPlayerPDA(arena, opponent.publicKey),
+          opponent: opponent.publicKey,
+          challenger: challenger.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([challenger])
+        .rpc();
+      await program.methods
+        .acceptDuel()
+        .accounts({
+          gameState: arena.gameState,
+          duel,
+          challengerPlayer: getArenaPlayerPDA(arena, challenger.publicKey),
+          opponentPlayer: getArenaPlayerPDA(arena, opponent.publicKey),
+          user: opponent.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([opponent])
+        .rpc();
+      return duel;
+    }
+
+    async function claimDuel(duel: PublicKey, challenger: Keypair, opponent: Keypair, user: Keypair): Promise<number> {
+      const before = await provider.connection.getBalance(user.publicKey);
+      await program.methods
+        .claimDuel()
+        .accounts({
+          gameState: arena.gameState,
+          treasury: arena.treasury,
+          duel,
+          challengerPlayer: getArenaPlayerPDA(arena, challenger.publicKey),
+          opponentPlayer: getArenaPlayerPDA(arena, opponent.publicKey),
+          user: user.publicKey,
+          challenger: challenger.publicKey,
+        })
+        .signers([user])
+        .rpc();
+      return await provider.connection.getBalance(user.publicKey) - before;
+    }
+
+    it("Active player can challenge another to a duel", async () => {
+      const endsAt = new anchor.BN(Math.floor(Date.now() / 1000) + 7 * 86400);
+
+      await program.methods
+        .createDuel(duelId, new anchor.BN(0.01 * LAMPORTS_PER_SOL), endsAt)
+        .accounts({
+          gameState: gameStatePDA,
+          duel: duelPDA,
+          challengerPlayer: getPlayerPDA(player1.publicKey)[0],
+          opponentPlayer: getPlayerPDA(player2.publicKey)[0],
+          opponent: player2.publicKey,
+          challenger: player1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player1])
+        .rpc();
+
+      const duel = await program.account.duel.fetch(duelPDA);
+      assert.isTrue((duel.opponent as PublicKey).equals(player2.publicKey));
+      assert.isFalse(duel.accepted as boolean);
+    });
+
+    it("Only the opponent can accept", async () => {
+      try {
+        await program.methods
+          .acceptDuel()
+          .accounts({
+            gameState: gameStatePDA,
+            duel: duelPDA,
+            challengerPlayer: getPlayerPDA(player1.publicKey)[0],
+            opponentPlayer: getPlayerPDA(player3.publicKey)[0],
+            user: player3.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player3])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err: any) {
+        assert.include(err.toString(), "NotDuelParty");
+      }
+    });
+
+    it("Challenger can cancel before the duel is accepted", async () => {
+      await program.methods
+        .cancelDuel()
+        .accounts({
+          gameState: gameStatePDA,
+          duel: duelPDA,
+          challenger: player1.publicKey,
+        })
+        .signers([player1])
+        .rpc();
+
+      assert.isNull(await program.account.duel.fetchNullable(duelPDA));
+    });
+
+    it("Arena players can wager on a duel until one dies or the end date passes", async () => {
+      arena = await createFlowArena();
+      [alice, bob, carol, dave] = await fundedPlayers(4);
+      // Duel fees go to the treasury, which has to exist to receive them
+      const sig = await provider.connection.requestAirdrop(arena.treasury, LAMPORTS_PER_SOL);
+      await provider.connection.confirmTransaction(sig, "confirmed");
+
+      await waitForNextPeriod();
+      for (const user of [alice, bob, carol, dave]) {
+        await stakeInArena(arena, user, 0.1 * LAMPORTS_PER_SOL);
+      }
+      startedAt = await chainTime();
+      deathDuel = await startDuel(alice, bob, 1, startedAt + 3600);
+      timedDuel = await startDuel(carol, dave, 1, startedAt + 5);
+      assert.equal((await program.account.player.fetch(getArenaPlayerPDA(arena, alice.publicKey))).openDuels as number, 1);
+      assert.equal((await program.account.player.fetch(getArenaPlayerPDA(arena, bob.publicKey))).openDuels as number, 1);
+    });
+
+    it("Refunds both wagers when both streaks are intact at the end date, then closes the duel", async () => {
+      await waitForNextPeriod();
+      for (const user of [bob, carol, dave]) {
+        await checkinInArena(arena, user);
+      }
+      while ((await chainTime()) < startedAt + 5) {
+        await new Promise(resolve => setTimeout(resolve, 1000));
+      }
+      assert.equal(await claimDuel(timedDuel, carol, dave, carol), wager);
+      const rent = await provider.connection.getBalance(timedDuel) - wager;
+      const carolBefore = await provider.connection.getBalance(carol.publicKey);
+      assert.equal(await claimDuel(timedDuel, carol, dave, dave), wager);
+      assert.isNull(await program.account.duel.fetchNullable(timedDuel));
+      assert.equal(await provider.connection.getBalance(carol.publicKey) - carolBefore, rent);
+    });
+
+    it("Settles on a death in the survivor's favour and closes once the winner claims", async () => {
+      // Alice missed a period, and that death hands Bob the duel
+      await waitForNextPeriod();
+      await checkinInArena(arena, bob);
+      const treasuryBefore = await provider.connection.getBalance(arena.treasury);
+      await processDeathInArena(arena, alice.publicKey, {}, [deathDuel, getArenaPlayerPDA(arena, bob.publicKey)]);
+
+      const fee = 2 * wager * 300 / 10_000;
+      const duel = await program.account.duel.fetch(deathDuel);
+      assert.isTrue(duel.settled as boolean);
+      assert.isTrue((duel.winner as PublicKey).equals(bob.publicKey));
+      assert.equal((duel.prize as anchor.BN).toNumber(), 2 * wager - fee);
+      assert.equal(await provider.connection.getBalance(arena.treasury) - treasuryBefore, fee);
+      const bobPlayer = await program.account.player.fetch(getArenaPlayerPDA(arena, bob.publicKey));
+      const alicePlayer = await program.account.player.fetch(getArenaPlayerPDA(arena, alice.publicKey));
+      assert.equal(bobPlayer.duelsWon as number, 1);
+      assert.equal(bobPlayer.openDuels as number, 0);
+      assert.equal(alicePlayer.duelsLost as number, 1);
+
+      // The winner's claim empties the duel, so it closes
+      assert.equal(await claimDuel(deathDuel, alice, bob, bob), 2 * wager - fee);
+      assert.isNull(await program.account.duel.fetchNullable(deathDuel));
+    });
+  });
+
+  describe("Squads", () => {
+    const squadId = new anchor.BN(Date.now());
+    let squadPDA: PublicKey;
+    let arena: FlowArena;
+    let alice: Keypair;
+    let bob: Keypair;
+    let carol: Keypair;
+    let arenaSquad: PublicKey;
+    let alicePDA: PublicKey;
+
+    before(() => {
+      [squadPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("squad"), squadId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+    });
+
+    it("Active player can start a squad", async () => {
+      await program.methods
+        .createSquad(squadId)
+        .accounts({
+          gameState: gameStatePDA,
+          squad: squadPDA,
+          player: getPlayerPDA(player1.publicKey)[0],
+          user: player1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player1])
+        .rpc();
+
+      const squad = await program.account.squad.fetch(squadPDA);
+      const player = await program.account.player.fetch(getPlayerPDA(player1.publicKey)[0]);
+      assert.equal((squad.members as any[]).length, 1);
+      assert.isTrue((player.squad as PublicKey).equals(squadPDA));
+    });
+
+    it("Joining needs the leader's approval", async () => {
+      try {
+        await program.methods
+          .joinSquad()
+          .accounts({
+            gameState: gameStatePDA,
+            squad: squadPDA,
+            player: getPlayerPDA(player2.publicKey)[0],
+            user: player2.publicKey,
+            leader: player2.publicKey,
+          })
+          .signers([player2])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err: any) {
+        assert.include(err.toString(), "Unauthorized");
+      }
+    });
+
+    it("Member can leave the squad", async () => {
+      await program.methods
+        .leaveSquad()
+        .accounts({
+          gameState: gameStatePDA,
+          squad: squadPDA,
+          player: getPlayerPDA(player1.publicKey)[0],
+          user: player1.publicKey,
+        })
+        .signers([player1])
+        .rpc();
+
+      const squad = await program.account.squad.fetch(squadPDA);
+      const player = await program.account.player.fetch(getPlayerPDA(player1.publicKey)[0]);
+      assert.equal((squad.members as any[]).length, 0);
+      assert.isNull(player.squad);
+    });
+
+    it("Arena players can form a squad with a growth bonus and death penalty", async () => {
+      arena = await createFlowArena();
+      [alice, bob, carol] = await fundedPlayers(3);
+      arenaSquad = getArenaPDA(arena, "squad", new anchor.BN(1).toArrayLike(Buffer, "le", 8));
+      alicePDA = getArenaPlayerPDA(arena, alice.publicKey);
+
+      await program.methods
+        .setSquadConfig(new anchor.BN(5_000), new anchor.BN(1_000))
+        .accounts({ gameState: arena.gameState, authority: authority.publicKey })
+        .rpc();
+
+      await waitForNextPeriod();
+      for (const user of [alice, bob, carol]) {
+        await stakeInArena(arena, user, 0.1 * LAMPORTS_PER_SOL);
+      }
+      await program.methods
+        .createSquad(new anchor.BN(1))
+        .accounts({
+          gameState: arena.gameState,
+          squad: arenaSquad,
+          player: alicePDA,
+          user: alice.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([alice])
+        .rpc();
+      for (const user of [bob, carol]) {
+        await program.methods
+          .joinSquad()
+          .accounts({
+            gameState: arena.gameState,
+            squad: arenaSquad,
+            player: getArenaPlayerPDA(arena, user.publicKey),
+            user: user.publicKey,
+            leader: alice.publicKey,
+          })
+          .signers([user, alice])
+          .rpc();
+      }
+
+      assert.equal(((await program.account.squad.fetch(arenaSquad)).members as any[]).length, 3);
+    });
+
+    it("Pays the growth bonus while every member checked in last period", async () => {
+      await waitForNextPeriod();
+      for (const user of [alice, bob, carol]) {
+        await checkinInArena(arena, user, { squad: arenaSquad });
+      }
+      const growth = 0.1 * LAMPORTS_PER_SOL * 10 / 10_000;
+      assert.equal(
+        ((await program.account.player.fetch(alicePDA)).stake as anchor.BN).toNumber(),
+        0.1 * LAMPORTS_PER_SOL + growth * 1.5
+      );
+    });
+
+    it("A member's death leaves every remaining member owing the penalty", async () => {
+      // Carol misses a period and dies
+      await waitForNextPeriod();
+      await checkinInArena(arena, alice, { squad: arenaSquad });
+      await checkinInArena(arena, bob, { squad: arenaSquad });
+      await waitForNextPeriod();
+      await processDeathInArena(arena, carol.publicKey, { squad: arenaSquad });
+
+      const state = await program.account.squad.fetch(arenaSquad);
+      assert.equal((state.members as any[]).length, 2);
+      assert.equal(state.penaltyCount as number, 1);
+      assert.isNull((await program.account.player.fetch(getArenaPlayerPDA(arena, carol.publicKey))).squad);
+    });
+
+    it("A check-in pays the owed penalty first, then grows with the bonus", async () => {
+      const stakeBefore = ((await program.account.player.fetch(alicePDA)).stake as anchor.BN).toNumber();
+      await checkinInArena(arena, alice, { squad: arenaSquad });
+      const penalty = Math.floor(stakeBefore * 1_000 / 10_000);
+      const afterPenalty = stakeBefore - penalty;
+      const baseGrowth = Math.floor(afterPenalty * 10 / 10_000);
+      const bonus = Math.floor(baseGrowth * 5_000 / 10_000);
+      assert.equal(
+        ((await program.account.player.fetch(alicePDA)).stake as anchor.BN).toNumber(),
+        afterPenalty + baseGrowth + bonus
+      );
+
+      const state = await program.account.squad.fetch(arenaSquad);
+      const members = state.members as { wallet: PublicKey; penaltiesPaid: number }[];
+      assert.equal((state.totalPenalties as anchor.BN).toNumber(), penalty);
+      assert.equal(members.find(member => member.wallet.equals(alice.publicKey))!.penaltiesPaid, 1);
+      assert.equal(members.find(member => member.wallet.equals(bob.publicKey))!.penaltiesPaid, 0);
+    });
+  });
+
+  describe("Mystery boxes", () => {
+    let arena: FlowArena;
+    let alice: Keypair;
+    let alicePDA: PublicKey;
+    let revealSlot: number;
+
+    it("Only the authority can configure mystery boxes", async () => {
+      try {
+        await program.methods
+          .setMysteryBoxConfig(7, [{ kind: 3, weight: 1, amount: new anchor.BN(100) }])
+          .accounts({
+            gameState: gameStatePDA,
+            authority: player1.publicKey,
+          })
+          .signers([player1])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err: any) {
+        assert.include(err.toString(), "Unauthorized");
+      }
+    });
+
+    it("Cannot open a box before earning one", async () => {
+      try {
+        await program.methods
+          .openBox()
+          .accounts({
+            gameState: gameStatePDA,
+            player: getPlayerPDA(player1.publicKey)[0],
+            user: player1.publicKey,
+            randomness: SYSVAR_SLOT_HASHES_PUBKEY,
+          })
+          .signers([player1])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err: any) {
+        assert.include(err.toString(), "NoMysteryBoxes");
+      }
+    });
+
+    it("A check-in grants a box revealed by a later slot", async () => {
+      arena = await createFlowArena();
+      [alice] = await fundedPlayers(1);
+      alicePDA = getArenaPlayerPDA(arena, alice.publicKey);
+
+      // Every check-in grants a box that always holds one lifeline
+      await program.methods
+        .setMysteryBoxConfig(1, [{ kind: 2, weight: 1, amount: new anchor.BN(1) }])
+        .accounts({ gameState: arena.gameState, authority: authority.publicKey })
+        .rpc();
+
+      await stakeInArena(arena, alice, 0.1 * LAMPORTS_PER_SOL);
+      await waitForNextPeriod();
+      const slotBefore = await provider.connection.getSlot("confirmed");
+      await checkinInArena(arena, alice);
+
+      const player = await program.account.player.fetch(alicePDA);
+      revealSlot = (player.boxRevealSlot as anchor.BN).toNumber();
+      assert.equal(player.mysteryBoxes as number, 1);
+      assert.isAbove(revealSlot, slotBefore);
+    });
+
+    it("Opens the box with the hash of its reveal slot", async () => {
+      while ((await provider.connection.getSlot("confirmed")) <= revealSlot + 1) {
+        await new Promise(resolve => setTimeout(resolve, 400));
+      }
+      await program.methods
+        .openBox()
+        .accounts({
+          gameState: arena.gameState,
+          player: alicePDA,
+          user: alice.publicKey,
+          randomness: SYSVAR_SLOT_HASHES_PUBKEY,
+        })
+        .signers([alice])
+        .rpc();
+
+      const player = await program.account.player.fetch(alicePDA);
+      assert.equal(player.mysteryBoxes as number, 0);
+      assert.equal(player.boxesOpened as number, 1);
+      assert.equal(player.lifelines as number, 1);
+    });
+  });
+
+  describe("Sponsor pools", () => {
+    const poolId = new anchor.BN(1);
+    let sponsorPoolPDA: PublicKey;
+    const share = 0.15 * LAMPORTS_PER_SOL;
+    let arena: FlowArena;
+    let alice: Keypair;
+    let bob: Keypair;
+    let arenaPool: PublicKey;
+    let arenaPoolClosesAt: number;
+
+    before(() => {
+      [sponsorPoolPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("sponsor_pool"), player2.publicKey.toBuffer(), poolId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+    });
+
+    function sponsorClaimPDA(wallet: PublicKey): PublicKey {
+      return PublicKey.findProgramAddressSync(
+        [Buffer.from("sponsor_claim"), arenaPool.toBuffer(), wallet.toBuffer()],
+        program.programId
+      )[0];
+    }
+
+    function claimSponsorReward(user: Keypair): Promise<string> {
+      return program.methods
+        .claimSponsorReward()
+        .accounts({
+          gameState: arena.gameState,
+          sponsorPool: arenaPool,
+          sponsorClaim: sponsorClaimPDA(user.publicKey),
+          user: user.publicKey,
+        })
+        .signers([user])
+        .rpc();
+    }
+
+    it("Anyone can create and fund a sponsor pool", async () => {
+      const entriesCloseAt = new anchor.BN(Math.floor(Date.now() / 1000) + 7 * 86400);
+      const expiresAt = entriesCloseAt.add(new anchor.BN(7 * 86400));
+
+      await program.methods
+        .createSponsorPool(
+          poolId,
+          30,
+          new anchor.BN(0),
+          new anchor.BN(0),
+          new anchor.BN(0),
+          entriesCloseAt,
+          expiresAt
+        )
+        .accounts({
+          gameState: gameStatePDA,
+          sponsorPool: sponsorPoolPDA,
+          sponsor: player2.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player2])
+        .rpc();
+
+      await program.methods
+        .fundSponsorPool(new anchor.BN(0.05 * LAMPORTS_PER_SOL))
+        .accounts({
+          sponsorPool: sponsorPoolPDA,
+          funder: player3.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player3])
+        .rpc();
+
+      const pool = await program.account.sponsorPool.fetch(sponsorPoolPDA);
+      assert.equal(pool.minStreakDays as number, 30);
+      assert.equal((pool.totalFunded as anchor.BN).toNumber(), 0.05 * LAMPORTS_PER_SOL);
+    });
+
+    it("Remainder cannot be returned before expiry", async () => {
+      try {
+        await program.methods
+          .closeSponsorPool()
+          .accounts({
+            sponsorPool: sponsorPoolPDA,
+            sponsor: player2.publicKey,
+          })
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err: any) {
+        assert.include(err.toString(), "SponsorPoolNotExpired");
+      }
+    });
+
+    it("Arena players can enter a funded pool before entries close", async () => {
+      arena = await createFlowArena();
+      [alice, bob] = await fundedPlayers(2);
+      arenaPool = getArenaPDA(arena, "sponsor_pool", authority.publicKey.toBuffer(), poolId.toArrayLike(Buffer, "le", 8));
+
+      arenaPoolClosesAt = (await chainTime()) + 15;
+      await program.methods
+        .createSponsorPool(
+          poolId,
+          0,
+          new anchor.BN(0),
+          new anchor.BN(0),
+          new anchor.BN(0),
+          new anchor.BN(arenaPoolClosesAt),
+          new anchor.BN(arenaPoolClosesAt + 3600)
+        )
+        .accounts({
+          gameState: arena.gameState,
+          sponsorPool: arenaPool,
+          sponsor: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      await program.methods
+        .fundSponsorPool(new anchor.BN(0.3 * LAMPORTS_PER_SOL))
+        .accounts({ sponsorPool: arenaPool, funder: authority.publicKey, systemProgram: SystemProgram.programId })
+        .rpc();
+
+      for (const user of [alice, bob]) {
+        await stakeInArena(arena, user, 0.1 * LAMPORTS_PER_SOL);
+        await program.methods
+          .enterSponsorPool()
+          .accounts({
+            gameState: arena.gameState,
+            sponsorPool: arenaPool,
+            sponsorClaim: sponsorClaimPDA(user.publicKey),
+            player: getArenaPlayerPDA(arena, user.publicKey),
+            user: user.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user])
+          .rpc();
+      }
+      assert.equal((await program.account.sponsorPool.fetch(arenaPool)).entries as number, 2);
+    });
+
+    it("Claims only open once entries close", async () => {
+      try {
+        await claimSponsorReward(alice);
+        assert.fail("Should have thrown error");
+      } catch (err: any) {
+        assert.include(err.toString(), "SponsorClaimsNotOpen");
+      }
+    });
+
+    it("Splits the pool evenly between the entrants, however many claim first", async () => {
+      while ((await chainTime()) < arenaPoolClosesAt) {
+        await new Promise(resolve => setTimeout(resolve, 1000));
+      }
+
+      for (const user of [alice, bob]) {
+        const before = await provider.connection.getBalance(user.publicKey);
+        await claimSponsorReward(user);
+        assert.equal(await provider.connection.getBalance(user.publicKey) - before, share);
+      }
+
+      const pool = await program.account.sponsorPool.fetch(arenaPool);
+      assert.equal((pool.rewardPerPlayer as anchor.BN).toNumber(), share);
+      assert.equal((pool.totalClaimed as anchor.BN).toNumber(), 2 * share);
+      assert.equal(pool.claims as number, 2);
+    });
+
+    it("Each entrant claims once", async () => {
+      try {
+        await claimSponsorReward(alice);
+        assert.fail("Should have thrown error");
+      } catch (err: any) {
+        assert.include(err.toString(), "AlreadyClaimed");
+      }
+    });
+  });
+
+  describe("Cohorts", () => {
+    const cohortId = new anchor.BN(Date.now());
+    let cohortPDA: PublicKey;
+
+    before(() => {
+      [cohortPDA] = PublicKey.findProgramAddressSync(
+        [COHORT_SEED, authority.publicKey.toBuffer(), cohortId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+    });
+
+    it("Admin can create a funded cohort", async () => {
+      await program.methods
+        .createCohort(
+          cohortId,
+          Array(32).fill(0),
+          new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+          new anchor.BN(0),
+          true,
+          new anchor.BN(0),
+          new anchor.BN(LAMPORTS_PER_SOL)
+        )
+        .accounts({
+          gameState: gameStatePDA,
+          cohort: cohortPDA,
+          admin: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const cohort = await program.account.cohort.fetch(cohortPDA);
+      assert.isTrue((cohort.admin as PublicKey).equals(authority.publicKey));
+      assert.equal((cohort.membersClaimed as number), 0);
+    });
+
+    it("Non-members cannot claim a cohort stake", async () => {
+      const [memberPDA] = PublicKey.findProgramAddressSync(
+        [COHORT_MEMBER_SEED, cohortPDA.toBuffer(), player3.publicKey.toBuffer()],
+        program.programId
+      );
+      const [playerPDA] = getPlayerPDA(player3.publicKey);
+
+      try {
+        await program.methods
+          .claimCohortStake([])
+          .accounts({
+            gameState: gameStatePDA,
+            cohort: cohortPDA,
+            cohortMember: memberPDA,
+            player: playerPDA,
+            member: player3.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player3])
+          .rpc();
+        assert.fail("Should have thrown NotCohortMember error");
+      } catch (e: unknown) {
+        assert.include((e as Error).message, "NotCohortMember");
+      }
+    });
+  });
+
+  describe("Re-stake after death (BUG FIX TEST)", () => {
+    let deadPlayer: Keypair;
+
+    before(async () => {
+      // Create a new player that we will let die
+      deadPlayer = Keypair.generate();
+      await provider.connection.requestAirdrop(deadPlayer.publicKey, 5 * LAMPORTS_PER_SOL);
+      await new Promise(resolve => setTimeout(resolve, 2000));
+    });
+
+    it("Dead player stakes initially", async () => {
+      const sig = await stake(deadPlayer, 0.1);
+      await provider.connection.confirmTransaction(sig, "confirmed");
+
+      const [playerPDA] = getPlayerPDA(deadPlayer.publicKey);
+      const player = await program.account.player.fetch(playerPDA) as unknown as Player;
+
+      assert.isTrue(player.isActive);
+      assert.equal(player.stake.toNumber(), 0.1 * LAMPORTS_PER_SOL);
+    });
+
+    it("Wait for check-in period to pass (simulating death)", async () => {
+      // Note: In a real test environment, we would use a local validator
+      // and warp time forward. For devnet testing, we wait.
+      console.log("In a real test, the player would miss the check-in period...");
+      // For now, we'll manually process death or mark as inactive
+    });
+
+    it("Process death on the dead player", async () => {
+      // For testing purposes, let's manually manipulate or wait
+      // In a real scenario with short intervals, we would wait
+
+      // First, let's check if we can process death
+      const [playerPDA] = getPlayerPDA(deadPlayer.publicKey);
+
+      try {
+        // Build process_death instruction manually
+        const discriminator = Buffer.from([114, 251, 43, 80, 207, 177, 198, 62]);
+
+        const keys = [
+          { pubkey: gameStatePDA, isSigner: false, isWritable: true },
+          { pubkey: treasury.publicKey, isSigner: false, isWritable: true },
+          { pubkey: playerPDA, isSigner: false, isWritable: true },
+          { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        ];
+
+        const ix = new anchor.web3.TransactionInstruction({
+          keys,
+          programId: program.programId,
+          data: discriminator,
+        });
+
+        const tx = new anchor.web3.Transaction().add(ix);
+        tx.feePayer = authority.publicKey;
+        tx.recentBlockhash = (await provider.connection.getLatestBlockhash()).blockhash;
+
+        await provider.sendAndConfirm(tx);
+
+        const player = await program.account.player.fetch(playerPDA) as unknown as Player;
+        assert.isFalse(player.isActive, "Player should be inactive after death");
+      } catch (e: unknown) {
+        // Player might not be dead yet (depends on timing)
+        console.log("Process death not applicable yet:", (e as Error).message);
+      }
+    });
+
+    it("Dead player (or inactive player) CAN RE-STAKE - THIS IS THE BUG FIX", async () => {
+      const [playerPDA] = getPlayerPDA(deadPlayer.publicKey);
+
+      // First, let's check current state
+      let playerBefore: Player;
+      try {
+        playerBefore = await program.account.player.fetch(playerPDA) as unknown as Player;
+        console.log("Player state before re-stake:", {
+          isActive: playerBefore.isActive,
+          stake: playerBefore.stake.toNumber() / LAMPORTS_PER_SOL,
+          wallet: playerBefore.wallet.toBase58().slice(0, 8) + "...",
+        });
+      } catch {
+        console.log("Player account doesn't exist yet");
+      }
+
+      // If player is active, we need to make them inactive first
+      // For this test, we'll modify the test to use a fresh player or skip if active
+      if (playerBefore! && playerBefore.isActive) {
+        console.log("Player is still active - cannot test re-stake scenario");
+        console.log("To properly test this, run against a local validator with time warp");
+        return;
+      }
+
+      // THE CRITICAL TEST: Re-stake should work without passing referrer_player account
+      // This is what was failing before the fix
+      const newStakeAmount = 0.2;
+
+      // Build stake instruction WITHOUT referrer (since this is a re-stake)
+      const amountLamports = new anchor.BN(Math.floor(newStakeAmount * LAMPORTS_PER_SOL));
+      const discriminator = Buffer.from([206, 176, 202, 18, 200, 209, 179, 108]);
+      const amountBuffer = Buffer.alloc(8);
+      amountLamports.toArrayLike(Buffer, "le", 8).copy(amountBuffer);
+      const referrerBuffer = Buffer.from([0]); // No referrer for re-stake
+      const offsetBuffer = Buffer.alloc(8); // Deadline offset 0 (UTC)
+      const commitmentBuffer = Buffer.alloc(4); // No commitment
+
+      const data = Buffer.concat([discriminator, amountBuffer, referrerBuffer, offsetBuffer, commitmentBuffer]);
+
+      const keys = [
+        { pubkey: gameStatePDA, isSigner: false, isWritable: true },
+        { pubkey: playerPDA, isSigner: false, isWritable: true },
+        // NO referrer_player account - this is what caused the bug!
+        { pubkey: deadPlayer.publicKey, isSigner: true, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      ];
+
+      const ix = new anchor.web3.TransactionInstruction({
+        keys,
+        programId: program.programId,
+        data,
+      });
+
+      const tx = new anchor.web3.Transaction().add(ix);
+      tx.feePayer = deadPlayer.publicKey;
+      tx.recentBlockhash = (await provider.connection.getLatestBlockhash()).blockhash;
+      tx.sign(deadPlayer);
+
+      try {
+        const sig = await provider.connection.sendRawTransaction(tx.serialize());
+        await provider.connection.confirmTransaction(sig, "confirmed");
+
+        const playerAfter = await program.account.player.fetch(playerPDA) as unknown as Player;
+
+        console.log("RE-STAKE SUCCESSFUL!");
+        console.log("Player state after re-stake:", {
+          isActive: playerAfter.isActive,
+          stake: playerAfter.stake.toNumber() / LAMPORTS_PER_SOL,
+          streakDays: playerAfter.streakDays,
         });
 
         assert.isTrue(playerAfter.isActive, "Player should be active after re-stake");
@@ -419,6 +2961,50 @@ describe("streak", () => {
         throw e;
       }
     });
+
+    it("Dead player has to claim pending rewards before re-staking", async () => {
+      const arena = await createFlowArena();
+      const [erin] = await fundedPlayers(1);
+      const erinPDA = getArenaPlayerPDA(arena, erin.publicKey);
+
+      // Harvested growth waits in pending_rewards, then Erin misses a period and dies
+      await stakeInArena(arena, erin, 0.1 * LAMPORTS_PER_SOL);
+      await waitForNextPeriod();
+      await checkinInArena(arena, erin);
+      await program.methods
+        .harvest()
+        .accounts({ gameState: arena.gameState, player: erinPDA, user: erin.publicKey })
+        .signers([erin])
+        .rpc();
+      await waitForNextPeriod();
+      await waitForNextPeriod();
+      await processDeathInArena(arena, erin.publicKey);
+
+      const pending = ((await program.account.player.fetch(erinPDA)).pendingRewards as anchor.BN).toNumber();
+      assert.isAbove(pending, 0);
+      try {
+        await stakeInArena(arena, erin, 0.1 * LAMPORTS_PER_SOL);
+        assert.fail("Should have thrown error");
+      } catch (err: any) {
+        assert.include(err.toString(), "OutstandingClaims");
+      }
+
+      await program.methods
+        .claimRewards()
+        .accounts({
+          gameState: arena.gameState,
+          player: erinPDA,
+          user: erin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([erin])
+        .rpc();
+      await stakeInArena(arena, erin, 0.1 * LAMPORTS_PER_SOL);
+
+      const player = await program.account.player.fetch(erinPDA);
+      assert.isTrue(player.isActive as boolean);
+      assert.equal((player.pendingRewards as anchor.BN).toNumber(), 0);
+    });
   });
 
   describe("Edge Cases", () => {